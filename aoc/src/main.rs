@@ -0,0 +1,850 @@
+//! Workspace runner for Advent of Code 2024 solutions.
+//!
+//! Usage: `cargo run -p aoc -- run --day 3 --timeout 10s`
+
+mod registry;
+#[cfg(feature = "tui")]
+mod tui;
+
+use anyhow::{bail, Context, Result};
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use shared::benchmarking::{
+    aggregate_benchmarks, check_group_regression, compare_runs, list_criterion_groups,
+    load_result_set, part_runtimes, print_group_summary, promote_baseline, regenerate_plot,
+    save_results_baseline, write_aggregate_json,
+};
+use shared::cache::{store_input, verify_cached_input};
+use shared::input::{read_input, read_input_or_example, stats, InputSource};
+use shared::matrix::{build_matrix, render_matrix};
+use shared::output::report_mismatch;
+use shared::plotting::{create_runtime_bar_chart, PlotTheme};
+use shared::runner::{parse_duration, run_day, run_solver, transform_input, RunOutcome, Solution};
+use shared::submit::{
+    accepted_answer, check_plausibility, read_submissions, record_submission, Submission, Verdict,
+};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 solution runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run a day's solvers on the example and real input
+    Run(RunArgs),
+    /// Download a day's puzzle input into dayXX/input.txt
+    Fetch(FetchArgs),
+    /// Submit a part's answer for the real input to adventofcode.com
+    Submit(SubmitArgs),
+    /// Run a day's Criterion benchmarks and summarize the results
+    Bench(BenchArgs),
+    /// Regenerate benchmark plots from existing Criterion data
+    Plot(PlotArgs),
+    /// Make the latest benchmark results the baseline for future comparisons
+    Promote(PromoteArgs),
+    /// Fail if benchmark results slowed down versus the stored baseline
+    Regress(RegressArgs),
+    /// Compare two exported benchmark result sets point by point
+    Compare(CompareArgs),
+    /// Combine every day's real-input benchmark times into one dataset
+    Aggregate(AggregateArgs),
+    /// Check answers on the real input against accepted submissions
+    Verify(VerifyArgs),
+    /// Run every solver variant and write a compatibility matrix
+    Matrix(MatrixArgs),
+    /// Re-solve a day on input transformed by a user script
+    Pipe(PipeArgs),
+    /// List implemented days, their solved parts and input availability
+    List,
+    /// Print a shell completion script (e.g. `aoc completions bash`)
+    Completions(CompletionsArgs),
+    /// Browse days and run solutions in an interactive dashboard
+    #[cfg(feature = "tui")]
+    Tui(TuiArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day number to run (1-25)
+    #[arg(short, long)]
+    day: u8,
+
+    /// Only run this part (1 or 2)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Give up on a solver after this long (e.g. "10s", "500ms", "2m")
+    #[arg(long, value_parser = parse_duration)]
+    timeout: Option<Duration>,
+
+    /// Print statistics of the real input before running
+    #[arg(long)]
+    stats: bool,
+
+    /// Explain how the day's rules apply to the input before running
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day number to fetch (1-25)
+    #[arg(short, long)]
+    day: u8,
+
+    /// Download again even if an intact input is already cached
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day number to submit (1-25)
+    #[arg(short, long)]
+    day: u8,
+
+    /// Part to submit (1 or 2)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Only compute and check the answer, never contact the website
+    #[arg(long)]
+    dry_run: bool,
+
+    /// Submit even if plausibility checks fail
+    #[arg(long)]
+    force: bool,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day number to benchmark (1-25)
+    #[arg(short, long)]
+    day: u8,
+
+    /// Only run the benchmarks of this Criterion group
+    #[arg(short, long)]
+    group: Option<String>,
+
+    /// Extra arguments passed through to Criterion (after `--`)
+    #[arg(last = true)]
+    criterion_args: Vec<String>,
+}
+
+#[derive(Args)]
+struct PlotArgs {
+    /// Only regenerate this day's plots (default: every day)
+    #[arg(short, long)]
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct PromoteArgs {
+    /// Day number whose results are promoted (1-25)
+    #[arg(short, long)]
+    day: u8,
+
+    /// Only promote this Criterion group (default: every group)
+    #[arg(short, long)]
+    group: Option<String>,
+}
+
+#[derive(Args)]
+struct RegressArgs {
+    /// Day number whose results are checked (1-25)
+    #[arg(short, long)]
+    day: u8,
+
+    /// Only check this Criterion group (default: every group)
+    #[arg(short, long)]
+    group: Option<String>,
+
+    /// Largest allowed slowdown in percent
+    #[arg(short, long, default_value_t = 10.0)]
+    threshold: f64,
+
+    /// Store the current results as the new baseline instead of checking
+    #[arg(long)]
+    save: bool,
+}
+
+#[derive(Args)]
+struct CompareArgs {
+    /// Results of the earlier run (results JSON file or data directory)
+    old: String,
+
+    /// Results of the later run (results JSON file or data directory)
+    new: String,
+
+    /// Changes beyond this many percent are highlighted
+    #[arg(short, long, default_value_t = 5.0)]
+    threshold: f64,
+}
+
+#[derive(Args)]
+struct AggregateArgs {
+    /// JSON file the combined dataset is written to
+    #[arg(short, long, default_value = shared::benchmarking::AGGREGATE_FILE)]
+    output: PathBuf,
+    /// Bar chart of each day's runtime per part (.svg, or .png with png-plots)
+    #[arg(short, long, default_value = shared::benchmarking::AGGREGATE_CHART_FILE)]
+    chart: PathBuf,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day (default: every day)
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Give up on a solver after this long (e.g. "10s", "500ms", "2m")
+    #[arg(long, value_parser = parse_duration)]
+    timeout: Option<Duration>,
+}
+
+#[derive(Args)]
+struct MatrixArgs {
+    /// Only include this day (default: every day)
+    #[arg(short, long)]
+    day: Option<u8>,
+
+    /// Markdown file the matrix is written to
+    #[arg(short, long, default_value = "compatibility.md")]
+    output: PathBuf,
+
+    /// Give up on a solver after this long (e.g. "10s", "500ms", "2m")
+    #[arg(long, value_parser = parse_duration)]
+    timeout: Option<Duration>,
+}
+
+#[derive(Args)]
+struct PipeArgs {
+    /// Day number to run (1-25)
+    #[arg(short, long)]
+    day: u8,
+
+    /// Only run this part (1 or 2)
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Script that reads the input on stdin and prints the new input
+    #[arg(short, long)]
+    filter_script: PathBuf,
+
+    /// Start from the example input even if input.txt exists
+    #[arg(long)]
+    example: bool,
+
+    /// Also write the transformed input to this file
+    #[arg(long)]
+    save: Option<PathBuf>,
+
+    /// Give up on a solver after this long (e.g. "10s", "500ms", "2m")
+    #[arg(long, value_parser = parse_duration)]
+    timeout: Option<Duration>,
+
+    /// Extra arguments passed through to the script (after `--`)
+    #[arg(last = true)]
+    script_args: Vec<String>,
+}
+
+#[derive(Args)]
+struct CompletionsArgs {
+    /// Shell to generate completions for
+    shell: Shell,
+}
+
+#[cfg(feature = "tui")]
+#[derive(Args)]
+struct TuiArgs {
+    /// Give up on a solver after this long (e.g. "10s", "500ms", "2m")
+    #[arg(long, value_parser = parse_duration)]
+    timeout: Option<Duration>,
+}
+
+fn main() -> Result<()> {
+    match Cli::parse().command {
+        Command::Run(args) => run(&args),
+        Command::Fetch(args) => fetch(&args),
+        Command::Submit(args) => submit(&args),
+        Command::Bench(args) => bench(&args),
+        Command::Plot(args) => plot(&args),
+        Command::Promote(args) => promote(&args),
+        Command::Regress(args) => regress(&args),
+        Command::Compare(args) => compare(&args),
+        Command::Aggregate(args) => aggregate(&args),
+        Command::Verify(args) => verify(&args),
+        Command::Matrix(args) => matrix(&args),
+        Command::Pipe(args) => pipe(&args),
+        Command::List => list(),
+        Command::Completions(args) => {
+            clap_complete::generate(args.shell, &mut Cli::command(), "aoc", &mut io::stdout());
+            Ok(())
+        }
+        #[cfg(feature = "tui")]
+        Command::Tui(args) => tui::run(args.timeout),
+    }
+}
+
+/// Runs the selected parts of a day on its example and real input.
+fn run(args: &RunArgs) -> Result<()> {
+    let solution = registry::find(args.day)?;
+    if args.stats {
+        match read_input(solution.day) {
+            Ok(input) => println!("=== Input Statistics ===\n{}\n", stats(&input)),
+            Err(_) => println!("No input.txt found - no statistics to show\n"),
+        }
+    }
+    if args.verbose {
+        match solution.explain {
+            Some(explain) => {
+                let (input, source) = read_input_or_example(solution.day, solution.example_input)?;
+                println!(
+                    "=== Explanation of the {source} ===\n{}\n",
+                    explain(&input)?
+                );
+            }
+            None => println!("Day {} has no explanation to show\n", solution.day),
+        }
+    }
+    let parts = args.part.map_or(vec![1, 2], |part| vec![part]);
+    run_day(solution, &parts, args.timeout)
+}
+
+/// Downloads a day's input and caches it with integrity metadata.
+///
+/// An intact cached input is kept unless `--force` is given. Downloads that
+/// are error pages or truncated are rejected without touching the cache.
+fn fetch(args: &FetchArgs) -> Result<()> {
+    let path = format!("day{:02}/input.txt", args.day);
+    if !args.force {
+        if let Ok(input) = read_input(args.day) {
+            let problems = verify_cached_input(args.day, &input);
+            if problems.is_empty() {
+                println!("{path} is already cached (use --force to download again)");
+                return Ok(());
+            }
+            for problem in &problems {
+                println!("⚠️  {problem}");
+            }
+            println!("Cached input looks corrupt, downloading again");
+        }
+    }
+
+    let body = ureq::get(&format!(
+        "https://adventofcode.com/2024/day/{}/input",
+        args.day
+    ))
+    .set("Cookie", &format!("session={}", session_cookie()?))
+    .call()?
+    .into_string()?;
+    let metadata = store_input(args.day, &body)?;
+    println!(
+        "Saved {path} ({} lines, checksum {:016x})",
+        body.lines().count(),
+        metadata.checksum
+    );
+    Ok(())
+}
+
+/// Reads the adventofcode.com session cookie from `AOC_SESSION`.
+fn session_cookie() -> Result<String> {
+    std::env::var("AOC_SESSION").context("Set AOC_SESSION to your adventofcode.com session cookie")
+}
+
+/// Computes a part's answer, checks its plausibility, and submits it.
+///
+/// Plausibility failures abort the submission unless `--force` is given, and
+/// `--dry-run` stops before any network access. The session cookie is read
+/// from the `AOC_SESSION` environment variable.
+fn submit(args: &SubmitArgs) -> Result<()> {
+    let solution = registry::find(args.day)?;
+    let solver = solution
+        .solver(args.part)
+        .with_context(|| format!("Day {} part {} is not implemented", args.day, args.part))?;
+
+    let input = read_input(solution.day)?;
+    let problems = verify_cached_input(solution.day, &input);
+    if !problems.is_empty() {
+        bail!(
+            "Refusing to solve a corrupt input: {} (run `aoc fetch --day {} --force`)",
+            problems.join("; "),
+            solution.day
+        );
+    }
+    let answer = solver(&input)?;
+    let example_answer = solver(solution.example_input(args.part)).ok();
+    let previous: Vec<Submission> = read_submissions(solution.day)?
+        .into_iter()
+        .filter(|submission| submission.part == args.part)
+        .collect();
+
+    if previous
+        .iter()
+        .any(|submission| submission.verdict == Verdict::Correct)
+    {
+        println!("⚠️  Part {} was already solved", args.part);
+    }
+
+    let issues = check_plausibility(&answer, example_answer.as_deref(), &previous);
+    for issue in &issues {
+        println!("⚠️  Plausibility check failed: {issue}");
+    }
+
+    if args.dry_run {
+        println!(
+            "Dry run: would submit {answer} for day {} part {}",
+            args.day, args.part
+        );
+        return Ok(());
+    }
+
+    if !issues.is_empty() && !args.force {
+        bail!(
+            "Refusing to submit {answer}: {} plausibility check(s) failed (use --force to submit anyway)",
+            issues.len()
+        );
+    }
+
+    let session = session_cookie()?;
+    let url = format!("https://adventofcode.com/2024/day/{}/answer", args.day);
+    let level = args.part.to_string();
+    let body = ureq::post(&url)
+        .set("Cookie", &format!("session={session}"))
+        .send_form(&[("level", &level), ("answer", &answer)])?
+        .into_string()?;
+
+    let verdict = Verdict::from_response(&body);
+    // Rate-limited submissions were never evaluated, so they are not recorded
+    if verdict != Verdict::RateLimited {
+        record_submission(
+            solution.day,
+            &Submission {
+                part: args.part,
+                answer: answer.clone(),
+                verdict,
+            },
+        )?;
+    }
+
+    println!(
+        "Submitted {answer} for day {} part {}: {verdict}",
+        args.day, args.part
+    );
+    Ok(())
+}
+
+/// Runs a day's Criterion benchmarks through cargo and summarizes them.
+///
+/// The group name is forwarded as Criterion's benchmark filter together with
+/// any extra arguments. Afterwards every selected group found in the day's
+/// `data` directory is summarized.
+fn bench(args: &BenchArgs) -> Result<()> {
+    let solution = registry::find(args.day)?;
+    let package = format!("day{:02}", solution.day);
+
+    let status = process::Command::new("cargo")
+        .args(["bench", "-p", &package, "--"])
+        .args(&args.group)
+        .args(&args.criterion_args)
+        .status()
+        .context("Failed to launch cargo bench")?;
+    if !status.success() {
+        bail!("cargo bench -p {package} failed with {status}");
+    }
+
+    let data_dir = format!("{package}/data");
+    let groups = match &args.group {
+        Some(group) => vec![group.clone()],
+        None => list_criterion_groups(&data_dir)?,
+    };
+    for group in groups {
+        println!();
+        print_group_summary(&data_dir, &group)?;
+    }
+    Ok(())
+}
+
+/// Regenerates every benchmark plot from the data already on disk.
+///
+/// Walks each selected day's `data` directory and redraws the plot of every
+/// Criterion group, reporting failures without stopping.
+fn plot(args: &PlotArgs) -> Result<()> {
+    let solutions = match args.day {
+        Some(day) => vec![registry::find(day)?],
+        None => registry::SOLUTIONS.iter().collect(),
+    };
+
+    let mut failures = 0;
+    for solution in solutions {
+        let data_dir = format!("day{:02}/data", solution.day);
+        if !Path::new(&data_dir).is_dir() {
+            continue;
+        }
+        for group in list_criterion_groups(&data_dir)? {
+            match regenerate_plot(&data_dir, &group) {
+                Ok(plot_path) => println!("📈 {plot_path}"),
+                Err(e) => {
+                    eprintln!("Failed to regenerate {data_dir}/{group}: {e}");
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        bail!("{failures} plot(s) could not be regenerated");
+    }
+    Ok(())
+}
+
+/// Copies the latest Criterion results of a day over its baselines.
+fn promote(args: &PromoteArgs) -> Result<()> {
+    let solution = registry::find(args.day)?;
+    let data_dir = format!("day{:02}/data", solution.day);
+
+    let groups = match &args.group {
+        Some(group) => vec![group.clone()],
+        None => list_criterion_groups(&data_dir)?,
+    };
+    for group in groups {
+        let promoted = promote_baseline(&data_dir, &group)?;
+        println!("📌 {data_dir}/{group}: promoted {promoted} benchmark(s) to baseline");
+    }
+    Ok(())
+}
+
+/// Checks a day's exported benchmark results against their baselines.
+///
+/// Exits with an error if any algorithm slowed down by more than the
+/// threshold at any size, so the command can gate CI.
+fn regress(args: &RegressArgs) -> Result<()> {
+    let solution = registry::find(args.day)?;
+    let data_dir = format!("day{:02}/data", solution.day);
+
+    let groups = match &args.group {
+        Some(group) => vec![group.clone()],
+        None => list_criterion_groups(&data_dir)?,
+    };
+
+    let mut regressions = 0;
+    for group in groups {
+        if args.save {
+            println!("📌 Saved {}", save_results_baseline(&data_dir, &group)?);
+            continue;
+        }
+        for regression in check_group_regression(&data_dir, &group, args.threshold)? {
+            eprintln!(
+                "❌ {group}/{}/{}: {:.2}μs -> {:.2}μs (+{:.1}%)",
+                regression.algorithm,
+                regression.size,
+                regression.baseline_ns / 1000.0,
+                regression.current_ns / 1000.0,
+                regression.change_pct
+            );
+            regressions += 1;
+        }
+    }
+
+    if regressions > 0 {
+        bail!(
+            "{regressions} benchmark(s) slowed down by more than {}%",
+            args.threshold
+        );
+    }
+    if !args.save {
+        println!("✅ No regressions above {}%", args.threshold);
+    }
+    Ok(())
+}
+
+/// Prints the change of every benchmark point between two result sets.
+///
+/// Slowdowns beyond the threshold are marked ❌ and speedups beyond it ✅.
+fn compare(args: &CompareArgs) -> Result<()> {
+    let deltas = compare_runs(&load_result_set(&args.old)?, &load_result_set(&args.new)?)?;
+    if deltas.is_empty() {
+        bail!("No benchmark points are shared by both result sets");
+    }
+
+    let (mut slower, mut faster) = (0, 0);
+    for delta in &deltas {
+        let marker = if delta.change_pct > args.threshold {
+            slower += 1;
+            "❌"
+        } else if delta.change_pct < -args.threshold {
+            faster += 1;
+            "✅"
+        } else {
+            "  "
+        };
+        println!(
+            "{marker} {}/{}/{}: {:.2}μs -> {:.2}μs ({:+.1}%)",
+            delta.group,
+            delta.algorithm,
+            delta.size,
+            delta.old_ns / 1000.0,
+            delta.new_ns / 1000.0,
+            delta.change_pct
+        );
+    }
+    println!(
+        "{} point(s) compared: {slower} slower, {faster} faster beyond {}%",
+        deltas.len(),
+        args.threshold
+    );
+    Ok(())
+}
+
+/// Writes the real-input benchmark times of every day to one JSON file.
+fn aggregate(args: &AggregateArgs) -> Result<()> {
+    let benchmarks = aggregate_benchmarks(".")?;
+    if benchmarks.is_empty() {
+        bail!("No real input benchmarks found; run a day's benchmarks with input.txt present");
+    }
+
+    for benchmark in &benchmarks {
+        println!(
+            "day{:02} {}/{}: {:.2}μs",
+            benchmark.day,
+            benchmark.group,
+            benchmark.algorithm,
+            benchmark.real_input_ns / 1000.0
+        );
+    }
+    write_aggregate_json(&args.output, &benchmarks)?;
+    println!(
+        "🧾 Combined {} benchmark(s) into {}",
+        benchmarks.len(),
+        args.output.display()
+    );
+
+    let runtimes = part_runtimes(&benchmarks);
+    if runtimes.is_empty() {
+        println!("⚠️  No benchmark group records its puzzle part; skipping the runtime chart");
+        return Ok(());
+    }
+    let total_ms = runtimes.values().sum::<f64>() / 1e6;
+    let chart = args.chart.to_string_lossy();
+    create_runtime_bar_chart(
+        &chart,
+        &format!("Runtime by Day ({total_ms:.2}ms total)"),
+        &PlotTheme::default(),
+        &benchmarks,
+    )?;
+    Ok(())
+}
+
+/// Re-solves every part that has an accepted answer and compares the result.
+///
+/// Parts without an accepted submission or days without `input.txt` are
+/// skipped. Mismatches are shown as a diff against the accepted answer.
+fn verify(args: &VerifyArgs) -> Result<()> {
+    let solutions = match args.day {
+        Some(day) => vec![registry::find(day)?],
+        None => registry::SOLUTIONS.iter().collect(),
+    };
+
+    let (mut checked, mut failures) = (0, 0);
+    for solution in solutions {
+        let submissions = read_submissions(solution.day)?;
+        let Ok(input) = read_input(solution.day) else {
+            continue;
+        };
+        for part in [1, 2] {
+            let (Some(solver), Some(expected)) =
+                (solution.solver(part), accepted_answer(&submissions, part))
+            else {
+                continue;
+            };
+            checked += 1;
+            let label = format!("Day {:02} part {part}", solution.day);
+            let outcome = run_solver(solver, &input, args.timeout);
+            match outcome.answer() {
+                Some(got) if report_mismatch(&label, expected, got) => {
+                    println!("✅ {label}: {outcome}");
+                }
+                Some(_) => failures += 1,
+                None => {
+                    println!("❌ {label}: {outcome}");
+                    failures += 1;
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        bail!("{failures} of {checked} answer(s) did not verify");
+    }
+    println!("{checked} answer(s) verified");
+    Ok(())
+}
+
+/// Writes the variant compatibility matrix and fails on any disagreement.
+fn matrix(args: &MatrixArgs) -> Result<()> {
+    let solutions = match args.day {
+        Some(day) => vec![registry::find(day)?],
+        None => registry::SOLUTIONS.iter().collect(),
+    };
+
+    let entries = build_matrix(&solutions, args.timeout);
+    let table = render_matrix(&entries);
+    print!("{table}");
+    fs::write(&args.output, &table)
+        .with_context(|| format!("Failed to write {}", args.output.display()))?;
+    println!("\nMatrix written to {}", args.output.display());
+
+    let disagreements = entries.iter().filter(|entry| !entry.agrees).count();
+    if disagreements > 0 {
+        bail!("{disagreements} solver run(s) disagree with the canonical answer");
+    }
+    Ok(())
+}
+
+/// Solves a day, transforms its input with a script, and solves it again.
+///
+/// The script sees the original answers as `AOC_PART1_ANSWER` and
+/// `AOC_PART2_ANSWER` (plus `AOC_DAY`), so a stage can depend on the output
+/// of the previous one. Both runs are printed side by side. Without
+/// `input.txt` the example input is used; a day with a separate Part 2
+/// example has each part's example piped on its own, and `--save` then
+/// writes one file per part.
+fn pipe(args: &PipeArgs) -> Result<()> {
+    let solution = registry::find(args.day)?;
+    let parts = args.part.map_or(vec![1, 2], |part| vec![part]);
+    let (real_input, source) = if args.example {
+        (None, InputSource::Example)
+    } else {
+        match read_input_or_example(solution.day, solution.example_input)? {
+            (input, InputSource::Real) => (Some(input), InputSource::Real),
+            (_, InputSource::Example) => (None, InputSource::Example),
+        }
+    };
+
+    // Parts sharing an input are piped together
+    let mut groups: Vec<(String, Vec<u8>)> = Vec::new();
+    for &part in &parts {
+        let input = real_input
+            .clone()
+            .unwrap_or_else(|| solution.example_input(part).to_string());
+        match groups.iter_mut().find(|(existing, _)| *existing == input) {
+            Some((_, group)) => group.push(part),
+            None => groups.push((input, vec![part])),
+        }
+    }
+
+    let several = groups.len() > 1;
+    for (input, parts) in &groups {
+        let transformed = pipe_input(args, solution, parts, input, source)?;
+        if let Some(path) = &args.save {
+            let path = if several {
+                part_path(path, parts)
+            } else {
+                path.clone()
+            };
+            fs::write(&path, &transformed)
+                .with_context(|| format!("Failed to write {}", path.display()))?;
+        }
+    }
+    Ok(())
+}
+
+/// Pipes one input through the script and prints the given parts' answers
+/// before and after.
+///
+/// # Returns
+/// The transformed input
+fn pipe_input(
+    args: &PipeArgs,
+    solution: &Solution,
+    parts: &[u8],
+    input: &str,
+    source: InputSource,
+) -> Result<String> {
+    let solve = |input: &str| -> Vec<(u8, RunOutcome)> {
+        parts
+            .iter()
+            .filter_map(|&part| {
+                let solver = solution.solver(part)?;
+                Some((part, run_solver(solver, input, args.timeout)))
+            })
+            .collect()
+    };
+
+    let original = solve(input);
+    let mut env = vec![("AOC_DAY".to_string(), solution.day.to_string())];
+    for (part, outcome) in &original {
+        if let Some(answer) = outcome.answer() {
+            env.push((format!("AOC_PART{part}_ANSWER"), answer.to_string()));
+        }
+    }
+
+    let transformed = transform_input(&args.filter_script, &args.script_args, input, &env)?;
+    println!(
+        "=== Day {}: {} ({source}, {} -> {} lines) ===",
+        solution.day,
+        solution.title,
+        input.lines().count(),
+        transformed.lines().count()
+    );
+
+    for ((part, before), (_, after)) in original.iter().zip(solve(&transformed)) {
+        println!("Part {part} original:    {before}");
+        println!("Part {part} transformed: {after}");
+    }
+    Ok(transformed)
+}
+
+/// Inserts the parts into a file name, e.g. `out.txt` becomes
+/// `out.part2.txt`.
+fn part_path(path: &Path, parts: &[u8]) -> PathBuf {
+    let suffix: String = parts.iter().map(|part| format!(".part{part}")).collect();
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let name = match path.extension() {
+        Some(extension) => format!("{stem}{suffix}.{}", extension.to_string_lossy()),
+        None => format!("{stem}{suffix}"),
+    };
+    path.with_file_name(name)
+}
+
+/// Prints a table of every registered day with part and input status.
+///
+/// A part is marked ★ when the submission history records a correct answer,
+/// ✓ when it is implemented, and - when it is missing.
+fn list() -> Result<()> {
+    println!("Day  {:<22} Part 1  Part 2  Input", "Title");
+    for solution in registry::SOLUTIONS {
+        let submissions = read_submissions(solution.day)?;
+        let status = |part: u8| {
+            let solved = submissions.iter().any(|submission| {
+                submission.part == part && submission.verdict == Verdict::Correct
+            });
+            match (solution.solver(part), solved) {
+                (Some(_), true) => "★",
+                (Some(_), false) => "✓",
+                (None, _) => "-",
+            }
+        };
+        let input = if Path::new(&format!("day{:02}/input.txt", solution.day)).exists() {
+            "✓"
+        } else {
+            "-"
+        };
+        println!(
+            "{:02}   {:<22} {:<7} {:<7} {input}",
+            solution.day,
+            solution.title,
+            status(1),
+            status(2)
+        );
+    }
+    Ok(())
+}
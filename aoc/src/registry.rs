@@ -0,0 +1,31 @@
+//! Registry of all implemented days available to the runner.
+
+use anyhow::{Context, Result};
+use shared::runner::Solution;
+
+/// Every implemented day, in day order.
+pub const SOLUTIONS: &[Solution] = &[
+    day01::SOLUTION,
+    day02::SOLUTION,
+    day03::SOLUTION,
+    day04::SOLUTION,
+    day05::SOLUTION,
+];
+
+/// Looks up the registry entry for a day.
+///
+/// # Parameters
+/// * `day` - The day number (1-25)
+///
+/// # Returns
+/// The day's registry entry
+///
+/// # Errors
+///
+/// Returns an error if the day is not implemented.
+pub fn find(day: u8) -> Result<&'static Solution> {
+    SOLUTIONS
+        .iter()
+        .find(|solution| solution.day == day)
+        .with_context(|| format!("Day {day} is not implemented"))
+}
@@ -0,0 +1,230 @@
+//! Interactive terminal dashboard for running and inspecting solutions.
+//!
+//! Lists every registered day with its status, runs individual parts with
+//! timings, keeps a scrollable log of run outcomes (answers, timings and
+//! error chains), and previews the selected day's input (useful for
+//! eyeballing grid puzzles).
+
+use crate::registry::SOLUTIONS;
+use anyhow::Result;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, List, ListItem, ListState, Paragraph};
+use ratatui::{DefaultTerminal, Frame};
+use shared::input::read_input;
+use shared::runner::{run_solver, RunOutcome};
+use std::time::Duration;
+
+/// Maximum number of input lines shown in the preview pane.
+const PREVIEW_LINES: usize = 200;
+
+/// Number of log lines scrolled per page key press.
+const SCROLL_STEP: u16 = 5;
+
+/// Dashboard state: selection, cached inputs, run results and outcome log.
+struct App {
+    list_state: ListState,
+    inputs: Vec<Option<String>>,
+    results: Vec<[Option<bool>; 2]>,
+    log: Vec<String>,
+    scroll: u16,
+    /// Rows of log text visible in the last drawn frame
+    log_height: u16,
+    timeout: Option<Duration>,
+}
+
+/// Runs the dashboard until the user quits.
+///
+/// # Parameters
+/// * `timeout` - Per-solver time limit, or `None` to wait forever
+///
+/// # Errors
+///
+/// Returns an error if the terminal cannot be drawn or read.
+pub fn run(timeout: Option<Duration>) -> Result<()> {
+    let mut terminal = ratatui::init();
+    let result = App::new(timeout).run(&mut terminal);
+    ratatui::restore();
+    result
+}
+
+impl App {
+    fn new(timeout: Option<Duration>) -> Self {
+        App {
+            list_state: ListState::default().with_selected(Some(0)),
+            inputs: SOLUTIONS
+                .iter()
+                .map(|solution| read_input(solution.day).ok())
+                .collect(),
+            results: vec![[None; 2]; SOLUTIONS.len()],
+            log: Vec::new(),
+            scroll: 0,
+            log_height: 0,
+            timeout,
+        }
+    }
+
+    fn run(mut self, terminal: &mut DefaultTerminal) -> Result<()> {
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Down | KeyCode::Char('j') => self.list_state.select_next(),
+                KeyCode::Up | KeyCode::Char('k') => self.list_state.select_previous(),
+                KeyCode::Char('1') => self.run_part(1),
+                KeyCode::Char('2') => self.run_part(2),
+                KeyCode::Char('a') => {
+                    self.run_part(1);
+                    self.run_part(2);
+                }
+                KeyCode::PageDown => {
+                    self.scroll = self
+                        .scroll
+                        .saturating_add(SCROLL_STEP)
+                        .min(self.max_scroll());
+                }
+                KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(SCROLL_STEP),
+                KeyCode::Char('c') => {
+                    self.log.clear();
+                    self.scroll = 0;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Largest scroll offset that still fills the log pane.
+    fn max_scroll(&self) -> u16 {
+        let lines: usize = self.log.iter().map(|entry| entry.lines().count()).sum();
+        u16::try_from(lines)
+            .unwrap_or(u16::MAX)
+            .saturating_sub(self.log_height)
+    }
+
+    /// Index of the selected day, clamped to the registry bounds.
+    fn selected(&self) -> usize {
+        self.list_state
+            .selected()
+            .unwrap_or(0)
+            .min(SOLUTIONS.len() - 1)
+    }
+
+    /// Runs one part of the selected day on its real input (or the example
+    /// when no input file exists) and appends the outcome to the log.
+    fn run_part(&mut self, part: u8) {
+        let index = self.selected();
+        let solution = &SOLUTIONS[index];
+        let Some(solver) = solution.solver(part) else {
+            self.log.push(format!(
+                "Day {:02} part {part}: not implemented",
+                solution.day
+            ));
+            return;
+        };
+
+        let (label, input) = match &self.inputs[index] {
+            Some(input) => ("input", input.as_str()),
+            None => ("example", solution.example_input(part)),
+        };
+        let outcome = run_solver(solver, input, self.timeout);
+        self.results[index][usize::from(part - 1)] =
+            Some(matches!(outcome, RunOutcome::Solved { .. }));
+        self.log.push(format!(
+            "Day {:02} part {part} ({label}): {outcome}",
+            solution.day
+        ));
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, help] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+        let [days_area, right] =
+            Layout::horizontal([Constraint::Length(44), Constraint::Min(0)]).areas(main);
+        let [preview_area, log_area] =
+            Layout::vertical([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(right);
+
+        let items: Vec<ListItem> = (0..SOLUTIONS.len())
+            .map(|index| ListItem::new(self.status_line(index)))
+            .collect();
+        let days = List::new(items)
+            .block(Block::bordered().title(" Days "))
+            .highlight_style(Style::new().reversed())
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(days, days_area, &mut self.list_state);
+
+        let index = self.selected();
+        let (title, preview) = match &self.inputs[index] {
+            Some(input) => {
+                let lines: Vec<&str> = input.lines().collect();
+                let width = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+                (
+                    format!(" Input preview ({} lines, {width} wide) ", lines.len()),
+                    lines[..lines.len().min(PREVIEW_LINES)].join("\n"),
+                )
+            }
+            None => (
+                " Example preview (no input.txt) ".to_string(),
+                SOLUTIONS[index].example_input.to_string(),
+            ),
+        };
+        frame.render_widget(
+            Paragraph::new(preview).block(Block::bordered().title(title)),
+            preview_area,
+        );
+
+        // The pane may have shrunk since the offset was last clamped
+        self.log_height = log_area.height.saturating_sub(2);
+        self.scroll = self.scroll.min(self.max_scroll());
+        frame.render_widget(
+            Paragraph::new(self.log.join("\n"))
+                .block(Block::bordered().title(" Outcomes "))
+                .scroll((self.scroll, 0)),
+            log_area,
+        );
+
+        frame.render_widget(
+            Line::from("↑/↓ select  1/2 run part  a run both  PgUp/PgDn scroll  c clear  q quit")
+                .dim(),
+            help,
+        );
+    }
+
+    /// Formats a day's list entry: number, title, part results and input
+    /// availability.
+    fn status_line(&self, index: usize) -> String {
+        let solution = &SOLUTIONS[index];
+        let marks: Vec<&str> = (1..=2u8)
+            .map(|part| {
+                match (
+                    solution.solver(part),
+                    self.results[index][usize::from(part - 1)],
+                ) {
+                    (None, _) => "·",
+                    (Some(_), None) => "○",
+                    (Some(_), Some(true)) => "✓",
+                    (Some(_), Some(false)) => "✗",
+                }
+            })
+            .collect();
+        let input = if self.inputs[index].is_some() {
+            "input"
+        } else {
+            "no input"
+        };
+        format!(
+            "{:02} {:<20} {} {input}",
+            solution.day,
+            solution.title,
+            marks.join(" ")
+        )
+    }
+}
@@ -1,11 +1,24 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use day01::{solve_part2, solve_part2_naive};
+use day01::{
+    solve_part1, solve_part1_btree, solve_part1_counting, solve_part1_heap, solve_part1_naive,
+    solve_part1_reusing, solve_part2, solve_part2_naive,
+};
 use shared::benchmarking::{
-    create_criterion_benchmark, process_benchmark_results, run_dual_algorithm_benchmark, Algorithm,
-    PlotConfig, TestConfig,
+    create_criterion_benchmark, process_benchmark_results, run_algorithm_benchmark, Algorithm,
+    AxisScale, PlotConfig, TestConfig,
 };
+use std::cell::RefCell;
+
+#[cfg(feature = "alloc-tracking")]
+#[global_allocator]
+static ALLOCATOR: shared::alloc_tracking::TrackingAllocator =
+    shared::alloc_tracking::TrackingAllocator;
 
 const SIZES: [usize; 6] = [500, 1000, 2000, 5000, 8000, 12000];
+const SMALL_SIZES: [usize; 6] = [10, 50, 100, 250, 500, 1000];
+const SORT_SIZES: [usize; 6] = [1000, 5000, 10000, 50000, 100000, 200000];
+#[cfg(feature = "parallel")]
+const PARALLEL_SIZES: [usize; 7] = [1000, 5000, 10000, 50000, 100000, 500000, 1000000];
 
 /// Criterion benchmark with JSON extraction and co-located output
 fn benchmark_algorithms(c: &mut Criterion) {
@@ -13,38 +26,306 @@ fn benchmark_algorithms(c: &mut Criterion) {
     let group_name = "criterion";
 
     // Algorithm definitions
-    let algorithm1 = Algorithm {
-        name: "hashmap",
-        function: solve_part2 as fn(&str) -> _,
-    };
-    let algorithm2 = Algorithm {
-        name: "naive",
-        function: solve_part2_naive as fn(&str) -> _,
-    };
+    let algorithms = [
+        Algorithm::new("hashmap", solve_part2),
+        Algorithm::new("naive", solve_part2_naive),
+    ];
+
+    // The real puzzle input is benchmarked too when it is present
+    let real_input = std::fs::read_to_string("input.txt").ok();
 
     // Test configuration
     let test_config = TestConfig {
         sizes: &SIZES,
         generate_input: generate_test_input,
+        verify_outputs: true,
+        settings: None,
+        real_input: real_input.as_deref(),
+        cold_start: true,
     };
 
-    // Run the benchmark
-    run_dual_algorithm_benchmark(c, group_name, &algorithm1, &algorithm2, &test_config);
+    // Run the benchmark unless the stored results are still current
+    run_algorithm_benchmark(c, data_dir, group_name, &algorithms, &test_config)
+        .expect("Benchmarked algorithms must agree on every input");
 
     // Process results and generate outputs
     let plot_config = PlotConfig {
         filename: "hashmap_vs_naive.svg",
         title: "Day 1: HashMap vs Naive Algorithm Performance",
-        algorithm1_name: "O(n) HashMap Solution",
-        algorithm2_name: "O(n²) Naive Algorithm",
+        algorithm_names: &["O(n) HashMap Solution", "O(n²) Naive Algorithm"],
         x_axis_label: "Number of Sequences (n)",
+        y_scale: AxisScale::Log,
+        speedup_filename: Some("hashmap_vs_naive_speedup.svg"),
+        distribution_filename: Some("hashmap_vs_naive_distribution.svg"),
+        fit_overlay: true,
+        part: Some(2),
+        ..PlotConfig::default()
+    };
+
+    process_benchmark_results(
+        data_dir,
+        group_name,
+        &algorithms,
+        &plot_config,
+        &test_config,
+    );
+}
+
+/// Benchmark: Part 1 Sort vs Naive
+/// Shows O(n log n) sorting vs O(n²) repeated minimum extraction, mirroring
+/// the Part 2 comparison
+fn benchmark_part1_naive(c: &mut Criterion) {
+    let data_dir = "data";
+    let group_name = "part1_sort_vs_naive";
+
+    let algorithms = [
+        Algorithm::new("sort", solve_part1),
+        Algorithm::new("naive", solve_part1_naive),
+    ];
+
+    let real_input = std::fs::read_to_string("input.txt").ok();
+
+    let test_config = TestConfig {
+        sizes: &SIZES,
+        generate_input: generate_test_input,
+        verify_outputs: true,
+        settings: None,
+        real_input: real_input.as_deref(),
+        cold_start: false,
+    };
+
+    run_algorithm_benchmark(c, data_dir, group_name, &algorithms, &test_config)
+        .expect("Benchmarked algorithms must agree on every input");
+
+    let plot_config = PlotConfig {
+        filename: "sort_vs_naive.svg",
+        title: "Day 1: Sort vs Naive Part 1 Performance",
+        algorithm_names: &["O(n log n) Sort Solution", "O(n²) Naive Algorithm"],
+        x_axis_label: "Number of Pairs (n)",
+        y_scale: AxisScale::Log,
+        speedup_filename: Some("sort_vs_naive_speedup.svg"),
+        fit_overlay: true,
+        part: Some(1),
+        ..PlotConfig::default()
+    };
+
+    process_benchmark_results(
+        data_dir,
+        group_name,
+        &algorithms,
+        &plot_config,
+        &test_config,
+    );
+}
+
+/// Benchmark: Part 1 sorting
+/// Compares the comparison sort of `sort_unstable` with a counting sort over
+/// the bounded value range
+fn benchmark_part1_sorting(c: &mut Criterion) {
+    let data_dir = "data";
+    let group_name = "part1_sorting";
+
+    let algorithms = [
+        Algorithm::new("sort_unstable", solve_part1),
+        Algorithm::new("counting", solve_part1_counting),
+    ];
+
+    let real_input = std::fs::read_to_string("input.txt").ok();
+
+    let test_config = TestConfig {
+        sizes: &SORT_SIZES,
+        generate_input: generate_test_input,
+        verify_outputs: true,
+        settings: None,
+        real_input: real_input.as_deref(),
+        cold_start: false,
+    };
+
+    run_algorithm_benchmark(c, data_dir, group_name, &algorithms, &test_config)
+        .expect("Benchmarked algorithms must agree on every input");
+
+    let plot_config = PlotConfig {
+        filename: "counting_vs_comparison_sort.svg",
+        title: "Day 1: Counting Sort vs sort_unstable Performance",
+        algorithm_names: &["O(n log n) sort_unstable", "O(n + k) Counting Sort"],
+        x_axis_label: "Number of Pairs (n)",
+        x_scale: AxisScale::Log,
+        y_scale: AxisScale::Log,
+        speedup_filename: Some("counting_vs_comparison_sort_speedup.svg"),
+        fit_overlay: true,
+        part: Some(1),
+        ..PlotConfig::default()
+    };
+
+    process_benchmark_results(
+        data_dir,
+        group_name,
+        &algorithms,
+        &plot_config,
+        &test_config,
+    );
+}
+
+/// Benchmark: Part 1 data structures
+/// Compares pairing the sorted lists via a full sort, a binary heap and a
+/// BTreeMap multiset
+fn benchmark_data_structures(c: &mut Criterion) {
+    let data_dir = "data";
+    let group_name = "part1_data_structures";
+
+    let algorithms = [
+        Algorithm::new("sort", solve_part1),
+        Algorithm::new("heap", solve_part1_heap),
+        Algorithm::new("btree", solve_part1_btree),
+    ];
+
+    let real_input = std::fs::read_to_string("input.txt").ok();
+
+    let test_config = TestConfig {
+        sizes: &SORT_SIZES,
+        generate_input: generate_test_input,
+        verify_outputs: true,
+        settings: None,
+        real_input: real_input.as_deref(),
+        cold_start: false,
+    };
+
+    run_algorithm_benchmark(c, data_dir, group_name, &algorithms, &test_config)
+        .expect("Benchmarked algorithms must agree on every input");
+
+    let plot_config = PlotConfig {
+        filename: "data_structures.svg",
+        title: "Day 1: Sort vs BinaryHeap vs BTreeMap Part 1 Performance",
+        algorithm_names: &["Full Sort", "BinaryHeap Pairing", "BTreeMap Multiset"],
+        x_axis_label: "Number of Pairs (n)",
+        x_scale: AxisScale::Log,
+        y_scale: AxisScale::Log,
+        speedup_filename: Some("data_structures_speedup.svg"),
+        fit_overlay: true,
+        part: Some(1),
+        ..PlotConfig::default()
     };
 
     process_benchmark_results(
         data_dir,
         group_name,
-        &algorithm1,
-        &algorithm2,
+        &algorithms,
+        &plot_config,
+        &test_config,
+    );
+}
+
+/// Benchmark: Sequential vs Parallel
+/// Shows the input size from which rayon's thread pool overhead pays off, for
+/// both parts
+#[cfg(feature = "parallel")]
+fn benchmark_parallel(c: &mut Criterion) {
+    use day01::{solve_part1_parallel, solve_part2_parallel};
+
+    let data_dir = "data";
+    let groups = [
+        (
+            "part1_parallel",
+            1,
+            [
+                Algorithm::new("sequential", solve_part1),
+                Algorithm::new("parallel", solve_part1_parallel),
+            ],
+        ),
+        (
+            "part2_parallel",
+            2,
+            [
+                Algorithm::new("sequential", solve_part2),
+                Algorithm::new("parallel", solve_part2_parallel),
+            ],
+        ),
+    ];
+
+    for (group_name, part, algorithms) in groups {
+        let test_config = TestConfig {
+            sizes: &PARALLEL_SIZES,
+            generate_input: generate_test_input,
+            verify_outputs: true,
+            settings: None,
+            real_input: None,
+            cold_start: false,
+        };
+
+        run_algorithm_benchmark(c, data_dir, group_name, &algorithms, &test_config)
+            .expect("Benchmarked algorithms must agree on every input");
+
+        let filename = format!("{group_name}.svg");
+        let speedup_filename = format!("{group_name}_speedup.svg");
+        let title = format!("Day 1: Sequential vs Parallel Part {part} Performance");
+        let plot_config = PlotConfig {
+            filename: &filename,
+            title: &title,
+            algorithm_names: &["Sequential", "Rayon Parallel"],
+            x_axis_label: "Number of Pairs (n)",
+            x_scale: AxisScale::Log,
+            y_scale: AxisScale::Log,
+            speedup_filename: Some(&speedup_filename),
+            part: Some(part),
+            ..PlotConfig::default()
+        };
+
+        process_benchmark_results(
+            data_dir,
+            group_name,
+            &algorithms,
+            &plot_config,
+            &test_config,
+        );
+    }
+}
+
+/// Benchmark: Fresh vs Reused Buffers
+/// Shows how much of Part 1 on small inputs is spent allocating the parsed
+/// columns
+fn benchmark_buffer_reuse(c: &mut Criterion) {
+    let data_dir = "data";
+    let group_name = "part1_buffer_reuse";
+
+    // Buffers outlive every call, so only the first call grows them
+    let buffers = RefCell::new((Vec::new(), Vec::new()));
+    let algorithms = [
+        Algorithm::new("allocating", solve_part1),
+        Algorithm::new("reused_buffers", |input: &str| {
+            let (left_nums, right_nums) = &mut *buffers.borrow_mut();
+            solve_part1_reusing(input, left_nums, right_nums)
+        }),
+    ];
+
+    let real_input = std::fs::read_to_string("input.txt").ok();
+
+    let test_config = TestConfig {
+        sizes: &SMALL_SIZES,
+        generate_input: generate_test_input,
+        verify_outputs: true,
+        settings: None,
+        real_input: real_input.as_deref(),
+        cold_start: false,
+    };
+
+    run_algorithm_benchmark(c, data_dir, group_name, &algorithms, &test_config)
+        .expect("Benchmarked algorithms must agree on every input");
+
+    let plot_config = PlotConfig {
+        filename: "buffer_reuse.svg",
+        title: "Day 1: Allocating vs Reused Buffers Part 1 Performance",
+        algorithm_names: &["Fresh Vectors per Call", "Reused Caller Buffers"],
+        x_axis_label: "Number of Pairs (n)",
+        x_scale: AxisScale::Log,
+        y_scale: AxisScale::Log,
+        part: Some(1),
+        ..PlotConfig::default()
+    };
+
+    process_benchmark_results(
+        data_dir,
+        group_name,
+        &algorithms,
         &plot_config,
         &test_config,
     );
@@ -84,9 +365,16 @@ fn generate_test_input(size: usize) -> String {
         .join("\n")
 }
 
+#[cfg(not(feature = "parallel"))]
+criterion_group!(
+    name = benches;
+    config = create_criterion_benchmark("data");
+    targets = benchmark_algorithms, benchmark_part1_naive, benchmark_part1_sorting, benchmark_data_structures, benchmark_buffer_reuse
+);
+#[cfg(feature = "parallel")]
 criterion_group!(
     name = benches;
     config = create_criterion_benchmark("data");
-    targets = benchmark_algorithms
+    targets = benchmark_algorithms, benchmark_part1_naive, benchmark_part1_sorting, benchmark_data_structures, benchmark_buffer_reuse, benchmark_parallel
 );
 criterion_main!(benches);
@@ -7,10 +7,35 @@
 //!
 //! Part 2: Calculate similarity score by multiplying each number in the left
 //! list by how many times it appears in the right list, then summing.
+//!
+//! Values may be any `i32`, negative ones included, and are widened to
+//! `i64` before any arithmetic, so no absolute distance can overflow; a
+//! squared distance can, and [`total_distance_with`] reports it. Answers are
+//! `i64`, since large benchmark inputs push the similarity score past
+//! `i32::MAX`. A negative value adds a negative amount to the similarity
+//! score, and a score beyond `i64` is an error rather than a wrap or panic.
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use itertools::Itertools;
-use rustc_hash::FxHashMap;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use shared::benchmarking::percentile;
+use shared::counter::Counter;
+use shared::input::{parse_pair, stream_lines_with, try_for_each_line, Delimiter};
+use shared::plotting::{bin_values, create_histogram, PlotTheme};
+use shared::runner::{Solution, Variant};
+use shared::validate::check_lines;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap};
+use std::io::BufRead;
+
+/// Widest value range [`solve_part1_counting`] sorts by counting; wider
+/// inputs fall back to a comparison sort, since the count table would dwarf
+/// the input.
+const COUNTING_SORT_MAX_SPAN: u64 = 1 << 20;
+
+/// Number of buckets in a [`DistanceReport`] histogram.
+pub const DISTANCE_BUCKETS: usize = 10;
 
 /// Example input from the problem statement used for testing and documentation.
 pub const EXAMPLE_INPUT: &str = "3   4
@@ -20,6 +45,136 @@ pub const EXAMPLE_INPUT: &str = "3   4
 3   9
 3   3";
 
+/// How far apart two paired values are, for [`total_distance_with`].
+#[derive(Debug, Clone, Copy)]
+pub enum DistanceMetric {
+    /// `|left - right|`, the puzzle's distance (L1)
+    Absolute,
+    /// `(left - right)²`, weighting large gaps more heavily (squared L2)
+    Squared,
+    /// Any other distance between a left and a right value
+    Custom(fn(i64, i64) -> i64),
+}
+
+impl DistanceMetric {
+    /// Returns the distance between a left and a right value, or `None` if
+    /// it does not fit in `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use day01::DistanceMetric;
+    /// assert_eq!(DistanceMetric::Absolute.distance(1, 4), Some(3));
+    /// assert_eq!(DistanceMetric::Squared.distance(1, 4), Some(9));
+    /// assert_eq!(DistanceMetric::Custom(|left, right| left.max(right)).distance(1, 4), Some(4));
+    /// // Two i32 extremes are 2^32 - 1 apart, whose square exceeds i64::MAX
+    /// let (min, max) = (i64::from(i32::MIN), i64::from(i32::MAX));
+    /// assert_eq!(DistanceMetric::Squared.distance(min, max), None);
+    /// ```
+    pub fn distance(self, left: i64, right: i64) -> Option<i64> {
+        let difference = left.checked_sub(right);
+        match self {
+            DistanceMetric::Absolute => difference?.checked_abs(),
+            DistanceMetric::Squared => difference?.checked_pow(2),
+            DistanceMetric::Custom(distance) => Some(distance(left, right)),
+        }
+    }
+}
+
+/// Statistics of the distances between sorted pairs, explaining the Part 1
+/// answer.
+///
+/// # Attributes
+/// * `differences` - `|left - right|` of each pair, smallest pair first;
+///   they sum to the Part 1 answer
+/// * `min` - Smallest difference
+/// * `max` - Largest difference
+/// * `mean` - Average difference
+/// * `median` - Middle difference, averaging the two middle ones for an
+///   even number of pairs
+/// * `buckets` - `(start, end, count)` of [`DISTANCE_BUCKETS`] equally wide
+///   ranges of differences, ready to draw as a histogram
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistanceReport {
+    pub differences: Vec<i64>,
+    pub min: i64,
+    pub max: i64,
+    pub mean: f64,
+    pub median: f64,
+    pub buckets: Vec<(f64, f64, usize)>,
+}
+
+impl DistanceReport {
+    /// Draws the histogram of the differences with the shared plotter.
+    ///
+    /// # Parameters
+    /// * `filename` - Output filename; its extension selects SVG or PNG
+    /// * `theme` - Dimensions, colors and fonts of the chart
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the chart cannot be written.
+    pub fn plot(&self, filename: &str, theme: &PlotTheme) -> Result<()> {
+        let values: Vec<f64> = self.differences.iter().map(|&diff| diff as f64).collect();
+        create_histogram(
+            filename,
+            "Day 1: Pair Distance Distribution",
+            &values,
+            DISTANCE_BUCKETS,
+            theme,
+        )
+    }
+}
+
+/// Registry entry used by the workspace runner.
+pub const SOLUTION: Solution = Solution {
+    day: 1,
+    title: "Historian Hysteria",
+    example_input: EXAMPLE_INPUT,
+    example_input_part2: None,
+    part1: |input| Ok(solve_part1(input)?.to_string()),
+    part2: Some(|input| Ok(solve_part2(input)?.to_string())),
+    variants: &[
+        Variant {
+            name: "naive",
+            part: 1,
+            solver: |input| Ok(solve_part1_naive(input)?.to_string()),
+        },
+        Variant {
+            name: "counting",
+            part: 1,
+            solver: |input| Ok(solve_part1_counting(input)?.to_string()),
+        },
+        Variant {
+            name: "heap",
+            part: 1,
+            solver: |input| Ok(solve_part1_heap(input)?.to_string()),
+        },
+        Variant {
+            name: "btree",
+            part: 1,
+            solver: |input| Ok(solve_part1_btree(input)?.to_string()),
+        },
+        Variant {
+            name: "streaming",
+            part: 1,
+            solver: |input| Ok(solve_part1_streaming(input.as_bytes())?.to_string()),
+        },
+        Variant {
+            name: "streaming",
+            part: 2,
+            solver: |input| Ok(solve_part2_streaming(input.as_bytes())?.to_string()),
+        },
+        Variant {
+            name: "naive",
+            part: 2,
+            solver: |input| Ok(solve_part2_naive(input)?.to_string()),
+        },
+    ],
+    validate: Some(|input| check_lines(input, parse_line)),
+    explain: None,
+};
+
 /// Solves Part 1: Calculates the total distance between the left and right
 /// lists.
 ///
@@ -45,23 +200,403 @@ pub const EXAMPLE_INPUT: &str = "3   4
 /// assert_eq!(solve_part1(input).unwrap(), 5);
 /// // |1-3| + |2-5| = 2 + 3 = 5
 /// ```
-pub fn solve_part1(input: &str) -> Result<i32> {
-    let (mut left_nums, mut right_nums) = parse_input(input)?;
+pub fn solve_part1(input: &str) -> Result<i64> {
+    let (left_nums, right_nums) = parse_input(input)?;
 
+    total_distance_with(left_nums, right_nums, DistanceMetric::Absolute)
+}
+
+/// Sums the distances between two lists paired up smallest to smallest.
+///
+/// Sorts both lists independently and adds up the metric's distance between
+/// corresponding elements. Part 1 is the [`DistanceMetric::Absolute`] case;
+/// other metrics compare lists in other ways.
+///
+/// # Parameters
+/// * `left_nums` - First list, in any order
+/// * `right_nums` - Second list, in any order; extra elements of the longer
+///   list are left unpaired
+/// * `metric` - Distance between two paired values
+///
+/// # Returns
+/// Sum of the metric over all sorted pairs
+///
+/// # Errors
+///
+/// Returns an error if a distance or the sum does not fit in `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # use day01::{total_distance_with, DistanceMetric};
+/// let (left, right) = (vec![3, 1], vec![5, 2]);
+/// // Sorted pairs (1, 2) and (3, 5)
+/// assert_eq!(total_distance_with(left.clone(), right.clone(), DistanceMetric::Absolute).unwrap(), 3);
+/// assert_eq!(total_distance_with(left.clone(), right.clone(), DistanceMetric::Squared).unwrap(), 5);
+/// // Count the pairs that differ at all
+/// let differs = DistanceMetric::Custom(|left, right| i64::from(left != right));
+/// assert_eq!(total_distance_with(left, right, differs).unwrap(), 2);
+/// ```
+pub fn total_distance_with(
+    mut left_nums: Vec<i64>,
+    mut right_nums: Vec<i64>,
+    metric: DistanceMetric,
+) -> Result<i64> {
     // Sort both lists
     left_nums.sort_unstable();
     right_nums.sort_unstable();
 
-    // Calculate total distance using functional style
-    let total_distance = left_nums
+    left_nums
+        .iter()
+        .zip(right_nums.iter())
+        .try_fold(0i64, |total, (&left, &right)| {
+            metric
+                .distance(left, right)
+                .and_then(|distance| total.checked_add(distance))
+        })
+        .context("Total distance overflows i64")
+}
+
+/// Solves Part 1 reusing caller-provided buffers for the parsed columns.
+///
+/// Same algorithm as [`solve_part1`], but parses with [`parse_input_into`]
+/// and sorts in place, so repeated calls with the same buffers allocate
+/// nothing once the buffers have grown to the input size.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing integer pairs
+///   (whitespace-separated)
+/// * `left_nums` - Scratch buffer for the left column
+/// * `right_nums` - Scratch buffer for the right column
+///
+/// # Returns
+/// Total distance as the sum of absolute differences between sorted pairs
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day01::solve_part1_reusing;
+/// let (mut left, mut right) = (Vec::new(), Vec::new());
+/// assert_eq!(solve_part1_reusing("1 3\n2 5", &mut left, &mut right).unwrap(), 5);
+/// assert_eq!(solve_part1_reusing("4 4", &mut left, &mut right).unwrap(), 0);
+/// ```
+pub fn solve_part1_reusing(
+    input: &str,
+    left_nums: &mut Vec<i64>,
+    right_nums: &mut Vec<i64>,
+) -> Result<i64> {
+    parse_input_into(input, left_nums, right_nums)?;
+    left_nums.sort_unstable();
+    right_nums.sort_unstable();
+
+    Ok(left_nums
         .iter()
         .zip(right_nums.iter())
         .map(|(&left, &right)| (left - right).abs())
+        .sum())
+}
+
+/// Naive O(n²) implementation of Part 1 for performance comparison.
+///
+/// Pairs the lists without sorting them first: repeatedly scans both lists
+/// for their smallest remaining values, pairs those and removes them.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing integer pairs
+///   (whitespace-separated)
+///
+/// # Returns
+/// Total distance calculated using the naive O(n²) algorithm
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day01::solve_part1_naive;
+/// let input = "1 3\n2 5";
+/// assert_eq!(solve_part1_naive(input).unwrap(), 5); // Same result as
+///                                                    // solve_part1
+/// ```
+pub fn solve_part1_naive(input: &str) -> Result<i64> {
+    let (mut left_nums, mut right_nums) = parse_input(input)?;
+
+    let mut total_distance = 0;
+    while let (Some(left), Some(right)) = (take_min(&mut left_nums), take_min(&mut right_nums)) {
+        total_distance += (left - right).abs();
+    }
+
+    Ok(total_distance)
+}
+
+/// Removes and returns the smallest value by scanning the whole list.
+fn take_min(nums: &mut Vec<i64>) -> Option<i64> {
+    let index = nums.iter().position_min()?;
+    Some(nums.swap_remove(index))
+}
+
+/// Counting sort implementation of Part 1 for performance comparison.
+///
+/// Real inputs hold values below 100000, so both lists are sorted in O(n + k)
+/// by counting occurrences over the value range instead of comparing
+/// values. Lists spanning more than [`COUNTING_SORT_MAX_SPAN`] values fall
+/// back to `sort_unstable`.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing integer pairs
+///   (whitespace-separated)
+///
+/// # Returns
+/// Total distance as the sum of absolute differences between sorted pairs
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day01::solve_part1_counting;
+/// let input = "1 3\n2 5";
+/// assert_eq!(solve_part1_counting(input).unwrap(), 5); // Same result as
+///                                                       // solve_part1
+/// ```
+pub fn solve_part1_counting(input: &str) -> Result<i64> {
+    let (left_nums, right_nums) = parse_input(input)?;
+
+    let total_distance = counting_sort(left_nums)
+        .iter()
+        .zip(counting_sort(right_nums).iter())
+        .map(|(&left, &right)| (left - right).abs())
         .sum();
 
     Ok(total_distance)
 }
 
+/// Sorts values by counting how often each value in their range occurs.
+///
+/// # Parameters
+/// * `values` - Values to sort
+///
+/// # Returns
+/// The values in ascending order
+fn counting_sort(mut values: Vec<i64>) -> Vec<i64> {
+    let (Some(&min), Some(&max)) = (values.iter().min(), values.iter().max()) else {
+        return values;
+    };
+    let span = max.abs_diff(min);
+    if span >= COUNTING_SORT_MAX_SPAN {
+        values.sort_unstable();
+        return values;
+    }
+
+    let mut counts = vec![0; span as usize + 1];
+    for &value in &values {
+        counts[value.abs_diff(min) as usize] += 1;
+    }
+    let mut start = 0;
+    for (offset, &count) in counts.iter().enumerate() {
+        values[start..start + count].fill(min + offset as i64);
+        start += count;
+    }
+    values
+}
+
+/// Binary heap implementation of Part 1 for performance comparison.
+///
+/// Builds a min-heap from each list in O(n) and pairs the lists by popping
+/// both heaps in lockstep, so each value is ordered lazily as it is paired.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing integer pairs
+///   (whitespace-separated)
+///
+/// # Returns
+/// Total distance as the sum of absolute differences between sorted pairs
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day01::solve_part1_heap;
+/// let input = "1 3\n2 5";
+/// assert_eq!(solve_part1_heap(input).unwrap(), 5); // Same result as
+///                                                   // solve_part1
+/// ```
+pub fn solve_part1_heap(input: &str) -> Result<i64> {
+    let (left_nums, right_nums) = parse_input(input)?;
+
+    let mut left_heap: BinaryHeap<Reverse<i64>> = left_nums.into_iter().map(Reverse).collect();
+    let mut right_heap: BinaryHeap<Reverse<i64>> = right_nums.into_iter().map(Reverse).collect();
+
+    let mut total_distance = 0;
+    while let (Some(Reverse(left)), Some(Reverse(right))) = (left_heap.pop(), right_heap.pop()) {
+        total_distance += (left - right).abs();
+    }
+
+    Ok(total_distance)
+}
+
+/// BTreeMap multiset implementation of Part 1 for performance comparison.
+///
+/// Counts each list into an ordered map from value to occurrences and walks
+/// both maps in key order, pairing values run by run instead of one by one.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing integer pairs
+///   (whitespace-separated)
+///
+/// # Returns
+/// Total distance as the sum of absolute differences between sorted pairs
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day01::solve_part1_btree;
+/// let input = "1 3\n2 5";
+/// assert_eq!(solve_part1_btree(input).unwrap(), 5); // Same result as
+///                                                    // solve_part1
+/// ```
+pub fn solve_part1_btree(input: &str) -> Result<i64> {
+    let (left_nums, right_nums) = parse_input(input)?;
+
+    let mut left_runs = multiset(left_nums).into_iter();
+    let mut right_runs = multiset(right_nums).into_iter();
+
+    let mut total_distance = 0;
+    let (mut left, mut right) = (left_runs.next(), right_runs.next());
+    while let (Some((left_value, left_count)), Some((right_value, right_count))) =
+        (left.as_mut(), right.as_mut())
+    {
+        let paired = (*left_count).min(*right_count);
+        total_distance += (*left_value - *right_value).abs() * paired as i64;
+        *left_count -= paired;
+        *right_count -= paired;
+        if *left_count == 0 {
+            left = left_runs.next();
+        }
+        if *right_count == 0 {
+            right = right_runs.next();
+        }
+    }
+
+    Ok(total_distance)
+}
+
+/// Counts how often each value occurs, ordered by value.
+fn multiset(values: Vec<i64>) -> BTreeMap<i64, usize> {
+    let mut counts = BTreeMap::new();
+    for value in values {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+    counts
+}
+
+/// Returns the pairs Part 1 sums over, with each pair's distance.
+///
+/// Shows which pairings dominate the total distance, where [`solve_part1`]
+/// only reports the sum.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing integer pairs
+///   (whitespace-separated)
+///
+/// # Returns
+/// `(left, right, |left - right|)` tuples, pairing both lists smallest to
+/// smallest
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day01::sorted_pairs;
+/// let pairs = sorted_pairs("3 4\n1 9").unwrap();
+/// assert_eq!(pairs, vec![(1, 4, 3), (3, 9, 6)]);
+/// // The largest distance dominates the total
+/// let largest = pairs.iter().max_by_key(|&&(_, _, diff)| diff).unwrap();
+/// assert_eq!(*largest, (3, 9, 6));
+/// ```
+pub fn sorted_pairs(input: &str) -> Result<Vec<(i64, i64, i64)>> {
+    let (mut left_nums, mut right_nums) = parse_input(input)?;
+    left_nums.sort_unstable();
+    right_nums.sort_unstable();
+
+    Ok(left_nums
+        .into_iter()
+        .zip(right_nums)
+        .map(|(left, right)| (left, right, (left - right).abs()))
+        .collect())
+}
+
+/// Describes how the Part 1 total distance is distributed over the pairs.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing integer pairs
+///   (whitespace-separated)
+///
+/// # Returns
+/// The per-pair differences with their summary statistics and histogram
+/// buckets
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails or the input holds no pairs.
+///
+/// # Examples
+///
+/// ```
+/// # use day01::{distance_report, EXAMPLE_INPUT};
+/// let report = distance_report(EXAMPLE_INPUT).unwrap();
+/// assert_eq!(report.differences, vec![2, 1, 0, 1, 2, 5]);
+/// assert_eq!((report.min, report.max, report.median), (0, 5, 1.5));
+/// assert_eq!(report.buckets.iter().map(|&(_, _, count)| count).sum::<usize>(), 6);
+/// ```
+pub fn distance_report(input: &str) -> Result<DistanceReport> {
+    let pairs = sorted_pairs(input)?;
+    if pairs.is_empty() {
+        bail!("No pairs to report on");
+    }
+
+    let differences: Vec<i64> = pairs.iter().map(|&(_, _, diff)| diff).collect();
+    let mut sorted: Vec<f64> = differences.iter().map(|&diff| diff as f64).collect();
+    sorted.sort_by(f64::total_cmp);
+
+    let (start, bucket_width, counts) = bin_values(&sorted, DISTANCE_BUCKETS)?;
+    let buckets = counts
+        .into_iter()
+        .enumerate()
+        .map(|(bucket, count)| {
+            let lower = start + bucket_width * bucket as f64;
+            (lower, lower + bucket_width, count)
+        })
+        .collect();
+
+    Ok(DistanceReport {
+        min: *differences.iter().min().unwrap_or(&0),
+        max: *differences.iter().max().unwrap_or(&0),
+        mean: sorted.iter().sum::<f64>() / sorted.len() as f64,
+        median: percentile(&sorted, 50.0).unwrap_or_default(),
+        buckets,
+        differences,
+    })
+}
+
 /// Solves Part 2: Calculates a similarity score based on frequency matching.
 ///
 /// For each unique number in the left list, multiplies the number by its
@@ -79,7 +614,8 @@ pub fn solve_part1(input: &str) -> Result<i32> {
 ///
 /// # Errors
 ///
-/// Returns an error if input parsing fails.
+/// Returns an error if input parsing fails or the score does not fit in
+/// an `i64`.
 ///
 /// # Examples
 ///
@@ -91,54 +627,172 @@ pub fn solve_part1(input: &str) -> Result<i32> {
 /// // 2 appears 0 times in right list: 2*0 = 0
 /// assert_eq!(solve_part2(input).unwrap(), 9); // 9 + 0 + 0 = 9
 /// ```
-pub fn solve_part2(input: &str) -> Result<i32> {
+pub fn solve_part2(input: &str) -> Result<i64> {
     let (left_nums, right_nums) = parse_input(input)?;
 
-    // Build frequency maps using FxHashMap for performance
-    let right_counts = build_frequency_map(&right_nums);
-    let left_counts = build_frequency_map(&left_nums);
+    // Count occurrences; Counter is backed by FxHashMap for fast integer keys
+    let right_counts = Counter::from_iter(right_nums);
+    let left_counts = Counter::from_iter(left_nums);
+
+    similarity_score(&left_counts, &right_counts)
+}
 
-    // Calculate similarity score using functional style
-    let similarity_score = left_counts
+/// Sums each left value times its frequency in both columns.
+///
+/// Terms are accumulated as `i128`, which cannot overflow for `i32` values
+/// and `u64` counts, and only the final score is narrowed.
+///
+/// # Parameters
+/// * `left_counts` - Frequency of each value in the left list
+/// * `right_counts` - Frequency of each value in the right list
+///
+/// # Returns
+/// Similarity score as the sum of (left_number × left_frequency ×
+/// right_frequency)
+///
+/// # Errors
+///
+/// Returns an error if the score does not fit in an `i64`.
+fn similarity_score(left_counts: &Counter<i64>, right_counts: &Counter<i64>) -> Result<i64> {
+    let similarity_score: i128 = left_counts
         .iter()
-        .map(|(&left_num, &left_freq)| {
-            let right_freq = right_counts.get(&left_num).unwrap_or(&0);
-            left_num * left_freq * right_freq
+        .map(|(&left_num, left_freq)| {
+            let right_freq = right_counts.get(&left_num);
+            i128::from(left_num) * i128::from(left_freq) * i128::from(right_freq)
         })
         .sum();
 
-    Ok(similarity_score)
+    i64::try_from(similarity_score).context("Similarity score does not fit in i64")
 }
 
-/// Builds a frequency map using FxHashMap for optimal performance.
+/// Parallel implementation of Part 1 for performance comparison.
 ///
-/// Creates a hash map counting how many times each number appears in the
-/// input slice. Uses FxHashMap for better performance with integer keys
-/// compared to standard HashMap.
+/// Sorts both lists concurrently with rayon's parallel `par_sort_unstable`
+/// and sums the pairwise distances in parallel. Only pays off once the
+/// lists are long enough to amortize the thread pool overhead.
 ///
 /// # Parameters
-/// * `nums` - Slice of integers to count frequencies for
+/// * `input` - Multi-line string containing integer pairs
+///   (whitespace-separated)
 ///
 /// # Returns
-/// Hash map where keys are the unique numbers and values are their occurrence
-/// counts
+/// Total distance as the sum of absolute differences between sorted pairs
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
 ///
 /// # Examples
 ///
 /// ```
-/// # use day01::build_frequency_map;
-/// let freq_map = build_frequency_map(&[1, 2, 2, 3, 3, 3]);
-/// assert_eq!(freq_map[&1], 1);
-/// assert_eq!(freq_map[&2], 2);
-/// assert_eq!(freq_map[&3], 3);
+/// # use day01::solve_part1_parallel;
+/// let input = "1 3\n2 5";
+/// assert_eq!(solve_part1_parallel(input).unwrap(), 5);
 /// ```
-pub fn build_frequency_map(nums: &[i32]) -> FxHashMap<i32, i32> {
-    let mut counts = FxHashMap::default();
-    for &num in nums {
-        *counts.entry(num).or_insert(0) += 1;
-    }
-    counts
+#[cfg(feature = "parallel")]
+pub fn solve_part1_parallel(input: &str) -> Result<i64> {
+    let (mut left_nums, mut right_nums) = parse_input(input)?;
+
+    rayon::join(
+        || left_nums.par_sort_unstable(),
+        || right_nums.par_sort_unstable(),
+    );
+
+    let total_distance = left_nums
+        .par_iter()
+        .zip(right_nums.par_iter())
+        .map(|(&left, &right)| (left - right).abs())
+        .sum();
+
+    Ok(total_distance)
+}
+
+/// Parallel implementation of Part 2 for performance comparison.
+///
+/// Each rayon task counts a chunk of a list into its own [`Counter`], and
+/// the partial counters are merged pairwise, for both lists concurrently.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing integer pairs
+///   (whitespace-separated)
+///
+/// # Returns
+/// Similarity score as the sum of (left_number × left_frequency ×
+/// right_frequency)
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails or the score does not fit in
+/// an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # use day01::solve_part2_parallel;
+/// let input = "3 3\n4 3\n2 3";
+/// assert_eq!(solve_part2_parallel(input).unwrap(), 9);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn solve_part2_parallel(input: &str) -> Result<i64> {
+    let (left_nums, right_nums) = parse_input(input)?;
+
+    let count = |nums: &[i64]| {
+        nums.par_iter()
+            .fold(Counter::new, |mut counts, &num| {
+                counts.add(num);
+                counts
+            })
+            .reduce(Counter::new, |left, right| left + right)
+    };
+    let (left_counts, right_counts) = rayon::join(|| count(&left_nums), || count(&right_nums));
+
+    similarity_score(&left_counts, &right_counts)
+}
+
+/// Solves both parts from a single parse of the input.
+///
+/// Sorts the parsed columns once for Part 1 and counts the same vectors for
+/// Part 2, so the input is parsed and allocated only once.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing integer pairs
+///   (whitespace-separated)
+///
+/// # Returns
+/// `(total_distance, similarity_score)`, the answers of [`solve_part1`] and
+/// [`solve_part2`]
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails or the similarity score does not
+/// fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # use day01::{solve_both, EXAMPLE_INPUT};
+/// assert_eq!(solve_both(EXAMPLE_INPUT).unwrap(), (11, 31));
+/// ```
+pub fn solve_both(input: &str) -> Result<(i64, i64)> {
+    let (mut left_nums, mut right_nums) = parse_input(input)?;
+    left_nums.sort_unstable();
+    right_nums.sort_unstable();
+
+    let total_distance = left_nums
+        .iter()
+        .zip(right_nums.iter())
+        .map(|(&left, &right)| (left - right).abs())
+        .sum();
+
+    let left_counts = Counter::from_iter(left_nums);
+    let right_counts = Counter::from_iter(right_nums);
+
+    Ok((
+        total_distance,
+        similarity_score(&left_counts, &right_counts)?,
+    ))
 }
+
 /// Naive O(n²) implementation of Part 2 for performance comparison.
 ///
 /// Uses cartesian product (nested iteration) to compare every left number
@@ -153,7 +807,8 @@ pub fn build_frequency_map(nums: &[i32]) -> FxHashMap<i32, i32> {
 ///
 /// # Errors
 ///
-/// Returns an error if input parsing fails.
+/// Returns an error if input parsing fails or the score does not fit in
+/// an `i64`.
 ///
 /// # Examples
 ///
@@ -163,14 +818,128 @@ pub fn build_frequency_map(nums: &[i32]) -> FxHashMap<i32, i32> {
 /// assert_eq!(solve_part2_naive(input).unwrap(), 9); // Same result as
 ///                                                    // optimized version
 /// ```
-pub fn solve_part2_naive(input: &str) -> Result<i32> {
-    parse_input(input).map(|(left_nums, right_nums)| {
-        left_nums
-            .iter()
-            .cartesian_product(right_nums.iter())
-            .filter_map(|(&left, &right)| (left == right).then_some(left))
-            .sum()
-    })
+pub fn solve_part2_naive(input: &str) -> Result<i64> {
+    let (left_nums, right_nums) = parse_input(input)?;
+    let similarity_score: i128 = left_nums
+        .iter()
+        .cartesian_product(right_nums.iter())
+        .filter_map(|(&left, &right)| (left == right).then_some(i128::from(left)))
+        .sum();
+
+    i64::try_from(similarity_score).context("Similarity score does not fit in i64")
+}
+
+/// Streaming implementation of Part 1 for very large inputs.
+///
+/// Reads the input line by line in a single pass, keeping only how often
+/// each value occurs in each column. Sorted pairing then walks the distinct
+/// values of both columns in order, pairing runs of equal values at once.
+/// Memory is bounded by the number of distinct values rather than the
+/// number of lines, so it only stays small when values repeat; on inputs
+/// with mostly unique values it is still linear.
+///
+/// Distances are accumulated as `i128` and only the final total is
+/// narrowed, so huge pair counts cannot overflow midway.
+///
+/// # Parameters
+/// * `reader` - Buffered source of integer pairs (whitespace-separated),
+///   such as a file or stdin
+///
+/// # Returns
+/// Total distance as the sum of absolute differences between sorted pairs
+///
+/// # Errors
+///
+/// Returns an error if reading or parsing a line fails, or the total
+/// distance does not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # use day01::solve_part1_streaming;
+/// let reader = "1 3\n2 5".as_bytes();
+/// assert_eq!(solve_part1_streaming(reader).unwrap(), 5);
+/// ```
+pub fn solve_part1_streaming<R: BufRead>(reader: R) -> Result<i64> {
+    let (left_counts, right_counts) = count_columns(reader)?;
+    let sorted_runs = |counts: Counter<i64>| {
+        let mut runs: Vec<(i64, u64)> = counts.iter().map(|(&num, freq)| (num, freq)).collect();
+        runs.sort_unstable();
+        runs.into_iter()
+    };
+    let (mut left_runs, mut right_runs) = (sorted_runs(left_counts), sorted_runs(right_counts));
+
+    // Both columns hold one value per line, so their runs end together
+    let mut total_distance: i128 = 0;
+    let (mut left, mut right) = (left_runs.next(), right_runs.next());
+    while let (Some((left_num, left_freq)), Some((right_num, right_freq))) = (left, right) {
+        let pairs = left_freq.min(right_freq);
+        total_distance += i128::from((left_num - right_num).abs()) * i128::from(pairs);
+        left = match left_freq - pairs {
+            0 => left_runs.next(),
+            rest => Some((left_num, rest)),
+        };
+        right = match right_freq - pairs {
+            0 => right_runs.next(),
+            rest => Some((right_num, rest)),
+        };
+    }
+
+    i64::try_from(total_distance).context("Total distance does not fit in i64")
+}
+
+/// Streaming implementation of Part 2 for very large inputs.
+///
+/// Reads the input line by line in a single pass, counting each column's
+/// values without storing the lines themselves. Memory is bounded by the
+/// number of distinct values, not by the number of lines.
+///
+/// # Parameters
+/// * `reader` - Buffered source of integer pairs (whitespace-separated),
+///   such as a file or stdin
+///
+/// # Returns
+/// Similarity score as the sum of (left_number × left_frequency ×
+/// right_frequency)
+///
+/// # Errors
+///
+/// Returns an error if reading or parsing a line fails, or the score does
+/// not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # use day01::solve_part2_streaming;
+/// let reader = "3 3\n4 3\n2 3".as_bytes();
+/// assert_eq!(solve_part2_streaming(reader).unwrap(), 9);
+/// ```
+pub fn solve_part2_streaming<R: BufRead>(reader: R) -> Result<i64> {
+    let (left_counts, right_counts) = count_columns(reader)?;
+
+    similarity_score(&left_counts, &right_counts)
+}
+
+/// Counts the values of the left and right columns in one pass.
+///
+/// # Parameters
+/// * `reader` - Buffered source of integer pairs (whitespace-separated)
+///
+/// # Returns
+/// Tuple of (left_counts, right_counts)
+///
+/// # Errors
+///
+/// Returns an error if reading or parsing a line fails.
+fn count_columns<R: BufRead>(reader: R) -> Result<(Counter<i64>, Counter<i64>)> {
+    let mut left_counts = Counter::new();
+    let mut right_counts = Counter::new();
+    for pair in stream_lines_with(reader, parse_line) {
+        let (left, right) = pair?;
+        left_counts.add(left);
+        right_counts.add(right);
+    }
+    Ok((left_counts, right_counts))
 }
 
 /// Parses the input string into two separate lists of integers (left and
@@ -184,11 +953,13 @@ pub fn solve_part2_naive(input: &str) -> Result<i32> {
 ///   whitespace-separated)
 ///
 /// # Returns
-/// Tuple of (left_column_numbers, right_column_numbers) as Vec<i32>
+/// Tuple of (left_column_numbers, right_column_numbers) as Vec<i64>,
+/// widened from `i32`
 ///
 /// # Errors
 ///
-/// Returns an error if:
+/// Returns a [`ParseError`](shared::input::ParseError) locating the
+/// offending text if:
 /// - Any value cannot be parsed as an `i32`
 /// - Any line doesn't contain exactly two whitespace-separated values
 ///
@@ -196,26 +967,68 @@ pub fn solve_part2_naive(input: &str) -> Result<i32> {
 ///
 /// ```
 /// # use day01::parse_input;
-/// let input = "1 2\n3 4";
+/// let input = "1 2\n-3 4";
 /// let (left, right) = parse_input(input).unwrap();
-/// assert_eq!(left, vec![1, 3]);
+/// assert_eq!(left, vec![1, -3]);
 /// assert_eq!(right, vec![2, 4]);
 /// ```
-pub fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>)> {
-    let mut left_nums = Vec::new();
-    let mut right_nums = Vec::new();
-
-    for line in input.lines() {
-        let parts: Vec<_> = line.split_whitespace().collect();
-        match parts[..] {
-            [] => continue, // skip empty lines
-            [left_str, right_str] => {
-                left_nums.push(left_str.parse()?);
-                right_nums.push(right_str.parse()?);
-            }
-            _ => bail!("Line must contain exactly two numbers: '{line}'"),
-        }
-    }
+pub fn parse_input(input: &str) -> Result<(Vec<i64>, Vec<i64>)> {
+    let (mut left_nums, mut right_nums) = (Vec::new(), Vec::new());
+    parse_input_into(input, &mut left_nums, &mut right_nums)?;
 
     Ok((left_nums, right_nums))
 }
+
+/// Parses the input into caller-provided column buffers.
+///
+/// The buffers are cleared first and keep their capacity, so parsing into
+/// the same buffers again allocates nothing once they are large enough.
+/// Lets benchmarks of small inputs measure the algorithm rather than the
+/// allocator.
+///
+/// # Parameters
+/// * `input` - Multi-line string with integer pairs (one pair per line,
+///   whitespace-separated)
+/// * `left_nums` - Receives the left column
+/// * `right_nums` - Receives the right column
+///
+/// # Errors
+///
+/// Returns the same [`ParseError`](shared::input::ParseError) as
+/// [`parse_input`]; the buffers then hold the lines before the bad one.
+///
+/// # Examples
+///
+/// ```
+/// # use day01::parse_input_into;
+/// let (mut left, mut right) = (Vec::new(), Vec::new());
+/// parse_input_into("1 2\n3 4", &mut left, &mut right).unwrap();
+/// parse_input_into("5 6", &mut left, &mut right).unwrap();
+/// assert_eq!((left, right), (vec![5], vec![6]));
+/// ```
+pub fn parse_input_into(
+    input: &str,
+    left_nums: &mut Vec<i64>,
+    right_nums: &mut Vec<i64>,
+) -> Result<()> {
+    left_nums.clear();
+    right_nums.clear();
+    try_for_each_line(input, |line| {
+        let (left, right) = parse_line(line)?;
+        left_nums.push(left);
+        right_nums.push(right);
+        Ok(())
+    })
+}
+
+/// Parses one line of two whitespace-separated `i32` values, widened to
+/// `i64`.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`](shared::input::ParseError) if the line does not
+/// hold exactly two `i32` values.
+fn parse_line(line: &str) -> Result<(i64, i64)> {
+    let (left, right) = parse_pair::<i32>(line, Delimiter::Whitespace, "an i32 location ID")?;
+    Ok((i64::from(left), i64::from(right)))
+}
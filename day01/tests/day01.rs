@@ -1,5 +1,12 @@
-use day01::{parse_input, solve_part1, solve_part2, solve_part2_naive, EXAMPLE_INPUT};
+use day01::{
+    distance_report, parse_input, parse_input_into, solve_both, solve_part1, solve_part1_btree,
+    solve_part1_counting, solve_part1_heap, solve_part1_naive, solve_part1_reusing,
+    solve_part1_streaming, solve_part2, solve_part2_naive, solve_part2_streaming, sorted_pairs,
+    total_distance_with, DistanceMetric, EXAMPLE_INPUT,
+};
+use proptest::prelude::*;
 use rstest::rstest;
+use shared::input::ParseError;
 
 // ===== PARSE INPUT TESTS =====
 
@@ -15,12 +22,13 @@ fn test_parse_input_example() {
 #[case("1 2\n\n3 4", vec![1, 3], vec![2, 4], "empty lines ignored")] // Empty lines are skipped
 #[case("\n\n1 2\n3 4\n\n", vec![1, 3], vec![2, 4], "leading/trailing whitespace ignored")] // Leading/trailing empty lines
 #[case("1 2\n   \n3 4", vec![1, 3], vec![2, 4], "whitespace-only lines ignored")] // Whitespace-only lines
+#[case("-1 2\n3 -4", vec![-1, 3], vec![2, -4], "negative values")] // Negative values are accepted
 #[case("", vec![], vec![], "empty input")] // Empty input returns empty vectors
 #[case("\n\n   \n", vec![], vec![], "only whitespace")] // Only whitespace returns empty vectors
 fn test_parse_input_edge_cases(
     #[case] input: &str,
-    #[case] expected_left: Vec<i32>,
-    #[case] expected_right: Vec<i32>,
+    #[case] expected_left: Vec<i64>,
+    #[case] expected_right: Vec<i64>,
     #[case] description: &str,
 ) {
     let (left, right) = parse_input(input).unwrap();
@@ -29,9 +37,9 @@ fn test_parse_input_edge_cases(
 }
 
 #[rstest]
-#[case("1", "exactly two")] // Single number
-#[case("1 2 3", "exactly two")] // Too many numbers
-#[case("1 2\n3", "exactly two")] // Mixed valid and invalid lines
+#[case("1", "2 values separated by whitespace")] // Single number
+#[case("1 2 3", "2 values separated by whitespace")] // Too many numbers
+#[case("1 2\n3", "2 values separated by whitespace")] // Mixed valid and invalid lines
 fn test_parse_input_errors(#[case] input: &str, #[case] expected_error: &str) {
     let result = parse_input(input);
     assert!(result.is_err(), "Should error on input: {input:?}");
@@ -42,15 +50,78 @@ fn test_parse_input_errors(#[case] input: &str, #[case] expected_error: &str) {
     );
 }
 
+#[rstest]
+#[case("1", 1, 1, "1", "2 values separated by whitespace")] // Single number
+#[case("1 2\n\n3", 3, 1, "3", "2 values separated by whitespace")] // Line count includes blank lines
+#[case("1 x", 1, 3, "x", "an i32 location ID")] // Non-numeric right value
+#[case("1 2\n  y   4", 2, 3, "y", "an i32 location ID")] // Column includes indentation
+#[case("99999999999 1", 1, 1, "99999999999", "an i32 location ID")] // Overflow
+#[case("1 -2147483649", 1, 3, "-2147483649", "an i32 location ID")] // Underflow
+fn test_parse_input_error_locations(
+    #[case] input: &str,
+    #[case] line: usize,
+    #[case] column: usize,
+    #[case] text: &str,
+    #[case] expected: &str,
+) {
+    let error = parse_input(input).unwrap_err();
+    let error = error
+        .downcast_ref::<ParseError>()
+        .expect("Expected a ParseError");
+    assert_eq!(error.line, line, "Line mismatch for input: {input:?}");
+    assert_eq!(error.column, column, "Column mismatch for input: {input:?}");
+    assert_eq!(error.text, text, "Text mismatch for input: {input:?}");
+    assert_eq!(
+        error.expected, expected,
+        "Expected mismatch for input: {input:?}"
+    );
+}
+
+#[test]
+fn test_parse_input_into_reuses_buffers() {
+    let (mut left, mut right) = (Vec::with_capacity(16), Vec::with_capacity(16));
+    parse_input_into(EXAMPLE_INPUT, &mut left, &mut right).unwrap();
+    assert_eq!(
+        (&left, &right),
+        (&vec![3, 4, 2, 1, 3, 3], &vec![4, 3, 5, 3, 9, 3])
+    );
+
+    let capacity = (left.capacity(), right.capacity());
+    parse_input_into("7 8", &mut left, &mut right).unwrap();
+    assert_eq!(
+        (left.as_slice(), right.as_slice()),
+        ([7].as_slice(), [8].as_slice())
+    );
+    assert_eq!((left.capacity(), right.capacity()), capacity);
+}
+
+#[test]
+fn test_parse_input_into_error_matches_parse_input() {
+    let (mut left, mut right) = (Vec::new(), Vec::new());
+    let error = parse_input_into("1 2\n3 x", &mut left, &mut right).unwrap_err();
+    let expected = parse_input("1 2\n3 x").unwrap_err();
+    assert_eq!(
+        error.downcast_ref::<ParseError>(),
+        expected.downcast_ref::<ParseError>()
+    );
+}
+
 // ===== SOLVE FUNCTION TESTS =====
 
 #[rstest]
 #[case(solve_part1, 11)] // Part 1 with example input
+#[case(solve_part1_naive, 11)] // Part 1 naive with example input
+#[case(solve_part1_counting, 11)] // Part 1 counting sort with example input
+#[case(solve_part1_heap, 11)] // Part 1 binary heap with example input
+#[case(solve_part1_btree, 11)] // Part 1 BTreeMap multiset with example input
+#[case(|input: &str| solve_part1_reusing(input, &mut Vec::new(), &mut Vec::new()), 11)] // Part 1 with caller buffers
 #[case(solve_part2, 31)] // Part 2 with example input
 #[case(solve_part2_naive, 31)] // Part 2 naive with example input
+#[case(|input: &str| solve_part1_streaming(input.as_bytes()), 11)] // Part 1 streaming with example input
+#[case(|input: &str| solve_part2_streaming(input.as_bytes()), 31)] // Part 2 streaming with example input
 fn test_solve_functions_example(
-    #[case] solve_fn: fn(&str) -> anyhow::Result<i32>,
-    #[case] expected: i32,
+    #[case] solve_fn: fn(&str) -> anyhow::Result<i64>,
+    #[case] expected: i64,
 ) {
     let result = solve_fn(EXAMPLE_INPUT).unwrap();
     assert_eq!(result, expected);
@@ -58,15 +129,38 @@ fn test_solve_functions_example(
 
 #[rstest]
 #[case("1 2\n3 4", solve_part1, 2)] // Simple case: sorted [1,3] and [2,4] -> |1-2| + |3-4| = 1 + 1 = 2
+#[case("3 4\n1 2\n3 -1", solve_part1_counting, 4)] // Duplicates and negatives: [1,3,3] and [-1,2,4] -> 2 + 1 + 1 = 4
+#[case("3 4\n1 2\n3 -1", solve_part1_heap, 4)] // Duplicates and negatives with binary heap
+#[case("3 4\n1 2\n3 -1", solve_part1_btree, 4)] // Duplicates and negatives with BTreeMap multiset
+#[case("3 3\n3 1\n1 3", solve_part1_btree, 0)] // Uneven runs: [1,3,3] and [1,3,3]
 #[case("1 2\n3 4", solve_part2, 0)] // No similarity (no common numbers)
 #[case("1 2\n3 4", solve_part2_naive, 0)] // Same as above
 #[case("", solve_part1, 0)] // Empty input edge case
+#[case("", solve_part1_naive, 0)] // Empty input edge case
+#[case("", solve_part1_counting, 0)] // Empty input edge case
+#[case("", solve_part1_heap, 0)] // Empty input edge case
+#[case("", solve_part1_btree, 0)] // Empty input edge case
 #[case("", solve_part2, 0)] // Empty input edge case
 #[case("", solve_part2_naive, 0)] // Empty input edge case
+#[case("", |input: &str| solve_part1_streaming(input.as_bytes()), 0)] // Empty input edge case
+#[case("", |input: &str| solve_part2_streaming(input.as_bytes()), 0)] // Empty input edge case
+#[case("5 1\n5 1\n1 9", |input: &str| solve_part1_streaming(input.as_bytes()), 8)] // Runs split across pairs: [1,5,5] and [1,1,9] -> 0 + 4 + 4 = 8
+#[case("-2000000000 2000000000", solve_part1, 4_000_000_000)] // Distance beyond i32::MAX
+#[case("-2000000000 2000000000\n5 1", solve_part1_counting, 3_999_999_996)] // Range too wide to count
+#[case(
+    "2000000000 2000000000\n2000000000 2000000000",
+    solve_part2,
+    8_000_000_000
+)] // Score beyond i32::MAX
+#[case(
+    "2000000000 2000000000\n2000000000 2000000000",
+    solve_part2_naive,
+    8_000_000_000
+)] // Same as above
 fn test_solve_functions_edge_cases(
     #[case] input: &str,
-    #[case] solve_fn: fn(&str) -> anyhow::Result<i32>,
-    #[case] expected: i32,
+    #[case] solve_fn: fn(&str) -> anyhow::Result<i64>,
+    #[case] expected: i64,
 ) {
     let result = solve_fn(input).unwrap();
     assert_eq!(result, expected);
@@ -74,14 +168,242 @@ fn test_solve_functions_edge_cases(
 
 #[rstest]
 #[case(solve_part1, 1603498)] // Part 1 with real input
+#[case(solve_part1_naive, 1603498)] // Part 1 naive with real input
+#[case(solve_part1_counting, 1603498)] // Part 1 counting sort with real input
+#[case(solve_part1_heap, 1603498)] // Part 1 binary heap with real input
+#[case(solve_part1_btree, 1603498)] // Part 1 BTreeMap multiset with real input
 #[case(solve_part2, 25574739)] // Part 2 with real input
 #[case(solve_part2_naive, 25574739)] // Part 2 naive with real input
+#[case(|input: &str| solve_part1_streaming(input.as_bytes()), 1603498)] // Part 1 streaming with real input
+#[case(|input: &str| solve_part2_streaming(input.as_bytes()), 25574739)] // Part 2 streaming with real input
 fn test_solve_functions_real_input(
-    #[case] solve_fn: fn(&str) -> anyhow::Result<i32>,
-    #[case] expected: i32,
+    #[case] solve_fn: fn(&str) -> anyhow::Result<i64>,
+    #[case] expected: i64,
 ) {
     let input = std::fs::read_to_string("input.txt")
         .expect("Failed to read input.txt - make sure it exists");
     let result = solve_fn(&input).unwrap();
     assert_eq!(result, expected);
 }
+
+#[rstest]
+#[case(EXAMPLE_INPUT, (11, 31))] // Example input
+#[case("", (0, 0))] // Empty input
+#[case("-3 -3\n-3 5\n4 -3", (1, -12))] // Negative values
+fn test_solve_both(#[case] input: &str, #[case] expected: (i64, i64)) {
+    assert_eq!(solve_both(input).unwrap(), expected);
+}
+
+#[test]
+fn test_solve_both_real_input() {
+    let input = std::fs::read_to_string("input.txt")
+        .expect("Failed to read input.txt - make sure it exists");
+    assert_eq!(solve_both(&input).unwrap(), (1603498, 25574739));
+}
+
+// ===== NEGATIVE VALUE AND OVERFLOW TESTS =====
+
+#[rstest]
+#[case("-3 -3\n-3 5\n4 -3", solve_part2, -12)] // -3 appears twice left and twice right: -3 * 2 * 2
+#[case("-3 -3\n-3 5\n4 -3", solve_part2_naive, -12)] // Same as above
+#[case("-3 -3\n-3 5\n4 -3", solve_part1, 1)] // Sorted [-3,-3,4] and [-3,-3,5]: 0 + 0 + 1
+fn test_solve_functions_negative_values(
+    #[case] input: &str,
+    #[case] solve_fn: fn(&str) -> anyhow::Result<i64>,
+    #[case] expected: i64,
+) {
+    assert_eq!(solve_fn(input).unwrap(), expected);
+}
+
+#[rstest]
+#[case(i32::MAX)]
+#[case(i32::MIN)]
+fn test_similarity_score_overflow_is_an_error(#[case] value: i32) {
+    // value × 70000 × 70000 lies beyond i64 in either direction
+    let input = format!("{value} {value}\n").repeat(70_000);
+    for solve_fn in [solve_part2, |input: &str| {
+        solve_part2_streaming(input.as_bytes())
+    }] {
+        let error = solve_fn(&input).unwrap_err();
+        assert!(
+            error.to_string().contains("does not fit in i64"),
+            "Got: {error}"
+        );
+    }
+}
+
+/// Formats pairs as puzzle input, one pair per line.
+fn format_pairs(pairs: &[(i32, i32)]) -> String {
+    pairs
+        .iter()
+        .map(|(left, right)| format!("{left}   {right}\n"))
+        .collect()
+}
+
+/// Computes Part 1 with exact `i128` arithmetic as a reference.
+fn reference_part1(pairs: &[(i32, i32)]) -> i128 {
+    let (mut left, mut right): (Vec<i128>, Vec<i128>) = pairs
+        .iter()
+        .map(|&(left, right)| (i128::from(left), i128::from(right)))
+        .unzip();
+    left.sort_unstable();
+    right.sort_unstable();
+    left.iter()
+        .zip(&right)
+        .map(|(left, right)| (left - right).abs())
+        .sum()
+}
+
+/// Computes Part 2 with exact `i128` arithmetic as a reference.
+fn reference_part2(pairs: &[(i32, i32)]) -> i128 {
+    pairs
+        .iter()
+        .map(|&(left, _)| {
+            let matches = pairs.iter().filter(|&&(_, right)| right == left).count();
+            i128::from(left) * matches as i128
+        })
+        .sum()
+}
+
+/// Pairs over the full `i32` range, or over a narrow range that repeats
+/// values so Part 2 has matches.
+fn pairs_strategy() -> impl Strategy<Value = Vec<(i32, i32)>> {
+    prop_oneof![
+        prop::collection::vec((any::<i32>(), any::<i32>()), 0..64),
+        prop::collection::vec((-5..5, -5..5), 0..64),
+        prop::collection::vec((i32::MAX - 2..=i32::MAX, i32::MIN..i32::MIN + 2), 0..64),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn prop_part1_solvers_match_reference(pairs in pairs_strategy()) {
+        let input = format_pairs(&pairs);
+        let expected = i64::try_from(reference_part1(&pairs)).unwrap();
+        prop_assert_eq!(solve_part1(&input).unwrap(), expected);
+        prop_assert_eq!(solve_part1_naive(&input).unwrap(), expected);
+        prop_assert_eq!(solve_both(&input).unwrap().0, expected);
+        prop_assert_eq!(solve_part1_counting(&input).unwrap(), expected);
+        prop_assert_eq!(solve_part1_heap(&input).unwrap(), expected);
+        prop_assert_eq!(solve_part1_btree(&input).unwrap(), expected);
+        prop_assert_eq!(solve_part1_reusing(&input, &mut Vec::new(), &mut Vec::new()).unwrap(), expected);
+        prop_assert_eq!(solve_part1_streaming(input.as_bytes()).unwrap(), expected);
+    }
+
+    #[test]
+    fn prop_part2_solvers_match_reference(pairs in pairs_strategy()) {
+        let input = format_pairs(&pairs);
+        let expected = i64::try_from(reference_part2(&pairs)).unwrap();
+        prop_assert_eq!(solve_part2(&input).unwrap(), expected);
+        prop_assert_eq!(solve_part2_naive(&input).unwrap(), expected);
+        prop_assert_eq!(solve_both(&input).unwrap().1, expected);
+        prop_assert_eq!(solve_part2_streaming(input.as_bytes()).unwrap(), expected);
+    }
+}
+
+// ===== DISTANCE METRIC TESTS =====
+
+#[rstest]
+#[case(DistanceMetric::Absolute, 11)] // Part 1 answer
+#[case(DistanceMetric::Squared, 35)] // Pairs (1,3) (2,3) (3,3) (3,4) (3,5) (4,9): 4 + 1 + 0 + 1 + 4 + 25 = 35
+#[case(DistanceMetric::Custom(|left, right| i64::from(left != right)), 5)] // Pairs that differ
+fn test_total_distance_with_example(#[case] metric: DistanceMetric, #[case] expected: i64) {
+    let (left, right) = parse_input(EXAMPLE_INPUT).unwrap();
+    assert_eq!(total_distance_with(left, right, metric).unwrap(), expected);
+}
+
+#[test]
+fn test_total_distance_with_unequal_lengths() {
+    // The unpaired 10 is ignored
+    let total = total_distance_with(vec![2, 1], vec![10, 4, 3], DistanceMetric::Absolute);
+    assert_eq!(total.unwrap(), 4);
+}
+
+#[test]
+fn test_total_distance_with_squared_overflow() {
+    let (min, max) = (i64::from(i32::MIN), i64::from(i32::MAX));
+    let total = total_distance_with(vec![min], vec![max], DistanceMetric::Squared);
+    assert!(total.is_err());
+}
+
+// ===== PAIRING DETAIL TESTS =====
+
+#[test]
+fn test_sorted_pairs_example() {
+    let pairs = sorted_pairs(EXAMPLE_INPUT).unwrap();
+    assert_eq!(
+        pairs,
+        vec![
+            (1, 3, 2),
+            (2, 3, 1),
+            (3, 3, 0),
+            (3, 4, 1),
+            (3, 5, 2),
+            (4, 9, 5)
+        ]
+    );
+    let total: i64 = pairs.iter().map(|&(_, _, diff)| diff).sum();
+    assert_eq!(total, solve_part1(EXAMPLE_INPUT).unwrap());
+}
+
+#[test]
+fn test_sorted_pairs_empty_input() {
+    assert!(sorted_pairs("").unwrap().is_empty());
+}
+
+// ===== DISTANCE REPORT TESTS =====
+
+#[test]
+fn test_distance_report_example() {
+    let report = distance_report(EXAMPLE_INPUT).unwrap();
+    assert_eq!(report.differences.iter().sum::<i64>(), 11);
+    assert_eq!(report.min, 0);
+    assert_eq!(report.max, 5);
+    assert!((report.mean - 11.0 / 6.0).abs() < 1e-9);
+    assert_eq!(report.median, 1.5);
+    assert_eq!(report.buckets.first().unwrap().0, 0.0);
+    assert_eq!(report.buckets.last().unwrap().1, 5.0);
+    assert_eq!(report.buckets.last().unwrap().2, 1); // Only the 4-9 pair
+}
+
+#[test]
+fn test_distance_report_empty_input() {
+    let error = distance_report("").unwrap_err();
+    assert!(error.to_string().contains("No pairs"));
+}
+
+#[test]
+fn test_distance_report_plot() {
+    let filename = std::env::temp_dir().join("day01_distance_report_test.svg");
+    let filename = filename.to_str().unwrap();
+    let report = distance_report(EXAMPLE_INPUT).unwrap();
+    report
+        .plot(filename, &shared::plotting::PlotTheme::default())
+        .unwrap();
+    assert!(std::fs::metadata(filename).unwrap().len() > 0);
+    std::fs::remove_file(filename).ok();
+    std::fs::remove_file(shared::plotting::plot_data_path(filename)).ok();
+}
+
+// ===== PARALLEL SOLVER TESTS =====
+
+#[cfg(feature = "parallel")]
+#[rstest]
+#[case(day01::solve_part1_parallel, solve_part1)]
+#[case(day01::solve_part2_parallel, solve_part2)]
+fn test_parallel_solvers_match_sequential(
+    #[case] parallel: fn(&str) -> anyhow::Result<i64>,
+    #[case] sequential: fn(&str) -> anyhow::Result<i64>,
+) {
+    let input: String = (0..10_000)
+        .map(|i| format!("{} {}\n", (i * 31) % 997, (i * 17) % 991))
+        .collect();
+    for input in [EXAMPLE_INPUT, "", input.as_str()] {
+        assert_eq!(
+            parallel(input).unwrap(),
+            sequential(input).unwrap(),
+            "Mismatch for input of {} bytes",
+            input.len()
+        );
+    }
+}
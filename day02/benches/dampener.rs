@@ -0,0 +1,253 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day02::{solve_part2, solve_part2_naive, solve_part2_skipping};
+use shared::benchmarking::{
+    create_criterion_benchmark, process_benchmark_results, run_algorithm_benchmark, Algorithm,
+    AxisScale, PlotConfig, TestConfig,
+};
+
+#[cfg(feature = "alloc-tracking")]
+#[global_allocator]
+static ALLOCATOR: shared::alloc_tracking::TrackingAllocator =
+    shared::alloc_tracking::TrackingAllocator;
+
+const REPORT_LENGTHS: [usize; 6] = [5, 10, 25, 50, 100, 200];
+const ALLOCATION_REPORT_COUNTS: [usize; 6] = [100, 500, 1000, 2000, 5000, 10000];
+#[cfg(feature = "parallel")]
+const REPORT_COUNTS: [usize; 6] = [100, 1000, 10000, 50000, 100000, 500000];
+
+/// Benchmark: Dampener Report Length Scaling
+/// Shows the O(n) first-violation dampener vs O(n²) brute-force removal when
+/// varying the number of levels per report
+fn benchmark_dampener(c: &mut Criterion) {
+    let data_dir = "data";
+    let group_name = "dampener_scaling";
+
+    let algorithms = [
+        Algorithm::new("linear", solve_part2),
+        Algorithm::new("naive", solve_part2_naive),
+    ];
+
+    // The real puzzle input is benchmarked too when it is present
+    let real_input = std::fs::read_to_string("input.txt").ok();
+
+    let test_config = TestConfig {
+        sizes: &REPORT_LENGTHS,
+        generate_input: generate_report_length_test,
+        verify_outputs: true,
+        settings: None,
+        real_input: real_input.as_deref(),
+        cold_start: true,
+    };
+
+    run_algorithm_benchmark(c, data_dir, group_name, &algorithms, &test_config)
+        .expect("Benchmarked algorithms must agree on every input");
+
+    let plot_config = PlotConfig {
+        filename: "dampener_linear_vs_naive.svg",
+        title: "Day 2: Linear vs Brute-Force Dampener Performance",
+        algorithm_names: &[
+            "O(n) First-Violation Dampener",
+            "O(n²) Brute-Force Dampener",
+        ],
+        x_axis_label: "Levels per Report (n)",
+        // Log-log turns O(n) and O(n²) into lines of slope 1 and 2
+        x_scale: AxisScale::Log,
+        y_scale: AxisScale::Log,
+        speedup_filename: Some("dampener_linear_vs_naive_speedup.svg"),
+        fit_overlay: true,
+        part: Some(2),
+        ..PlotConfig::default()
+    };
+
+    process_benchmark_results(
+        data_dir,
+        group_name,
+        &algorithms,
+        &plot_config,
+        &test_config,
+    );
+}
+
+/// Benchmark: Allocating vs Allocation-Free Brute Force
+/// Isolates the cost of collecting a new vector per candidate removal, with
+/// both dampeners trying every removal
+fn benchmark_dampener_allocation(c: &mut Criterion) {
+    let data_dir = "data";
+    let group_name = "dampener_allocation";
+
+    let algorithms = [
+        Algorithm::new("allocating", solve_part2_naive),
+        Algorithm::new("skipping", solve_part2_skipping),
+    ];
+
+    let real_input = std::fs::read_to_string("input.txt").ok();
+
+    let test_config = TestConfig {
+        sizes: &ALLOCATION_REPORT_COUNTS,
+        generate_input: generate_report_count_test,
+        verify_outputs: true,
+        settings: None,
+        real_input: real_input.as_deref(),
+        cold_start: false,
+    };
+
+    run_algorithm_benchmark(c, data_dir, group_name, &algorithms, &test_config)
+        .expect("Benchmarked algorithms must agree on every input");
+
+    let plot_config = PlotConfig {
+        filename: "dampener_allocation.svg",
+        title: "Day 2: Allocating vs Index-Skipping Brute-Force Dampener",
+        algorithm_names: &["Vec per Removal", "Index Skipping"],
+        x_axis_label: "Number of Reports (n)",
+        x_scale: AxisScale::Log,
+        y_scale: AxisScale::Log,
+        speedup_filename: Some("dampener_allocation_speedup.svg"),
+        fit_overlay: true,
+        part: Some(2),
+        ..PlotConfig::default()
+    };
+
+    process_benchmark_results(
+        data_dir,
+        group_name,
+        &algorithms,
+        &plot_config,
+        &test_config,
+    );
+}
+
+/// Benchmark: Sequential vs Parallel
+/// Shows where checking reports on rayon's thread pool overtakes a single
+/// thread as the number of reports grows
+#[cfg(feature = "parallel")]
+fn benchmark_parallel(c: &mut Criterion) {
+    use day02::{solve_part1, solve_part1_parallel, solve_part2_parallel};
+
+    let data_dir = "data";
+    let groups = [
+        (
+            "part1_parallel",
+            1,
+            [
+                Algorithm::new("sequential", solve_part1),
+                Algorithm::new("parallel", solve_part1_parallel),
+            ],
+        ),
+        (
+            "part2_parallel",
+            2,
+            [
+                Algorithm::new("sequential", solve_part2),
+                Algorithm::new("parallel", solve_part2_parallel),
+            ],
+        ),
+    ];
+
+    for (group_name, part, algorithms) in groups {
+        let test_config = TestConfig {
+            sizes: &REPORT_COUNTS,
+            generate_input: generate_report_count_test,
+            verify_outputs: true,
+            settings: None,
+            real_input: None,
+            cold_start: false,
+        };
+
+        run_algorithm_benchmark(c, data_dir, group_name, &algorithms, &test_config)
+            .expect("Benchmarked algorithms must agree on every input");
+
+        let filename = format!("{group_name}.svg");
+        let speedup_filename = format!("{group_name}_speedup.svg");
+        let title = format!("Day 2: Sequential vs Parallel Part {part} Performance");
+        let plot_config = PlotConfig {
+            filename: &filename,
+            title: &title,
+            algorithm_names: &["Sequential", "Rayon Parallel"],
+            x_axis_label: "Number of Reports (n)",
+            x_scale: AxisScale::Log,
+            y_scale: AxisScale::Log,
+            speedup_filename: Some(&speedup_filename),
+            part: Some(part),
+            ..PlotConfig::default()
+        };
+
+        process_benchmark_results(
+            data_dir,
+            group_name,
+            &algorithms,
+            &plot_config,
+            &test_config,
+        );
+    }
+}
+
+/// Test data: Fixed report count, variable report length
+///
+/// Reports increase by 1-3 per level. Most carry one bad level late in the
+/// report, where the brute-force dampener only finds it after many removals;
+/// every fourth report has a second bad level and stays unsafe.
+fn generate_report_length_test(report_length: usize) -> String {
+    const N_REPORTS: usize = 200;
+
+    (0..N_REPORTS)
+        .map(|report| {
+            let fault = report_length - 1 - report % (report_length / 4 + 1);
+            let second_fault = (report % 4 == 0).then_some(report_length / 2);
+
+            let mut level = 10 + (report % 50) as i32;
+            (0..report_length)
+                .map(|index| {
+                    level += 1 + ((report + index) % 3) as i32;
+                    if index == fault || Some(index) == second_fault {
+                        level + 10
+                    } else {
+                        level
+                    }
+                })
+                .map(|level| level.to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Test data: Variable report count, real-input report length
+///
+/// Reports of 5-8 levels increase by 1-3 per level, with every third report
+/// carrying one bad level so both parts have work for the dampener.
+fn generate_report_count_test(report_count: usize) -> String {
+    (0..report_count)
+        .map(|report| {
+            let length = 5 + report % 4;
+            let mut level = 10 + (report % 50) as i32;
+            (0..length)
+                .map(|index| {
+                    level += 1 + ((report + index) % 3) as i32;
+                    if report % 3 == 0 && index == report % length {
+                        level + 10
+                    } else {
+                        level
+                    }
+                })
+                .map(|level| level.to_string())
+                .collect::<Vec<String>>()
+                .join(" ")
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+#[cfg(not(feature = "parallel"))]
+criterion_group!(
+    name = benches;
+    config = create_criterion_benchmark("data");
+    targets = benchmark_dampener, benchmark_dampener_allocation
+);
+#[cfg(feature = "parallel")]
+criterion_group!(
+    name = benches;
+    config = create_criterion_benchmark("data");
+    targets = benchmark_dampener, benchmark_dampener_allocation, benchmark_parallel
+);
+criterion_main!(benches);
@@ -10,8 +10,15 @@
 //! reports safe. If removing any single level makes a report safe,
 //! then the report is considered safe.
 
-use anyhow::Result;
-use itertools::Itertools;
+use anyhow::{bail, Result};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use shared::input::{parse_number_rows, parse_token};
+use shared::iter::AocIterExt;
+use shared::plotting::{create_histogram, PlotTheme};
+use shared::runner::{Solution, Variant};
+use shared::validate::check_lines;
+use std::fmt;
 
 /// Example input from the problem statement used for testing and documentation.
 pub const EXAMPLE_INPUT: &str = "7 6 4 2 1
@@ -21,11 +28,75 @@ pub const EXAMPLE_INPUT: &str = "7 6 4 2 1
 8 6 4 4 1
 1 3 6 7 9";
 
-/// Minimum safe difference between adjacent levels in a reactor report.
-const MIN_SAFE_DIFF: i32 = 1;
+/// Registry entry used by the workspace runner.
+pub const SOLUTION: Solution = Solution {
+    day: 2,
+    title: "Red-Nosed Reports",
+    example_input: EXAMPLE_INPUT,
+    example_input_part2: None,
+    part1: |input| Ok(solve_part1(input)?.to_string()),
+    part2: Some(|input| Ok(solve_part2(input)?.to_string())),
+    variants: &[
+        Variant {
+            name: "naive",
+            part: 2,
+            solver: |input| Ok(solve_part2_naive(input)?.to_string()),
+        },
+        Variant {
+            name: "skipping",
+            part: 2,
+            solver: |input| Ok(solve_part2_skipping(input)?.to_string()),
+        },
+    ],
+    validate: Some(|input| {
+        check_lines(input, |line| {
+            line.split_whitespace()
+                .map(|level| parse_token::<i32>(line, level, "an i32 level"))
+                .collect::<Result<Vec<_>>>()
+        })
+    }),
+    explain: Some(explain_reports),
+};
+
+/// Thresholds deciding which steps between adjacent levels are safe.
+///
+/// The defaults are the puzzle's rules: adjacent levels differ by 1-3 and
+/// never stay equal.
+///
+/// # Attributes
+/// * `min_diff` - Minimum absolute difference of a step that changes the
+///   level
+/// * `max_diff` - Maximum absolute difference of any step
+/// * `allow_equal` - Whether adjacent levels may be equal; equal levels do
+///   not establish or break the report's direction
+///
+/// # Examples
+///
+/// ```
+/// # use day02::{is_safe_with, SafetyConfig};
+/// let tolerant = SafetyConfig {
+///     max_diff: 4,
+///     ..SafetyConfig::default()
+/// };
+/// assert!(is_safe_with(&[1, 5, 6], &tolerant)); // Jump of 4 now allowed
+/// assert!(!is_safe_with(&[1, 5, 6], &SafetyConfig::default()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SafetyConfig {
+    pub min_diff: i32,
+    pub max_diff: i32,
+    pub allow_equal: bool,
+}
 
-/// Maximum safe difference between adjacent levels in a reactor report.
-const MAX_SAFE_DIFF: i32 = 3;
+impl Default for SafetyConfig {
+    fn default() -> Self {
+        SafetyConfig {
+            min_diff: 1,
+            max_diff: 3,
+            allow_equal: false,
+        }
+    }
+}
 
 /// Solves Part 1: Counts how many reports are safe.
 ///
@@ -50,7 +121,18 @@ const MAX_SAFE_DIFF: i32 = 3;
 /// assert_eq!(solve_part1(input).unwrap(), 2); // Both reports are safe
 /// ```
 pub fn solve_part1(input: &str) -> Result<usize> {
-    parse_input(input).map(|reports| reports.iter().filter(|report| is_safe(report)).count())
+    let config = SafetyConfig::default();
+    let mut buffer = Vec::new();
+
+    parse_input(input).map(|reports| {
+        reports
+            .iter()
+            .filter(|report| {
+                diffs_into(report, &mut buffer);
+                diffs_are_safe(buffer.iter().copied(), &config)
+            })
+            .count()
+    })
 }
 
 /// Checks if a report is safe according to reactor safety rules.
@@ -74,26 +156,108 @@ pub fn solve_part1(input: &str) -> Result<usize> {
 /// assert_eq!(is_safe(&vec![8, 6, 4, 4, 1]), false); // No change (4->4)
 /// ```
 pub fn is_safe(report: &[i32]) -> bool {
+    is_safe_with(report, &SafetyConfig::default())
+}
+
+/// Checks if a report is safe according to custom safety thresholds.
+///
+/// A report is safe if:
+/// 1. All levels are either increasing or decreasing, or equal when
+///    `config.allow_equal` is set
+/// 2. Adjacent levels that differ do so by `config.min_diff` to
+///    `config.max_diff`
+///
+/// # Parameters
+/// * `report` - Vector of reactor levels to analyze for safety
+/// * `config` - Thresholds deciding which steps are safe
+///
+/// # Returns
+/// True if the report meets all safety criteria, false otherwise
+///
+/// # Examples
+///
+/// ```
+/// # use day02::{is_safe_with, SafetyConfig};
+/// let plateaus = SafetyConfig {
+///     allow_equal: true,
+///     ..SafetyConfig::default()
+/// };
+/// assert!(is_safe_with(&[8, 6, 4, 4, 1], &plateaus)); // 4->4 now allowed
+/// assert!(!is_safe_with(&[1, 3, 2], &plateaus)); // Still not monotonic
+/// ```
+pub fn is_safe_with(report: &[i32], config: &SafetyConfig) -> bool {
+    levels_are_safe(report.iter(), config)
+}
+
+/// Computes the differences between adjacent levels of a report.
+///
+/// The safety checks and the Problem Dampener all work on these
+/// differences: removing a level merges the two differences around it into
+/// their sum, so the dampener never recomputes them from the levels.
+///
+/// # Parameters
+/// * `report` - Vector of reactor levels
+///
+/// # Returns
+/// `report[i + 1] - report[i]` for every adjacent pair, one shorter than
+/// the report (empty for reports of fewer than two levels)
+///
+/// # Examples
+///
+/// ```
+/// # use day02::diffs;
+/// assert_eq!(diffs(&[7, 6, 4, 2, 1]), vec![-1, -2, -2, -1]);
+/// assert_eq!(diffs(&[1]), Vec::<i32>::new());
+/// ```
+pub fn diffs(report: &[i32]) -> Vec<i32> {
+    let mut buffer = Vec::with_capacity(report.len().saturating_sub(1));
+    diffs_into(report, &mut buffer);
+    buffer
+}
+
+/// Fills `buffer` with the differences of a report, reusing its allocation.
+fn diffs_into(report: &[i32], buffer: &mut Vec<i32>) {
+    buffer.clear();
+    buffer.extend(report.windows(2).map(|pair| pair[1] - pair[0]));
+}
+
+/// Checks the safety rules over a sequence of levels.
+fn levels_are_safe<'a>(levels: impl Iterator<Item = &'a i32>, config: &SafetyConfig) -> bool {
+    diffs_are_safe(
+        levels.tuple_windows_array::<2>().map(|[a, b]| b - a),
+        config,
+    )
+}
+
+/// Checks the safety rules over a sequence of differences.
+fn diffs_are_safe(mut diffs: impl Iterator<Item = i32>, config: &SafetyConfig) -> bool {
     let mut direction = None;
 
-    report.iter().tuple_windows().all(|(a, b)| {
-        let diff = b - a;
+    diffs.all(|diff| is_safe_diff(diff, &mut direction, config))
+}
 
-        // Check if difference is within valid range
-        if diff.abs() < MIN_SAFE_DIFF || diff.abs() > MAX_SAFE_DIFF {
-            return false;
-        }
+/// Checks whether a step changing the level by `diff` is safe, establishing
+/// the report's direction on its first step that changes the level.
+fn is_safe_diff(diff: i32, direction: &mut Option<bool>, config: &SafetyConfig) -> bool {
+    // Equal levels neither establish nor break the direction
+    if diff == 0 {
+        return config.allow_equal;
+    }
 
-        // Check/establish monotonicity
-        let is_increasing = diff > 0;
-        match direction {
-            None => {
-                direction = Some(is_increasing);
-                true
-            }
-            Some(dir) => dir == is_increasing,
+    // Check if difference is within valid range
+    if diff.abs() < config.min_diff || diff.abs() > config.max_diff {
+        return false;
+    }
+
+    // Check/establish monotonicity
+    let is_increasing = diff > 0;
+    match direction {
+        None => {
+            *direction = Some(is_increasing);
+            true
         }
-    })
+        Some(dir) => *dir == is_increasing,
+    }
 }
 
 /// Solves Part 2: Counts how many reports are safe with the Problem
@@ -122,10 +286,15 @@ pub fn is_safe(report: &[i32]) -> bool {
 /// assert_eq!(solve_part2(input).unwrap(), 3); // All can be made safe
 /// ```
 pub fn solve_part2(input: &str) -> Result<usize> {
+    let mut buffer = Vec::new();
+
     parse_input(input).map(|reports| {
         reports
             .iter()
-            .filter(|report| is_safe_with_dampener(report))
+            .filter(|report| {
+                diffs_into(report, &mut buffer);
+                verdict_from_diffs(&buffer) != SafetyVerdict::Unsafe
+            })
             .count()
     })
 }
@@ -136,6 +305,12 @@ pub fn solve_part2(input: &str) -> Result<usize> {
 /// report to make it safe. A report is considered safe if it's either
 /// already safe, or becomes safe after removing any single level.
 ///
+/// Runs in O(n): only a level next to the first unsafe step can be the one
+/// to remove. Removing any earlier level leaves that step and the report's
+/// direction intact, and removing any later level leaves the step itself
+/// intact. So at most three removals are checked, each on the report's
+/// [`diffs`] with the two differences around the removed level merged.
+///
 /// # Parameters
 /// * `report` - Vector of reactor levels to analyze with dampening capability
 ///
@@ -155,6 +330,187 @@ pub fn solve_part2(input: &str) -> Result<usize> {
 ///                                                    // large
 /// ```
 pub fn is_safe_with_dampener(report: &[i32]) -> bool {
+    verdict_from_diffs(&diffs(report)) != SafetyVerdict::Unsafe
+}
+
+/// Outcome of checking a report with the Problem Dampener active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SafetyVerdict {
+    /// The report is safe without removing any level
+    Safe,
+    /// The report becomes safe once the level at this index is removed
+    SafeByRemoving(usize),
+    /// No single removal makes the report safe
+    Unsafe,
+}
+
+/// Checks a report with the Problem Dampener active, identifying the level
+/// the dampener removed.
+///
+/// Uses the same O(n) search as [`is_safe_with_dampener`]. When several
+/// removals make the report safe, the lowest index among the levels next to
+/// the first unsafe step is reported.
+///
+/// # Parameters
+/// * `report` - Vector of reactor levels to analyze with dampening capability
+///
+/// # Returns
+/// Whether the report is safe outright, safe after removing one level, or
+/// unsafe
+///
+/// # Examples
+///
+/// ```
+/// # use day02::{safety_verdict, SafetyVerdict};
+/// assert_eq!(safety_verdict(&[7, 6, 4, 2, 1]), SafetyVerdict::Safe);
+/// assert_eq!(safety_verdict(&[1, 3, 2, 4, 5]), SafetyVerdict::SafeByRemoving(1));
+/// assert_eq!(safety_verdict(&[1, 2, 7, 8, 9]), SafetyVerdict::Unsafe);
+/// ```
+pub fn safety_verdict(report: &[i32]) -> SafetyVerdict {
+    verdict_from_diffs(&diffs(report))
+}
+
+/// Checks a report given as its differences with the Problem Dampener
+/// active.
+fn verdict_from_diffs(diffs: &[i32]) -> SafetyVerdict {
+    let Some(step) = first_unsafe_diff(diffs) else {
+        return SafetyVerdict::Safe;
+    };
+
+    // The first unsafe step runs from level `step` to level `step + 1`
+    let config = SafetyConfig::default();
+    (step.saturating_sub(1)..=step + 1)
+        .find(|&skip| diffs_are_safe(diffs_skipping(diffs, skip), &config))
+        .map_or(SafetyVerdict::Unsafe, SafetyVerdict::SafeByRemoving)
+}
+
+/// Returns the index of the first unsafe difference, which is also the
+/// index of the level starting the first unsafe step, if any.
+fn first_unsafe_diff(diffs: &[i32]) -> Option<usize> {
+    let config = SafetyConfig::default();
+    let mut direction = None;
+
+    diffs
+        .iter()
+        .position(|&diff| !is_safe_diff(diff, &mut direction, &config))
+}
+
+/// Returns the differences of a report once the level at index `skip` is
+/// removed.
+fn diffs_skipping(diffs: &[i32], skip: usize) -> impl Iterator<Item = i32> + '_ {
+    // Removing an inner level merges the differences on either side of it
+    let merged = (skip > 0 && skip < diffs.len()).then(|| diffs[skip - 1] + diffs[skip]);
+    let before = &diffs[..skip.saturating_sub(1)];
+    let after = &diffs[(skip + 1).min(diffs.len())..];
+
+    before
+        .iter()
+        .copied()
+        .chain(merged)
+        .chain(after.iter().copied())
+}
+
+/// Checks if a report is safe once the level at index `skip` is removed.
+fn is_safe_skipping(report: &[i32], skip: usize) -> bool {
+    levels_are_safe(
+        report
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| index != skip)
+            .map(|(_, level)| level),
+        &SafetyConfig::default(),
+    )
+}
+
+/// Checks if a report is safe when a dampener may remove up to `k` levels.
+///
+/// Generalizes [`is_safe_with_dampener`], which is the `k = 1` case. For each
+/// direction, a dynamic program records the fewest removals leaving a safe
+/// report that ends at each level. Only the `k + 1` levels before a level can
+/// precede it once the levels between them are removed, and prefixes needing
+/// more than `k` removals are pruned, so this runs in O(n·k) instead of
+/// trying every subset of levels.
+///
+/// # Parameters
+/// * `report` - Vector of reactor levels to analyze with dampening capability
+/// * `k` - Maximum number of levels the dampener may remove
+///
+/// # Returns
+/// True if the report is safe or can be made safe by removing at most `k`
+/// levels
+///
+/// # Examples
+///
+/// ```
+/// # use day02::is_safe_with_k_dampener;
+/// assert!(is_safe_with_k_dampener(&[7, 6, 4, 2, 1], 0)); // Already safe
+/// assert!(!is_safe_with_k_dampener(&[1, 9, 2, 9, 3], 1)); // Two bad levels
+/// assert!(is_safe_with_k_dampener(&[1, 9, 2, 9, 3], 2)); // Remove both 9s
+/// ```
+pub fn is_safe_with_k_dampener(report: &[i32], k: usize) -> bool {
+    [true, false]
+        .into_iter()
+        .any(|increasing| min_removals(report, k, increasing).is_some_and(|removals| removals <= k))
+}
+
+/// Returns the fewest removals making a report safe in the given direction,
+/// or `None` if more than `k` removals are needed.
+fn min_removals(report: &[i32], k: usize, increasing: bool) -> Option<usize> {
+    let n = report.len();
+    if n == 0 {
+        return Some(0);
+    }
+
+    let config = SafetyConfig::default();
+    // removals[i]: fewest removals leaving a safe prefix that ends at level i
+    let mut removals: Vec<Option<usize>> = Vec::with_capacity(n);
+
+    for (i, &level) in report.iter().enumerate() {
+        // Keeping level i first means removing every level before it
+        let first = (i <= k).then_some(i);
+        let best = (i.saturating_sub(k.saturating_add(1))..i)
+            .filter_map(|j| {
+                let skipped = i - j - 1;
+                removals[j]
+                    .filter(|_| is_safe_diff(level - report[j], &mut Some(increasing), &config))
+                    .map(|removed| removed + skipped)
+            })
+            .chain(first)
+            .min()
+            .filter(|&removed| removed <= k);
+        removals.push(best);
+    }
+
+    // Keeping level i last means removing every level after it
+    removals
+        .iter()
+        .enumerate()
+        .filter_map(|(i, removed)| removed.map(|removed| removed + n - 1 - i))
+        .min()
+}
+
+/// Naive O(n²) implementation of [`is_safe_with_dampener`] for performance
+/// comparison.
+///
+/// Tries removing every level in turn, collecting each shortened report into
+/// a new vector.
+///
+/// # Parameters
+/// * `report` - Vector of reactor levels to analyze with dampening capability
+///
+/// # Returns
+/// True if the report is safe or can be made safe by removing one level
+///
+/// # Examples
+///
+/// ```
+/// # use day02::is_safe_with_dampener_naive;
+/// assert!(is_safe_with_dampener_naive(&[1, 3, 2, 4, 5])); // Safe by
+///                                                         // removing 3
+/// assert!(!is_safe_with_dampener_naive(&[1, 2, 7, 8, 9])); // Cannot be
+///                                                          // made safe
+/// ```
+pub fn is_safe_with_dampener_naive(report: &[i32]) -> bool {
     // Check if already safe without removing any elements
     is_safe(report)
         // Or try removing each element one by one until we find a safe sequence
@@ -170,6 +526,546 @@ pub fn is_safe_with_dampener(report: &[i32]) -> bool {
         })
 }
 
+/// Allocation-free brute-force implementation of [`is_safe_with_dampener`]
+/// for performance comparison.
+///
+/// Tries removing every level in turn like [`is_safe_with_dampener_naive`],
+/// but checks each shortened report by skipping the removed index while
+/// iterating the original slice instead of collecting a new vector.
+///
+/// # Parameters
+/// * `report` - Vector of reactor levels to analyze with dampening capability
+///
+/// # Returns
+/// True if the report is safe or can be made safe by removing one level
+///
+/// # Examples
+///
+/// ```
+/// # use day02::is_safe_with_dampener_skipping;
+/// assert!(is_safe_with_dampener_skipping(&[1, 3, 2, 4, 5])); // Safe by
+///                                                            // removing 3
+/// assert!(!is_safe_with_dampener_skipping(&[1, 2, 7, 8, 9])); // Cannot be
+///                                                             // made safe
+/// ```
+pub fn is_safe_with_dampener_skipping(report: &[i32]) -> bool {
+    is_safe(report) || (0..report.len()).any(|skip| is_safe_skipping(report, skip))
+}
+
+/// Allocation-free brute-force implementation of Part 2 for performance
+/// comparison.
+///
+/// Counts the reports that are safe according to
+/// [`is_safe_with_dampener_skipping`].
+///
+/// # Parameters
+/// * `input` - Multi-line string containing reactor level reports
+///
+/// # Returns
+/// Number of safe reports (including those made safe by dampening) as an
+/// integer
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day02::solve_part2_skipping;
+/// let input = "7 6 4 2 1\n1 3 2 4 5\n8 6 4 4 1";
+/// assert_eq!(solve_part2_skipping(input).unwrap(), 3); // Same result as
+///                                                      // solve_part2
+/// ```
+pub fn solve_part2_skipping(input: &str) -> Result<usize> {
+    parse_input(input).map(|reports| {
+        reports
+            .iter()
+            .filter(|report| is_safe_with_dampener_skipping(report))
+            .count()
+    })
+}
+
+/// Naive implementation of Part 2 for performance comparison.
+///
+/// Counts the reports that are safe according to
+/// [`is_safe_with_dampener_naive`].
+///
+/// # Parameters
+/// * `input` - Multi-line string containing reactor level reports
+///
+/// # Returns
+/// Number of safe reports (including those made safe by dampening) as an
+/// integer
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day02::solve_part2_naive;
+/// let input = "7 6 4 2 1\n1 3 2 4 5\n8 6 4 4 1";
+/// assert_eq!(solve_part2_naive(input).unwrap(), 3); // Same result as
+///                                                   // solve_part2
+/// ```
+pub fn solve_part2_naive(input: &str) -> Result<usize> {
+    parse_input(input).map(|reports| {
+        reports
+            .iter()
+            .filter(|report| is_safe_with_dampener_naive(report))
+            .count()
+    })
+}
+
+/// Why a step between two adjacent levels is unsafe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ViolationKind {
+    /// The levels are equal
+    Unchanged,
+    /// The levels differ by more than the maximum safe difference
+    TooLarge,
+    /// The step reverses the direction set by the report's first step
+    DirectionConflict,
+}
+
+/// The first unsafe step of a report.
+///
+/// # Attributes
+/// * `index` - Index of the level the step starts from
+/// * `levels` - The two levels of the step
+/// * `difference` - Signed change from the first level to the second
+/// * `kind` - Which safety rule the step breaks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Violation {
+    pub index: usize,
+    pub levels: (i32, i32),
+    pub difference: i32,
+    pub kind: ViolationKind,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (from, to) = self.levels;
+        write!(
+            f,
+            "levels {} and {} ({from} -> {to}) ",
+            self.index,
+            self.index + 1
+        )?;
+        match self.kind {
+            ViolationKind::Unchanged => f.write_str("do not change"),
+            ViolationKind::TooLarge => write!(
+                f,
+                "change by {:+}, more than {}",
+                self.difference,
+                SafetyConfig::default().max_diff
+            ),
+            ViolationKind::DirectionConflict => write!(
+                f,
+                "change by {:+}, {} in {} report",
+                self.difference,
+                if self.difference > 0 {
+                    "increasing"
+                } else {
+                    "decreasing"
+                },
+                if self.difference > 0 {
+                    "a decreasing"
+                } else {
+                    "an increasing"
+                }
+            ),
+        }
+    }
+}
+
+/// Diagnostic explanation of a report's safety.
+///
+/// # Attributes
+/// * `violation` - The report's first unsafe step, or `None` if it is safe
+/// * `verdict` - The outcome once the Problem Dampener is active
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SafetyExplanation {
+    pub violation: Option<Violation>,
+    pub verdict: SafetyVerdict,
+}
+
+impl fmt::Display for SafetyExplanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.violation {
+            None => f.write_str("safe"),
+            Some(violation) => {
+                write!(f, "unsafe, {violation}")?;
+                match self.verdict {
+                    SafetyVerdict::SafeByRemoving(index) => {
+                        write!(f, "; safe after removing level {index}")
+                    }
+                    _ => f.write_str("; unsafe even with the dampener"),
+                }
+            }
+        }
+    }
+}
+
+/// Explains why a report is safe or unsafe.
+///
+/// Describes the first unsafe step: its levels, their difference, and
+/// whether the step is unchanged, too large or reverses the report's
+/// direction. Also reports whether the Problem Dampener rescues the report.
+///
+/// # Parameters
+/// * `report` - Vector of reactor levels to explain
+///
+/// # Returns
+/// The report's first violation, if any, and its dampener verdict
+///
+/// # Examples
+///
+/// ```
+/// # use day02::{explain, SafetyVerdict, ViolationKind};
+/// let explanation = explain(&[1, 3, 2, 4, 5]);
+/// let violation = explanation.violation.unwrap();
+/// assert_eq!((violation.index, violation.levels), (1, (3, 2)));
+/// assert_eq!(violation.kind, ViolationKind::DirectionConflict);
+/// assert_eq!(explanation.verdict, SafetyVerdict::SafeByRemoving(1));
+/// assert_eq!(
+///     explanation.to_string(),
+///     "unsafe, levels 1 and 2 (3 -> 2) change by -1, decreasing in an \
+///      increasing report; safe after removing level 1"
+/// );
+/// ```
+pub fn explain(report: &[i32]) -> SafetyExplanation {
+    let config = SafetyConfig::default();
+    let diffs = diffs(report);
+    let violation = first_unsafe_diff(&diffs).map(|index| {
+        let difference = diffs[index];
+        let kind = if difference == 0 {
+            ViolationKind::Unchanged
+        } else if difference.abs() > config.max_diff {
+            ViolationKind::TooLarge
+        } else {
+            ViolationKind::DirectionConflict
+        };
+        Violation {
+            index,
+            levels: (report[index], report[index + 1]),
+            difference,
+            kind,
+        }
+    });
+
+    SafetyExplanation {
+        violation,
+        verdict: verdict_from_diffs(&diffs),
+    }
+}
+
+/// Explains every report of an input, one line per report.
+///
+/// Used by the runner's `--verbose` mode.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing reactor level reports
+///
+/// # Returns
+/// One line per report with its levels and [`explain`]'s explanation
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day02::explain_reports;
+/// let text = explain_reports("7 6 4 2 1\n8 6 4 4 1").unwrap();
+/// assert_eq!(
+///     text,
+///     "Report 1 [7 6 4 2 1]: safe\n\
+///      Report 2 [8 6 4 4 1]: unsafe, levels 2 and 3 (4 -> 4) do not change; \
+///      safe after removing level 2"
+/// );
+/// ```
+pub fn explain_reports(input: &str) -> Result<String> {
+    let reports = parse_input(input)?;
+    Ok(reports
+        .iter()
+        .enumerate()
+        .map(|(index, report)| {
+            let levels = report.iter().map(i32::to_string).collect::<Vec<_>>();
+            format!(
+                "Report {} [{}]: {}",
+                index + 1,
+                levels.join(" "),
+                explain(report)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
+
+/// Counts of the unsafe steps in a single report.
+///
+/// # Attributes
+/// * `monotonicity` - Steps against the report's majority direction: the
+///   fewer of its increasing and decreasing steps
+/// * `out_of_range` - Steps whose difference lies outside the safe range,
+///   including steps that leave the level unchanged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ReportViolations {
+    pub monotonicity: usize,
+    pub out_of_range: usize,
+}
+
+impl ReportViolations {
+    /// Returns the number of violations of either kind; a step that breaks
+    /// both rules counts twice.
+    pub fn total(&self) -> usize {
+        self.monotonicity + self.out_of_range
+    }
+}
+
+/// Violation counts of every report of an input.
+///
+/// # Attributes
+/// * `reports` - Violations of each report, in input order
+/// * `monotonicity` - Monotonicity violations summed over all reports
+/// * `out_of_range` - Out-of-range steps summed over all reports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ViolationStats {
+    pub reports: Vec<ReportViolations>,
+    pub monotonicity: usize,
+    pub out_of_range: usize,
+}
+
+impl ViolationStats {
+    /// Draws the histogram of violations per report with the shared plotter.
+    ///
+    /// # Parameters
+    /// * `filename` - Output filename; its extension selects SVG or PNG
+    /// * `theme` - Dimensions, colors and fonts of the chart
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are no reports or the chart cannot be
+    /// written.
+    pub fn plot(&self, filename: &str, theme: &PlotTheme) -> Result<()> {
+        let totals: Vec<usize> = self.reports.iter().map(ReportViolations::total).collect();
+        let (Some(&min), Some(&max)) = (totals.iter().min(), totals.iter().max()) else {
+            bail!("No reports to plot");
+        };
+        let values: Vec<f64> = totals.iter().map(|&total| total as f64).collect();
+        // One bin per possible number of violations
+        create_histogram(
+            filename,
+            "Day 2: Violations per Report",
+            &values,
+            max - min + 1,
+            theme,
+        )
+    }
+}
+
+/// Counts the monotonicity violations and out-of-range steps of a report.
+///
+/// Unlike [`explain`], which stops at the first unsafe step, this counts
+/// every unsafe step, showing how far a report is from safe.
+///
+/// # Parameters
+/// * `report` - Vector of reactor levels to analyze
+///
+/// # Returns
+/// The report's monotonicity violations and out-of-range steps
+///
+/// # Examples
+///
+/// ```
+/// # use day02::{count_violations, ReportViolations};
+/// let violations = count_violations(&[1, 3, 2, 4, 4, 9]);
+/// assert_eq!(violations, ReportViolations { monotonicity: 1, out_of_range: 2 });
+/// ```
+pub fn count_violations(report: &[i32]) -> ReportViolations {
+    let config = SafetyConfig::default();
+    let diffs = diffs(report);
+
+    let increasing = diffs.iter().filter(|&&diff| diff > 0).count();
+    let decreasing = diffs.iter().filter(|&&diff| diff < 0).count();
+    let out_of_range = diffs
+        .iter()
+        .filter(|diff| !(config.min_diff..=config.max_diff).contains(&diff.abs()))
+        .count();
+
+    ReportViolations {
+        monotonicity: increasing.min(decreasing),
+        out_of_range,
+    }
+}
+
+/// Counts the violations of every report of an input.
+///
+/// Useful to compare the real input with synthetic benchmark inputs and to
+/// draw their distribution with [`ViolationStats::plot`].
+///
+/// # Parameters
+/// * `input` - Multi-line string containing reactor level reports
+///
+/// # Returns
+/// Per-report [`count_violations`] results and their totals
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day02::{violation_stats, EXAMPLE_INPUT};
+/// let stats = violation_stats(EXAMPLE_INPUT).unwrap();
+/// assert_eq!(stats.reports.len(), 6);
+/// assert_eq!((stats.monotonicity, stats.out_of_range), (1, 3));
+/// ```
+pub fn violation_stats(input: &str) -> Result<ViolationStats> {
+    let reports: Vec<ReportViolations> = parse_input(input)?
+        .iter()
+        .map(|report| count_violations(report))
+        .collect();
+
+    Ok(ViolationStats {
+        monotonicity: reports.iter().map(|report| report.monotonicity).sum(),
+        out_of_range: reports.iter().map(|report| report.out_of_range).sum(),
+        reports,
+    })
+}
+
+/// Parallel implementation of Part 1 for performance comparison.
+///
+/// Reports are independent, so rayon checks them concurrently and sums the
+/// safe ones. Only pays off once there are enough reports to amortize the
+/// thread pool overhead.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing reactor level reports
+///
+/// # Returns
+/// Number of safe reports as an integer
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day02::solve_part1_parallel;
+/// let input = "7 6 4 2 1\n1 3 6 7 9";
+/// assert_eq!(solve_part1_parallel(input).unwrap(), 2);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn solve_part1_parallel(input: &str) -> Result<usize> {
+    parse_input(input).map(|reports| reports.par_iter().filter(|report| is_safe(report)).count())
+}
+
+/// Parallel implementation of Part 2 for performance comparison.
+///
+/// Checks the reports concurrently with [`is_safe_with_dampener`], the same
+/// way [`solve_part1_parallel`] does for Part 1.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing reactor level reports
+///
+/// # Returns
+/// Number of safe reports (including those made safe by dampening) as an
+/// integer
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day02::solve_part2_parallel;
+/// let input = "7 6 4 2 1\n1 3 2 4 5\n8 6 4 4 1";
+/// assert_eq!(solve_part2_parallel(input).unwrap(), 3);
+/// ```
+#[cfg(feature = "parallel")]
+pub fn solve_part2_parallel(input: &str) -> Result<usize> {
+    parse_input(input).map(|reports| {
+        reports
+            .par_iter()
+            .filter(|report| is_safe_with_dampener(report))
+            .count()
+    })
+}
+
+/// Classifies every report in a single pass over the input.
+///
+/// Each report is checked once with [`safety_verdict`], so both parts'
+/// answers and the reports only the dampener rescues come from one
+/// traversal: Part 1 is `safe` and Part 2 is `safe + safe_with_dampener`.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing reactor level reports
+///
+/// # Returns
+/// `(safe, safe_with_dampener, unsafe)`: the number of reports safe outright,
+/// safe only after removing one level, and unsafe either way
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day02::{classify_reports, EXAMPLE_INPUT};
+/// assert_eq!(classify_reports(EXAMPLE_INPUT).unwrap(), (2, 2, 2));
+/// ```
+pub fn classify_reports(input: &str) -> Result<(usize, usize, usize)> {
+    let mut buffer = Vec::new();
+
+    parse_input(input).map(|reports| {
+        reports
+            .iter()
+            .fold((0, 0, 0), |(safe, dampened, unsafe_reports), report| {
+                diffs_into(report, &mut buffer);
+                match verdict_from_diffs(&buffer) {
+                    SafetyVerdict::Safe => (safe + 1, dampened, unsafe_reports),
+                    SafetyVerdict::SafeByRemoving(_) => (safe, dampened + 1, unsafe_reports),
+                    SafetyVerdict::Unsafe => (safe, dampened, unsafe_reports + 1),
+                }
+            })
+    })
+}
+
+/// Solves both parts from a single parse and classification pass.
+///
+/// Builds on [`classify_reports`]: reports safe outright answer Part 1, and
+/// adding those the dampener rescues answers Part 2.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing reactor level reports
+///
+/// # Returns
+/// `(part1, part2)`, the answers of [`solve_part1`] and [`solve_part2`]
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day02::{solve_both, EXAMPLE_INPUT};
+/// assert_eq!(solve_both(EXAMPLE_INPUT).unwrap(), (2, 4));
+/// ```
+pub fn solve_both(input: &str) -> Result<(usize, usize)> {
+    let (safe, dampened, _) = classify_reports(input)?;
+    Ok((safe, safe + dampened))
+}
+
 /// Parses the input string into a vector of reports, where each report is a
 /// vector of levels.
 ///
@@ -184,7 +1080,8 @@ pub fn is_safe_with_dampener(report: &[i32]) -> bool {
 ///
 /// # Errors
 ///
-/// Returns an error if any value cannot be parsed as an `i32`.
+/// Returns an error naming the row and column of any value that cannot be
+/// parsed as an `i32`.
 ///
 /// # Examples
 ///
@@ -195,12 +1092,5 @@ pub fn is_safe_with_dampener(report: &[i32]) -> bool {
 /// assert_eq!(reports, vec![vec![1, 2, 3], vec![4, 5, 6]]);
 /// ```
 pub fn parse_input(input: &str) -> Result<Vec<Vec<i32>>> {
-    input
-        .lines()
-        .filter(|line| !line.trim().is_empty())
-        .map(|line| {
-            let levels = line.split_whitespace().map(|s| s.parse()).try_collect()?;
-            Ok(levels)
-        })
-        .collect()
+    parse_number_rows(input, "an i32 level")
 }
@@ -1,4 +1,11 @@
-use day02::{is_safe, is_safe_with_dampener, parse_input, solve_part1, solve_part2, EXAMPLE_INPUT};
+use day02::{
+    classify_reports, count_violations, diffs, explain, explain_reports, is_safe, is_safe_with,
+    is_safe_with_dampener, is_safe_with_dampener_naive, is_safe_with_dampener_skipping,
+    is_safe_with_k_dampener, parse_input, safety_verdict, solve_both, solve_part1, solve_part2,
+    solve_part2_naive, solve_part2_skipping, violation_stats, ReportViolations, SafetyConfig,
+    SafetyVerdict, ViolationKind, EXAMPLE_INPUT,
+};
+use proptest::prelude::*;
 use rstest::rstest;
 
 // ===== PARSE INPUT TESTS =====
@@ -27,10 +34,12 @@ fn test_parse_input_edge_cases(
 }
 
 #[rstest]
-#[case("1 abc 3", "invalid digit")] // Non-numeric values
-#[case("1 2 3\n4 def 6", "invalid digit")] // Mixed valid/invalid lines
-#[case("abc def ghi", "invalid digit")] // All non-numeric
-#[case("1 2\n\n3 xyz", "invalid digit")] // Error after empty line
+#[case("1 abc 3", "expected an i32 level, found 'abc'")] // Non-numeric values
+#[case("1 2 3\n4 def 6", "expected an i32 level, found 'def'")] // Mixed valid/invalid lines
+#[case("abc def ghi", "expected an i32 level, found 'abc'")] // All non-numeric
+#[case("1 2\n\n3 xyz", "expected an i32 level, found 'xyz'")] // Error after empty line
+#[case("1 2 3\n4 def 6", "Line 2, column 3:")] // Error names token position
+#[case("1 2\n\n3 xyz", "Line 3, column 3:")] // Rows count blank lines
 fn test_parse_input_errors(#[case] input: &str, #[case] expected_error: &str) {
     let result = parse_input(input);
     assert!(result.is_err(), "Should error on input: {input:?}");
@@ -43,6 +52,16 @@ fn test_parse_input_errors(#[case] input: &str, #[case] expected_error: &str) {
 
 // ===== CORE FUNCTION TESTS =====
 
+#[rstest]
+#[case(&[7, 6, 4, 2, 1], vec![-1, -2, -2, -1])] // Decreasing report
+#[case(&[1, 3, 6, 7, 9], vec![2, 3, 1, 2])] // Increasing report
+#[case(&[8, 6, 4, 4, 1], vec![-2, -2, 0, -3])] // Unchanged step
+#[case(&[5], vec![])] // Single level has no differences
+#[case(&[], vec![])] // Empty report has no differences
+fn test_diffs(#[case] levels: &[i32], #[case] expected: Vec<i32>) {
+    assert_eq!(diffs(levels), expected);
+}
+
 #[rstest]
 #[case(&[7, 6, 4, 2, 1], true)] // Safe: decreasing by 1 or 2
 #[case(&[1, 2, 7, 8, 9], false)] // Unsafe: 2->7 is increase of 5
@@ -64,6 +83,31 @@ fn test_is_safe_edge_cases(#[case] levels: &[i32], #[case] expected: bool) {
     assert_eq!(is_safe(levels), expected);
 }
 
+#[rstest]
+#[case(&[1, 2, 7, 8, 9], 1, 5, false, true)] // Jump of 5 within a wider range
+#[case(&[1, 2, 7, 8, 9], 1, 4, false, false)] // Jump of 5 still too large
+#[case(&[1, 3, 5, 7], 2, 3, false, true)] // Every step meets a raised minimum
+#[case(&[1, 2, 4, 6], 2, 3, false, false)] // Step of 1 below a raised minimum
+#[case(&[8, 6, 4, 4, 1], 1, 3, true, true)] // Plateau allowed
+#[case(&[8, 6, 4, 4, 1], 1, 3, false, false)] // Plateau rejected by default
+#[case(&[4, 4, 5, 3], 1, 3, true, false)] // Direction set after a plateau still holds
+#[case(&[5, 5, 5], 1, 3, true, true)] // Only plateaus
+#[case(&[1, 1, 2], 0, 3, false, false)] // Equal levels rejected despite zero minimum
+fn test_is_safe_with_configs(
+    #[case] levels: &[i32],
+    #[case] min_diff: i32,
+    #[case] max_diff: i32,
+    #[case] allow_equal: bool,
+    #[case] expected: bool,
+) {
+    let config = SafetyConfig {
+        min_diff,
+        max_diff,
+        allow_equal,
+    };
+    assert_eq!(is_safe_with(levels, &config), expected);
+}
+
 #[rstest]
 #[case(&[7, 6, 4, 2, 1], true)] // Safe without removing any level
 #[case(&[1, 2, 7, 8, 9], false)] // Unsafe regardless of removal
@@ -88,11 +132,284 @@ fn test_is_safe_with_dampener_edge_cases(#[case] levels: &[i32], #[case] expecte
     assert_eq!(is_safe_with_dampener(levels), expected);
 }
 
+#[rstest]
+#[case(is_safe_with_dampener, &[5, 1, 2, 3], true)] // Remove first level, which set the wrong direction
+#[case(is_safe_with_dampener_naive, &[5, 1, 2, 3], true)] // Remove first level (naive)
+#[case(is_safe_with_dampener, &[1, 2, 3, 9], true)] // Remove last level
+#[case(is_safe_with_dampener_naive, &[1, 2, 3, 9], true)] // Remove last level (naive)
+#[case(is_safe_with_dampener, &[3, 5, 4, 3, 2], true)] // Remove level before the first unsafe step
+#[case(is_safe_with_dampener_naive, &[3, 5, 4, 3, 2], true)] // Remove level before the first unsafe step (naive)
+#[case(is_safe_with_dampener, &[1, 2, 2, 2], false)] // Two unsafe steps
+#[case(is_safe_with_dampener_naive, &[1, 2, 2, 2], false)] // Two unsafe steps (naive)
+#[case(is_safe_with_dampener_skipping, &[5, 1, 2, 3], true)] // Remove first level (skipping)
+#[case(is_safe_with_dampener_skipping, &[1, 2, 3, 9], true)] // Remove last level (skipping)
+#[case(is_safe_with_dampener_skipping, &[1, 2, 2, 2], false)] // Two unsafe steps (skipping)
+fn test_dampener_removal_positions(
+    #[case] dampener: fn(&[i32]) -> bool,
+    #[case] levels: &[i32],
+    #[case] expected: bool,
+) {
+    assert_eq!(dampener(levels), expected);
+}
+
+#[rstest]
+#[case(&[7, 6, 4, 2, 1], SafetyVerdict::Safe)] // Safe without removing any level
+#[case(&[1, 2, 7, 8, 9], SafetyVerdict::Unsafe)] // Unsafe regardless of removal
+#[case(&[1, 3, 2, 4, 5], SafetyVerdict::SafeByRemoving(1))] // Remove 3
+#[case(&[8, 6, 4, 4, 1], SafetyVerdict::SafeByRemoving(2))] // Remove the first 4
+#[case(&[5, 1, 2, 3], SafetyVerdict::SafeByRemoving(0))] // Remove the first level
+#[case(&[1, 2, 3, 9], SafetyVerdict::SafeByRemoving(3))] // Remove the last level
+#[case(&[], SafetyVerdict::Safe)] // Empty report is safe
+fn test_safety_verdict(#[case] levels: &[i32], #[case] expected: SafetyVerdict) {
+    assert_eq!(safety_verdict(levels), expected);
+}
+
+#[rstest]
+#[case(&[1, 2, 7, 8, 9], Some((1, (2, 7), 5, ViolationKind::TooLarge)))] // Jump of 5
+#[case(&[9, 7, 6, 2, 1], Some((2, (6, 2), -4, ViolationKind::TooLarge)))] // Drop of 4
+#[case(&[8, 6, 4, 4, 1], Some((2, (4, 4), 0, ViolationKind::Unchanged)))] // No change
+#[case(&[1, 3, 2, 4, 5], Some((1, (3, 2), -1, ViolationKind::DirectionConflict)))] // Turns decreasing
+#[case(&[5, 4, 6], Some((1, (4, 6), 2, ViolationKind::DirectionConflict)))] // Turns increasing
+#[case(&[1, 3, 6, 7, 9], None)] // Safe report has no violation
+fn test_explain_violation(
+    #[case] levels: &[i32],
+    #[case] expected: Option<(usize, (i32, i32), i32, ViolationKind)>,
+) {
+    let violation = explain(levels).violation;
+    assert_eq!(
+        violation.map(|violation| (
+            violation.index,
+            violation.levels,
+            violation.difference,
+            violation.kind
+        )),
+        expected
+    );
+}
+
+#[rstest]
+#[case(&[7, 6, 4, 2, 1], "safe")] // Safe outright
+#[case(&[1, 2, 7, 8, 9], "unsafe, levels 1 and 2 (2 -> 7) change by +5, more than 3; unsafe even with the dampener")] // Too large
+#[case(&[5, 4, 6], "unsafe, levels 1 and 2 (4 -> 6) change by +2, increasing in a decreasing report; safe after removing level 0")] // Direction conflict
+fn test_explain_rendering(#[case] levels: &[i32], #[case] expected: &str) {
+    assert_eq!(explain(levels).to_string(), expected);
+}
+
+#[test]
+fn test_explain_reports_example() {
+    let text = explain_reports(EXAMPLE_INPUT).unwrap();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 6);
+    assert!(lines[0].starts_with("Report 1 [7 6 4 2 1]: safe"));
+    assert!(lines[3].ends_with("safe after removing level 1"));
+    assert!(explain_reports("1 x").is_err());
+}
+
+/// Every report of up to `max_len` levels drawn from `levels`, including
+/// the empty report.
+fn all_reports(levels: &[i32], max_len: usize) -> Vec<Vec<i32>> {
+    let mut layer: Vec<Vec<i32>> = vec![vec![]];
+    let mut reports = layer.clone();
+    for _ in 0..max_len {
+        layer = layer
+            .iter()
+            .flat_map(|report| {
+                levels
+                    .iter()
+                    .map(move |&level| [report.as_slice(), &[level]].concat())
+            })
+            .collect();
+        reports.extend(layer.iter().cloned());
+    }
+    reports
+}
+
+#[test]
+fn test_dampener_matches_naive_exhaustively() {
+    for report in &all_reports(&[1, 2, 3, 4, 5], 6) {
+        assert_eq!(
+            is_safe_with_dampener(report),
+            is_safe_with_dampener_naive(report),
+            "Dampeners disagree on {report:?}"
+        );
+        assert_eq!(
+            is_safe_with_dampener_skipping(report),
+            is_safe_with_dampener_naive(report),
+            "Brute-force dampeners disagree on {report:?}"
+        );
+        assert_eq!(explain(report).violation.is_none(), is_safe(report));
+        if let SafetyVerdict::SafeByRemoving(index) = safety_verdict(report) {
+            let dampened = [&report[..index], &report[index + 1..]].concat();
+            assert!(
+                is_safe(&dampened),
+                "Removing {index} leaves {report:?} unsafe"
+            );
+            assert!(!is_safe(report), "{report:?} is safe without removals");
+        }
+    }
+}
+
+#[rstest]
+#[case(&[1, 9, 2, 9, 3], 0, false)] // Unsafe without removals
+#[case(&[1, 9, 2, 9, 3], 1, false)] // One removal leaves a bad 9
+#[case(&[1, 9, 2, 9, 3], 2, true)] // Remove both 9s
+#[case(&[9, 9, 1, 2, 3], 2, true)] // Remove a leading run
+#[case(&[1, 2, 3, 0, 0, 0], 3, true)] // Remove a trailing run
+#[case(&[1, 2, 3, 0, 0, 0], 2, false)] // Trailing run too long
+#[case(&[1, 2, 9, 9, 9, 3], 3, true)] // Remove a run in the middle
+#[case(&[5, 5], 1, true)] // Single level left
+#[case(&[], 0, true)] // Empty report is safe
+#[case(&[1, 9, 2, 9, 3], usize::MAX, true)] // Unbounded removals must not overflow
+fn test_is_safe_with_k_dampener_cases(
+    #[case] levels: &[i32],
+    #[case] k: usize,
+    #[case] expected: bool,
+) {
+    assert_eq!(is_safe_with_k_dampener(levels, k), expected);
+}
+
+/// Fewest removals making a report safe, found by trying every subset of
+/// levels to remove.
+fn min_removals_oracle(report: &[i32]) -> usize {
+    (0u32..1 << report.len())
+        .filter(|removed| {
+            let kept: Vec<i32> = report
+                .iter()
+                .enumerate()
+                .filter(|&(index, _)| removed & (1 << index) == 0)
+                .map(|(_, &level)| level)
+                .collect();
+            is_safe(&kept)
+        })
+        .map(u32::count_ones)
+        .min()
+        .unwrap() as usize
+}
+
+#[test]
+fn test_k_dampener_matches_subset_oracle() {
+    // Levels spaced 1-4 apart, so both safe and unsafe steps occur
+    for report in &all_reports(&[1, 2, 4, 5, 9], 6) {
+        let min_removals = min_removals_oracle(report);
+        for k in 0..=4 {
+            assert_eq!(
+                is_safe_with_k_dampener(report, k),
+                min_removals <= k,
+                "k = {k} disagrees with the oracle on {report:?}"
+            );
+        }
+        assert_eq!(is_safe_with_k_dampener(report, 0), is_safe(report));
+        assert_eq!(
+            is_safe_with_k_dampener(report, 1),
+            is_safe_with_dampener(report)
+        );
+    }
+}
+
+// ===== VIOLATION STATISTICS TESTS =====
+
+#[rstest]
+#[case(&[7, 6, 4, 2, 1], 0, 0)] // Safe report
+#[case(&[1, 2, 7, 8, 9], 0, 1)] // One jump of 5
+#[case(&[1, 3, 2, 4, 5], 1, 0)] // One decreasing step in an increasing report
+#[case(&[8, 6, 4, 4, 1], 0, 1)] // Unchanged step is out of range
+#[case(&[1, 3, 2, 4, 3, 9], 2, 1)] // Two direction changes and a jump of 6
+#[case(&[5, 9, 8, 7], 1, 1)] // Minority jump breaks both rules
+#[case(&[1, 2, 1, 2], 1, 0)] // Ties count the fewer direction
+#[case(&[], 0, 0)] // Empty report
+fn test_count_violations(
+    #[case] levels: &[i32],
+    #[case] monotonicity: usize,
+    #[case] out_of_range: usize,
+) {
+    assert_eq!(
+        count_violations(levels),
+        ReportViolations {
+            monotonicity,
+            out_of_range
+        }
+    );
+}
+
+#[test]
+fn test_violation_stats_example() {
+    let stats = violation_stats(EXAMPLE_INPUT).unwrap();
+    let totals: Vec<usize> = stats.reports.iter().map(ReportViolations::total).collect();
+    assert_eq!(totals, vec![0, 1, 1, 1, 1, 0]);
+    assert_eq!((stats.monotonicity, stats.out_of_range), (1, 3));
+}
+
+#[test]
+fn test_violation_stats_plot() {
+    let filename = std::env::temp_dir().join("day02_violation_stats_test.svg");
+    let filename = filename.to_str().unwrap();
+    let stats = violation_stats(EXAMPLE_INPUT).unwrap();
+    stats
+        .plot(filename, &shared::plotting::PlotTheme::default())
+        .unwrap();
+    assert!(std::fs::metadata(filename).unwrap().len() > 0);
+    std::fs::remove_file(filename).ok();
+    std::fs::remove_file(shared::plotting::plot_data_path(filename)).ok();
+}
+
+#[test]
+fn test_violation_stats_plot_empty_input() {
+    let stats = violation_stats("").unwrap();
+    let error = stats
+        .plot("unused.svg", &shared::plotting::PlotTheme::default())
+        .unwrap_err();
+    assert!(error.to_string().contains("No reports"));
+}
+
+// ===== PROPERTY TESTS =====
+
+/// Arbitrary reports, or random walks with steps of at most 4 so that many
+/// reports are safe or one level away from safe.
+fn report_strategy() -> impl Strategy<Value = Vec<i32>> {
+    prop_oneof![
+        prop::collection::vec(-100..1000, 0..30),
+        (-100..1000, prop::collection::vec(-4..=4, 0..30)).prop_map(|(start, steps)| {
+            std::iter::once(start)
+                .chain(steps.into_iter().scan(start, |level, step| {
+                    *level += step;
+                    Some(*level)
+                }))
+                .collect()
+        }),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn prop_dampeners_agree(report in report_strategy()) {
+        let expected = is_safe_with_dampener_naive(&report);
+        prop_assert_eq!(is_safe_with_dampener(&report), expected);
+        prop_assert_eq!(is_safe_with_dampener_skipping(&report), expected);
+        prop_assert_eq!(is_safe_with_k_dampener(&report, 1), expected);
+        prop_assert_eq!(safety_verdict(&report) != SafetyVerdict::Unsafe, expected);
+    }
+
+    #[test]
+    fn prop_safe_reports_have_no_violations(report in report_strategy()) {
+        prop_assert_eq!(count_violations(&report).total() == 0, is_safe(&report));
+    }
+
+    #[test]
+    fn prop_safe_implies_safe_with_dampener(report in report_strategy()) {
+        if is_safe(&report) {
+            prop_assert!(is_safe_with_dampener(&report));
+            prop_assert_eq!(safety_verdict(&report), SafetyVerdict::Safe);
+        }
+    }
+}
+
 // ===== SOLVE FUNCTION TESTS =====
 
 #[rstest]
 #[case(solve_part1, 2)] // Part 1 imperative with example input
 #[case(solve_part2, 4)] // Part 2 with example input
+#[case(solve_part2_naive, 4)] // Part 2 naive with example input
+#[case(solve_part2_skipping, 4)] // Part 2 skipping with example input
 fn test_solve_functions_example(
     #[case] solve_fn: fn(&str) -> anyhow::Result<usize>,
     #[case] expected: usize,
@@ -104,6 +421,7 @@ fn test_solve_functions_example(
 #[rstest]
 #[case(solve_part1, "1 2 3\n5 4 3 2\n1 1 1", 2)] // Part 1: First two safe, third has no changes
 #[case(solve_part2, "1 2 3\n1 5 2\n10 8 6 4\n1 1 1 1", 3)] // Part 2: Custom dampener test
+#[case(solve_part2_naive, "1 2 3\n1 5 2\n10 8 6 4\n1 1 1 1", 3)] // Part 2 naive: Custom dampener test
 fn test_solve_functions_edge_cases(
     #[case] solve_fn: fn(&str) -> anyhow::Result<usize>,
     #[case] input: &str,
@@ -113,9 +431,44 @@ fn test_solve_functions_edge_cases(
     assert_eq!(result, expected);
 }
 
+#[rstest]
+#[case(EXAMPLE_INPUT, (2, 2, 2))] // Example input
+#[case("", (0, 0, 0))] // Empty input
+#[case("1 2 3\n1 5 2\n10 8 6 4\n1 1 1 1", (2, 1, 1))] // One report of each kind plus a safe one
+fn test_classify_reports(#[case] input: &str, #[case] expected: (usize, usize, usize)) {
+    assert_eq!(classify_reports(input).unwrap(), expected);
+}
+
+#[test]
+fn test_classify_reports_real_input() {
+    let input = std::fs::read_to_string("input.txt")
+        .expect("Failed to read input.txt - make sure it exists");
+    let (safe, dampened, unsafe_reports) = classify_reports(&input).unwrap();
+    assert_eq!(safe, 686);
+    assert_eq!(safe + dampened, 717);
+    assert_eq!(safe + dampened + unsafe_reports, 1000);
+}
+
+#[rstest]
+#[case(EXAMPLE_INPUT, (2, 4))] // Example input
+#[case("", (0, 0))] // Empty input
+#[case("1 2 3\n1 5 2\n10 8 6 4\n1 1 1 1", (2, 3))] // Matches the solve edge cases
+fn test_solve_both(#[case] input: &str, #[case] expected: (usize, usize)) {
+    assert_eq!(solve_both(input).unwrap(), expected);
+}
+
+#[test]
+fn test_solve_both_real_input() {
+    let input = std::fs::read_to_string("input.txt")
+        .expect("Failed to read input.txt - make sure it exists");
+    assert_eq!(solve_both(&input).unwrap(), (686, 717));
+}
+
 #[rstest]
 #[case(solve_part1, 686)] // Part 1 imperative with real input
 #[case(solve_part2, 717)] // Part 2 with real input
+#[case(solve_part2_naive, 717)] // Part 2 naive with real input
+#[case(solve_part2_skipping, 717)] // Part 2 skipping with real input
 fn test_solve_functions_real_input(
     #[case] solve_fn: fn(&str) -> anyhow::Result<usize>,
     #[case] expected: usize,
@@ -125,3 +478,34 @@ fn test_solve_functions_real_input(
     let result = solve_fn(&input).unwrap();
     assert_eq!(result, expected);
 }
+
+// ===== PARALLEL SOLVER TESTS =====
+
+#[cfg(feature = "parallel")]
+#[rstest]
+#[case(day02::solve_part1_parallel, solve_part1)]
+#[case(day02::solve_part2_parallel, solve_part2)]
+fn test_parallel_solvers_match_sequential(
+    #[case] parallel: fn(&str) -> anyhow::Result<usize>,
+    #[case] sequential: fn(&str) -> anyhow::Result<usize>,
+) {
+    let input: String = (0..10_000)
+        .map(|i| {
+            format!(
+                "{} {} {} {}\n",
+                i % 97,
+                (i * 3) % 101,
+                (i * 7) % 103,
+                (i * 2) % 99
+            )
+        })
+        .collect();
+    for input in [EXAMPLE_INPUT, "", input.as_str()] {
+        assert_eq!(
+            parallel(input).unwrap(),
+            sequential(input).unwrap(),
+            "Mismatch for input of {} bytes",
+            input.len()
+        );
+    }
+}
@@ -0,0 +1,119 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use day03::{solve_part1, solve_part1_manual, solve_part2, solve_part2_manual};
+use shared::benchmarking::{
+    create_criterion_benchmark, process_benchmark_results, run_algorithm_benchmark, Algorithm,
+    AxisScale, PlotConfig, TestConfig,
+};
+
+#[cfg(feature = "alloc-tracking")]
+#[global_allocator]
+static ALLOCATOR: shared::alloc_tracking::TrackingAllocator =
+    shared::alloc_tracking::TrackingAllocator;
+
+const MEMORY_SIZES: [usize; 6] = [1_000, 5_000, 20_000, 100_000, 500_000, 1_000_000];
+
+/// Benchmark: Regex vs Manual Scanner
+/// Compares the regex extraction with the hand-rolled byte-level scanner for
+/// both parts as the corrupted memory grows
+fn benchmark_regex_vs_manual(c: &mut Criterion) {
+    let data_dir = "data";
+    let groups = [
+        (
+            "part1_regex_vs_manual",
+            1,
+            [
+                Algorithm::new("regex", solve_part1),
+                Algorithm::new("manual", solve_part1_manual),
+            ],
+        ),
+        (
+            "part2_regex_vs_manual",
+            2,
+            [
+                Algorithm::new("regex", solve_part2),
+                Algorithm::new("manual", solve_part2_manual),
+            ],
+        ),
+    ];
+
+    // The real puzzle input is benchmarked too when it is present
+    let real_input = std::fs::read_to_string("input.txt").ok();
+
+    for (group_name, part, algorithms) in groups {
+        let test_config = TestConfig {
+            sizes: &MEMORY_SIZES,
+            generate_input: generate_corrupted_memory,
+            verify_outputs: true,
+            settings: None,
+            real_input: real_input.as_deref(),
+            // Only the process's first group sees the solvers cold
+            cold_start: part == 1,
+        };
+
+        run_algorithm_benchmark(c, data_dir, group_name, &algorithms, &test_config)
+            .expect("Benchmarked algorithms must agree on every input");
+
+        let filename = format!("{group_name}.svg");
+        let speedup_filename = format!("{group_name}_speedup.svg");
+        let title = format!("Day 3: Regex vs Manual Scanner Part {part} Performance");
+        let plot_config = PlotConfig {
+            filename: &filename,
+            title: &title,
+            algorithm_names: &["Regex", "Byte-Level Scanner"],
+            x_axis_label: "Memory Size (bytes)",
+            x_scale: AxisScale::Log,
+            y_scale: AxisScale::Log,
+            speedup_filename: Some(&speedup_filename),
+            fit_overlay: true,
+            part: Some(part),
+            ..PlotConfig::default()
+        };
+
+        process_benchmark_results(
+            data_dir,
+            group_name,
+            &algorithms,
+            &plot_config,
+            &test_config,
+        );
+    }
+}
+
+/// Test data: Corrupted memory of about `size` bytes
+///
+/// Mixes valid instructions with near misses, conditionals and junk in
+/// roughly the proportions of the real input.
+fn generate_corrupted_memory(size: usize) -> String {
+    const JUNK: [&str; 8] = [
+        "what()",
+        "#",
+        "select()",
+        "mul[3,7]",
+        "mul(4*",
+        "+",
+        "from()",
+        "mul ( 2 , 4 )",
+    ];
+
+    let mut memory = String::with_capacity(size + 32);
+    let mut i = 0;
+    while memory.len() < size {
+        let piece = match i % 7 {
+            0 | 3 => format!("mul({},{})", (i * 37) % 100, (i * 91) % 1000),
+            1 | 4 | 6 => JUNK[i % JUNK.len()].to_string(),
+            2 => format!("mul({},{}", i % 1000, (i * 13) % 100),
+            _ if i % 2 == 0 => "do()".to_string(),
+            _ => "don't()".to_string(),
+        };
+        memory.push_str(&piece);
+        i += 1;
+    }
+    memory
+}
+
+criterion_group!(
+    name = benches;
+    config = create_criterion_benchmark("data");
+    targets = benchmark_regex_vs_manual
+);
+criterion_main!(benches);
@@ -10,8 +10,10 @@
 //! and disable mul() instructions. Only mul() instructions after do()
 //! (or at the start) are processed, while those after don't() are ignored.
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use regex::Regex;
+use shared::iter::AocIterExt;
+use shared::runner::{Solution, Variant};
 use std::sync::LazyLock;
 
 /// Example input from the problem statement used for testing and
@@ -23,6 +25,30 @@ pub const EXAMPLE_INPUT: &str =
 pub const EXAMPLE_INPUT_PART2: &str =
     "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
 
+/// Registry entry used by the workspace runner.
+pub const SOLUTION: Solution = Solution {
+    day: 3,
+    title: "Mull It Over",
+    example_input: EXAMPLE_INPUT,
+    example_input_part2: Some(EXAMPLE_INPUT_PART2),
+    part1: |input| Ok(solve_part1(input)?.to_string()),
+    part2: Some(|input| Ok(solve_part2(input)?.to_string())),
+    variants: &[
+        Variant {
+            name: "manual",
+            part: 1,
+            solver: |input| Ok(solve_part1_manual(input)?.to_string()),
+        },
+        Variant {
+            name: "manual",
+            part: 2,
+            solver: |input| Ok(solve_part2_manual(input)?.to_string()),
+        },
+    ],
+    validate: None,
+    explain: None,
+};
+
 /// Solves Part 1: Sums the results of all valid multiplication instructions.
 ///
 /// Scans corrupted memory for valid mul(X,Y) instructions, multiplies the
@@ -36,7 +62,8 @@ pub const EXAMPLE_INPUT_PART2: &str =
 ///
 /// # Errors
 ///
-/// Returns an error if instruction parsing fails (malformed numbers).
+/// Returns an error if instruction parsing fails (malformed numbers) or the
+/// sum overflows `u32`.
 ///
 /// # Examples
 ///
@@ -46,14 +73,109 @@ pub const EXAMPLE_INPUT_PART2: &str =
 /// assert_eq!(solve_part1(memory).unwrap(), 161); // 2*4 + 5*5 + 11*8 + 8*5 = 161
 /// ```
 pub fn solve_part1(input: &str) -> Result<u32> {
-    extract_mul_instructions(input)
-        .map(|instructions| instructions.iter().map(|(x, y)| x * y).sum())
+    sum_products(mul_operands(input))
+}
+
+/// A single instruction found in corrupted memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    /// `mul(X,Y)` with its two operands
+    Mul(u32, u32),
+    /// `do()`, enabling later mul instructions
+    Do,
+    /// `don't()`, disabling later mul instructions
+    Dont,
+}
+
+/// Lazily finds every mul(X,Y), do() and don't() instruction in corrupted
+/// memory.
+///
+/// Yields instructions in order as the regex finds them instead of
+/// collecting them. Match positions are read into one set of capture
+/// locations allocated up front, so callers that only need a sum or the
+/// first few instructions allocate nothing per match, and the iterator
+/// composes with adapters such as `take_while` and `filter`.
+///
+/// # Parameters
+/// * `input` - String containing corrupted memory to scan
+///
+/// # Returns
+/// An iterator over the instructions, each an `Err` if its operands cannot
+/// be parsed as `u32`
+///
+/// # Examples
+///
+/// ```
+/// # use day03::{instructions, Instruction, EXAMPLE_INPUT_PART2};
+/// let until_disabled: Vec<Instruction> = instructions(EXAMPLE_INPUT_PART2)
+///     .map(Result::unwrap)
+///     .take_while(|instruction| *instruction != Instruction::Dont)
+///     .collect();
+/// assert_eq!(until_disabled, vec![Instruction::Mul(2, 4)]);
+/// ```
+pub fn instructions(input: &str) -> impl Iterator<Item = Result<Instruction>> + '_ {
+    static RE: LazyLock<Regex> = LazyLock::new(|| {
+        Regex::new(r"(?:mul\(([0-9]{1,3}),([0-9]{1,3})\)|do\(\)|don't\(\))")
+            .expect("Invalid regex pattern for instructions")
+    });
+
+    let mut locations = RE.capture_locations();
+    let mut position = 0;
+    std::iter::from_fn(move || {
+        let found = RE.captures_read_at(&mut locations, input, position)?;
+        position = found.end();
+        let operand = |group| -> Result<u32> {
+            let (start, end) = locations.get(group).context("mul( without operands")?;
+            Ok(input[start..end].parse()?)
+        };
+        Some(match found.as_str() {
+            "do()" => Ok(Instruction::Do),
+            "don't()" => Ok(Instruction::Dont),
+            _ => operand(1).and_then(|x| Ok(Instruction::Mul(x, operand(2)?))),
+        })
+    })
+}
+
+/// Operands of every mul instruction, ignoring do() and don't().
+fn mul_operands(input: &str) -> impl Iterator<Item = Result<(u32, u32)>> + '_ {
+    instructions(input).filter_map(|instruction| match instruction {
+        Ok(Instruction::Mul(x, y)) => Some(Ok((x, y))),
+        Ok(Instruction::Do | Instruction::Dont) => None,
+        Err(error) => Some(Err(error)),
+    })
+}
+
+/// Operands of the mul instructions enabled by the latest do() or don't().
+fn enabled_mul_operands(input: &str) -> impl Iterator<Item = Result<(u32, u32)>> + '_ {
+    let mut enabled = true;
+    instructions(input).filter_map(move |instruction| match instruction {
+        Ok(Instruction::Mul(x, y)) => enabled.then_some(Ok((x, y))),
+        Ok(Instruction::Do) => {
+            enabled = true;
+            None
+        }
+        Ok(Instruction::Dont) => {
+            enabled = false;
+            None
+        }
+        Err(error) => Some(Err(error)),
+    })
+}
+
+/// Sums the products of operand pairs, failing on the first error or if
+/// the sum overflows `u32`.
+fn sum_products(mut operands: impl Iterator<Item = Result<(u32, u32)>>) -> Result<u32> {
+    operands.try_fold(0u32, |sum, pair| {
+        let (x, y) = pair?;
+        sum.checked_add(x * y)
+            .context("Sum of multiplication results overflows u32")
+    })
 }
 
 /// Extracts all valid mul(X,Y) instructions from corrupted memory.
 ///
-/// Uses regex pattern `mul\((\d{1,3}),(\d{1,3})\)` to find instructions with
-/// the exact format mul(X,Y) where X and Y are 1-3 digit numbers. Invalid
+/// Collects the mul instructions found by [`instructions`], which have the
+/// exact format mul(X,Y) where X and Y are 1-3 digit numbers. Invalid
 /// formats like mul(4*, mul[3,7], or mul ( 2 , 4 ) are ignored.
 ///
 /// # Parameters
@@ -76,18 +198,7 @@ pub fn solve_part1(input: &str) -> Result<u32> {
 /// assert_eq!(instructions, vec![(2, 4), (5, 5), (11, 8), (8, 5)]);
 /// ```
 pub fn extract_mul_instructions(input: &str) -> Result<Vec<(u32, u32)>> {
-    static RE: LazyLock<Regex> = LazyLock::new(|| {
-        Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)")
-            .expect("Invalid regex pattern for mul instructions")
-    });
-
-    RE.captures_iter(input)
-        .map(|captures| {
-            let x = captures[1].parse()?;
-            let y = captures[2].parse()?;
-            Ok((x, y))
-        })
-        .collect()
+    mul_operands(input).collect()
 }
 
 /// Solves Part 2: Sums the results of enabled multiplication instructions.
@@ -104,7 +215,8 @@ pub fn extract_mul_instructions(input: &str) -> Result<Vec<(u32, u32)>> {
 ///
 /// # Errors
 ///
-/// Returns `Err` if instruction parsing fails (malformed numbers).
+/// Returns `Err` if instruction parsing fails (malformed numbers) or the sum
+/// overflows `u32`.
 ///
 /// # Examples
 ///
@@ -114,9 +226,9 @@ pub fn extract_mul_instructions(input: &str) -> Result<Vec<(u32, u32)>> {
 /// assert_eq!(solve_part2(memory).unwrap(), 48); // 2*4 + 8*5 = 48
 /// ```
 pub fn solve_part2(input: &str) -> Result<u32> {
-    extract_enabled_mul_instructions(input)
-        .map(|instructions| instructions.iter().map(|(x, y)| x * y).sum())
+    sum_products(enabled_mul_operands(input))
 }
+
 /// Extracts enabled mul(X,Y) instructions from corrupted memory.
 ///
 /// Processes do() and don't() instructions to determine which mul instructions
@@ -145,32 +257,194 @@ pub fn solve_part2(input: &str) -> Result<u32> {
 /// assert_eq!(instructions, vec![(2, 4), (8, 5)]);
 /// ```
 pub fn extract_enabled_mul_instructions(input: &str) -> Result<Vec<(u32, u32)>> {
-    static RE: LazyLock<Regex> = LazyLock::new(|| {
-        Regex::new(r"(?:mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\))")
-            .expect("Invalid regex pattern for conditional mul instructions")
-    });
+    enabled_mul_operands(input).collect()
+}
+
+/// Hand-rolled implementation of Part 1 for performance comparison.
+///
+/// Same as [`solve_part1`], but finds the instructions with
+/// [`extract_mul_instructions_manual`] instead of a regex.
+///
+/// # Parameters
+/// * `input` - String containing corrupted memory to parse
+///
+/// # Returns
+/// Sum of all multiplication results
+///
+/// # Errors
+///
+/// Returns an error if the sum overflows `u32`.
+///
+/// # Examples
+///
+/// ```
+/// # use day03::{solve_part1_manual, EXAMPLE_INPUT};
+/// assert_eq!(solve_part1_manual(EXAMPLE_INPUT).unwrap(), 161); // Same result as
+///                                                              // solve_part1
+/// ```
+pub fn solve_part1_manual(input: &str) -> Result<u32> {
+    extract_mul_instructions_manual(input)
+        .iter()
+        .map(|(x, y)| x * y)
+        .sum_checked()
+        .context("Sum of multiplication results overflows u32")
+}
 
+/// Extracts all valid mul(X,Y) instructions with a byte-level scanner.
+///
+/// Finds the same instructions as [`extract_mul_instructions`] without a
+/// regex: at each `mul(` it reads 1-3 digits, a comma, 1-3 digits and a
+/// closing parenthesis, and resumes one byte later if any of them is
+/// missing. Numbers of at most 3 digits always fit in a `u32`, so the scan
+/// cannot fail.
+///
+/// # Parameters
+/// * `input` - String containing corrupted memory with mixed valid/invalid
+///   instructions
+///
+/// # Returns
+/// Vector of (X, Y) tuples representing the operands of valid mul instructions
+///
+/// # Examples
+///
+/// ```
+/// # use day03::{extract_mul_instructions_manual, EXAMPLE_INPUT};
+/// let instructions = extract_mul_instructions_manual(EXAMPLE_INPUT);
+/// assert_eq!(instructions, vec![(2, 4), (5, 5), (11, 8), (8, 5)]);
+/// ```
+pub fn extract_mul_instructions_manual(input: &str) -> Vec<(u32, u32)> {
+    let bytes = input.as_bytes();
+    let mut instructions = Vec::new();
+    let mut position = 0;
+
+    while position < bytes.len() {
+        match scan_mul(bytes, position) {
+            Some((operands, end)) => {
+                instructions.push(operands);
+                position = end;
+            }
+            None => position += 1,
+        }
+    }
+
+    instructions
+}
+
+/// Hand-rolled implementation of Part 2 for performance comparison.
+///
+/// Same as [`solve_part2`], but finds the instructions with
+/// [`extract_enabled_mul_instructions_manual`] instead of a regex.
+///
+/// # Parameters
+/// * `input` - String containing corrupted memory to parse
+///
+/// # Returns
+/// Sum of all enabled multiplication results
+///
+/// # Errors
+///
+/// Returns an error if the sum overflows `u32`.
+///
+/// # Examples
+///
+/// ```
+/// # use day03::{solve_part2_manual, EXAMPLE_INPUT_PART2};
+/// // Same result as solve_part2
+/// assert_eq!(solve_part2_manual(EXAMPLE_INPUT_PART2).unwrap(), 48);
+/// ```
+pub fn solve_part2_manual(input: &str) -> Result<u32> {
+    extract_enabled_mul_instructions_manual(input)
+        .iter()
+        .map(|(x, y)| x * y)
+        .sum_checked()
+        .context("Sum of multiplication results overflows u32")
+}
+
+/// Extracts enabled mul(X,Y) instructions with a byte-level scanner.
+///
+/// Finds the same instructions as [`extract_enabled_mul_instructions`]
+/// without a regex, toggling the enabled state at every `do()` and
+/// `don't()`.
+///
+/// # Parameters
+/// * `input` - String containing corrupted memory with mul, do(), and
+///   don't() instructions
+///
+/// # Returns
+/// Vector of (X, Y) tuples representing the operands of enabled mul
+/// instructions
+///
+/// # Examples
+///
+/// ```
+/// # use day03::{extract_enabled_mul_instructions_manual, EXAMPLE_INPUT_PART2};
+/// let instructions = extract_enabled_mul_instructions_manual(EXAMPLE_INPUT_PART2);
+/// assert_eq!(instructions, vec![(2, 4), (8, 5)]);
+/// ```
+pub fn extract_enabled_mul_instructions_manual(input: &str) -> Vec<(u32, u32)> {
+    let bytes = input.as_bytes();
     let mut enabled = true;
     let mut instructions = Vec::new();
+    let mut position = 0;
 
-    for captures in RE.captures_iter(input) {
-        // captures[0] contains the entire match: "do()", "don't()", or "mul(X,Y)"
-        // captures[1] and captures[2] contain the X and Y values for mul instructions
-        let instruction = &captures[0];
-
-        match instruction {
-            "do()" => enabled = true,
-            "don't()" => enabled = false,
-            _ => {
-                // This is a mul instruction
-                if enabled {
-                    let x = captures[1].parse()?;
-                    let y = captures[2].parse()?;
-                    instructions.push((x, y));
-                }
+    while position < bytes.len() {
+        let rest = &bytes[position..];
+        if rest.starts_with(b"do()") {
+            enabled = true;
+            position += b"do()".len();
+        } else if rest.starts_with(b"don't()") {
+            enabled = false;
+            position += b"don't()".len();
+        } else if let Some((operands, end)) = scan_mul(bytes, position) {
+            if enabled {
+                instructions.push(operands);
             }
+            position = end;
+        } else {
+            position += 1;
         }
     }
 
-    Ok(instructions)
+    instructions
+}
+
+/// Scans a `mul(X,Y)` instruction starting at `start`.
+///
+/// # Returns
+/// The operands and the position just past the closing parenthesis, or
+/// `None` if no valid instruction starts at `start`
+fn scan_mul(bytes: &[u8], start: usize) -> Option<((u32, u32), usize)> {
+    if !bytes[start..].starts_with(b"mul(") {
+        return None;
+    }
+    let (x, position) = scan_number(bytes, start + b"mul(".len())?;
+    if bytes.get(position) != Some(&b',') {
+        return None;
+    }
+    let (y, position) = scan_number(bytes, position + 1)?;
+    if bytes.get(position) != Some(&b')') {
+        return None;
+    }
+    Some(((x, y), position + 1))
+}
+
+/// Scans a number of 1-3 digits starting at `start`.
+///
+/// # Returns
+/// The number and the position just past its last digit, or `None` if no
+/// digit starts at `start`. A fourth digit is left unscanned, so the
+/// caller's next check fails on it.
+fn scan_number(bytes: &[u8], start: usize) -> Option<(u32, usize)> {
+    let digits = bytes[start.min(bytes.len())..]
+        .iter()
+        .take(3)
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if digits == 0 {
+        return None;
+    }
+    let number = bytes[start..start + digits]
+        .iter()
+        .fold(0, |number, &digit| number * 10 + u32::from(digit - b'0'));
+    Some((number, start + digits))
 }
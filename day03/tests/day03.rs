@@ -1,6 +1,8 @@
 use day03::{
-    extract_enabled_mul_instructions, extract_mul_instructions, solve_part1, solve_part2,
-    EXAMPLE_INPUT, EXAMPLE_INPUT_PART2,
+    extract_enabled_mul_instructions, extract_enabled_mul_instructions_manual,
+    extract_mul_instructions, extract_mul_instructions_manual, instructions, solve_part1,
+    solve_part1_manual, solve_part2, solve_part2_manual, Instruction, EXAMPLE_INPUT,
+    EXAMPLE_INPUT_PART2,
 };
 use rstest::rstest;
 
@@ -82,11 +84,83 @@ fn test_extract_enabled_mul_instructions_error_cases(
     assert_eq!(result.unwrap(), expected);
 }
 
+#[rstest]
+#[case(EXAMPLE_INPUT)] // Example input
+#[case(EXAMPLE_INPUT_PART2)] // Part 2 example input
+#[case("mul(1,2) mul(12,34) mul(123,456) mul(1234,5) mul(1,2345)")] // 1-3 digit boundary
+#[case("mulmul(2,3)mul(mul(4,5)mul(6,7")] // Restart inside a failed instruction
+#[case("mul(,3)mul(3,)mul(-1,2)mul(2,3 )mul(2,3")] // Missing or malformed operands
+#[case("don'tdo()don't(do()mul(1,2)don't()do(mul(3,4)")] // Near-miss conditionals
+#[case("do()don't()do()mul(1,1)don't()mul(2,2)")] // Adjacent conditionals
+#[case("mul(2,3)é€mul(4,5)")] // Multi-byte characters
+#[case("mul(١,2)mul(3,٤)mul(5,6)")] // Non-ASCII digits
+#[case("")] // Empty input
+fn test_manual_extraction_matches_regex(#[case] input: &str) {
+    assert_eq!(
+        extract_mul_instructions_manual(input),
+        extract_mul_instructions(input).unwrap()
+    );
+    assert_eq!(
+        extract_enabled_mul_instructions_manual(input),
+        extract_enabled_mul_instructions(input).unwrap()
+    );
+}
+
+#[test]
+fn test_manual_extraction_matches_regex_real_input() {
+    let input = std::fs::read_to_string("input.txt")
+        .expect("Failed to read input.txt - make sure it exists");
+    assert_eq!(
+        extract_mul_instructions_manual(&input),
+        extract_mul_instructions(&input).unwrap()
+    );
+    assert_eq!(
+        extract_enabled_mul_instructions_manual(&input),
+        extract_enabled_mul_instructions(&input).unwrap()
+    );
+}
+
+// ===== INSTRUCTION ITERATOR TESTS =====
+
+#[rstest]
+#[case(EXAMPLE_INPUT_PART2, vec![Instruction::Mul(2, 4), Instruction::Dont, Instruction::Mul(5, 5), Instruction::Mul(11, 8), Instruction::Do, Instruction::Mul(8, 5)])] // Part 2 example
+#[case("do()don't()mul(1,2)", vec![Instruction::Do, Instruction::Dont, Instruction::Mul(1, 2)])] // Conditionals in order
+#[case("mul(1234,5)don'tdo(mul[3,7]", vec![])] // Only near misses
+#[case("", vec![])] // Empty input
+fn test_instructions(#[case] input: &str, #[case] expected: Vec<Instruction>) {
+    let found: Vec<Instruction> = instructions(input).map(Result::unwrap).collect();
+    assert_eq!(found, expected);
+}
+
+#[test]
+fn test_instructions_match_extraction() {
+    let input = std::fs::read_to_string("input.txt")
+        .expect("Failed to read input.txt - make sure it exists");
+    let muls: Vec<(u32, u32)> = instructions(&input)
+        .filter_map(|instruction| match instruction.unwrap() {
+            Instruction::Mul(x, y) => Some((x, y)),
+            Instruction::Do | Instruction::Dont => None,
+        })
+        .collect();
+    assert_eq!(muls, extract_mul_instructions(&input).unwrap());
+}
+
+#[test]
+fn test_solve_functions_overflow() {
+    let input = "mul(999,999)".repeat(5000);
+    for solve_fn in [solve_part1, solve_part2] {
+        let error = solve_fn(&input).unwrap_err();
+        assert!(error.to_string().contains("overflows u32"));
+    }
+}
+
 // ===== SOLVE FUNCTION TESTS =====
 
 #[rstest]
 #[case(solve_part1, EXAMPLE_INPUT, 161)] // Part 1 with example input
 #[case(solve_part2, EXAMPLE_INPUT_PART2, 48)] // Part 2 with example input
+#[case(solve_part1_manual, EXAMPLE_INPUT, 161)] // Part 1 manual scanner with example input
+#[case(solve_part2_manual, EXAMPLE_INPUT_PART2, 48)] // Part 2 manual scanner with example input
 fn test_solve_functions_example(
     #[case] solve_fn: fn(&str) -> anyhow::Result<u32>,
     #[case] input: &str,
@@ -103,7 +177,8 @@ fn test_solve_functions_example(
 #[case("no valid instructions", 0)] // No valid instructions
 #[case("mul(4* mul[3,7] mul ( 2 , 4 )", 0)] // Invalid format instructions
 fn test_solve_functions_edge_cases(
-    #[values(solve_part1, solve_part2)] solve_fn: fn(&str) -> anyhow::Result<u32>,
+    #[values(solve_part1, solve_part2, solve_part1_manual, solve_part2_manual)]
+    solve_fn: fn(&str) -> anyhow::Result<u32>,
     #[case] input: &str,
     #[case] expected: u32,
 ) {
@@ -115,14 +190,20 @@ fn test_solve_functions_edge_cases(
 #[case("don't()mul(2,3)", 0)] // Simple disabled case
 #[case("don't()mul(2,3)do()mul(4,5)", 20)] // Re-enabled case
 #[case("mul(1,1)don't()mul(2,2)do()mul(3,3)don't()mul(4,4)do()mul(5,5)", 35)] // Complex state changes: 1*1 + 3*3 + 5*5 = 35
-fn test_solve_part2_control_flow(#[case] input: &str, #[case] expected: u32) {
-    let result = solve_part2(input).unwrap();
+fn test_solve_part2_control_flow(
+    #[values(solve_part2, solve_part2_manual)] solve_fn: fn(&str) -> anyhow::Result<u32>,
+    #[case] input: &str,
+    #[case] expected: u32,
+) {
+    let result = solve_fn(input).unwrap();
     assert_eq!(result, expected, "Part 2 failed for input: {input:?}");
 }
 
 #[rstest]
 #[case(solve_part1, 167650499)] // Part 1 with real input
 #[case(solve_part2, 95846796)] // Part 2 with real input
+#[case(solve_part1_manual, 167650499)] // Part 1 manual scanner with real input
+#[case(solve_part2_manual, 95846796)] // Part 2 manual scanner with real input
 fn test_solve_functions_real_input(
     #[case] solve_fn: fn(&str) -> anyhow::Result<u32>,
     #[case] expected: u32,
@@ -10,6 +10,12 @@
 //! An X-MAS pattern consists of two "MAS" words that intersect at their center 'A'
 //! to form an X shape. Each "MAS" can be written forwards or backwards ("SAM").
 
+use anyhow::Result;
+use shared::grid::{parse_char_grid, Grid};
+use shared::point::{Direction, Point, ALL_DIRECTIONS};
+use shared::runner::{Solution, Variant};
+use shared::validate::check_rectangular;
+
 /// Example input from the problem statement used for testing and
 /// documentation.
 pub const EXAMPLE_INPUT: &str = "MMMSXXMASM
@@ -23,6 +29,30 @@ SAXAMASAAA
 MAMMMXMMMM
 MXMXAXMASX";
 
+/// Registry entry used by the workspace runner.
+pub const SOLUTION: Solution = Solution {
+    day: 4,
+    title: "Ceres Search",
+    example_input: EXAMPLE_INPUT,
+    example_input_part2: None,
+    part1: |input| Ok(solve_part1(input)?.to_string()),
+    part2: Some(|input| Ok(solve_part2(input)?.to_string())),
+    variants: &[
+        Variant {
+            name: "lines",
+            part: 1,
+            solver: |input| Ok(solve_part1_lines(input)?.to_string()),
+        },
+        Variant {
+            name: "stencil",
+            part: 2,
+            solver: |input| Ok(solve_part2_stencil(input)?.to_string()),
+        },
+    ],
+    validate: Some(check_rectangular),
+    explain: None,
+};
+
 /// Solves Part 1: Finds all occurrences of "XMAS" in the word search grid.
 ///
 /// Searches through every position in the grid and counts how many times
@@ -35,23 +65,65 @@ MXMXAXMASX";
 /// # Returns
 /// Total number of "XMAS" occurrences found in the grid
 ///
+/// # Errors
+///
+/// Returns an error if the grid rows have different lengths.
+///
 /// # Examples
 ///
 /// ```
 /// # use day04::solve_part1;
 /// let input = "XMAS\nMASX";
-/// assert_eq!(solve_part1(input), 1); // "XMAS" going right from (0,0)
+/// assert_eq!(solve_part1(input).unwrap(), 1); // "XMAS" going right from (0,0)
 /// ```
-pub fn solve_part1(input: &str) -> usize {
-    let grid = parse_input(input);
+pub fn solve_part1(input: &str) -> Result<usize> {
+    let grid = parse_input(input)?;
 
-    (0..grid.len())
-        .map(|row| {
-            (0..grid[row].len())
-                .map(|col| count_xmas_at_position(&grid, row, col))
-                .sum::<usize>()
-        })
-        .sum()
+    Ok(grid
+        .positions()
+        .map(|(row, col)| count_xmas_at_position(&grid, row, col))
+        .sum())
+}
+
+/// Solves Part 1 by scanning every line of every orientation.
+///
+/// Alternative to [`solve_part1`]: the rows, columns, diagonals and
+/// anti-diagonals are each read as a string and searched for "XMAS" and its
+/// reverse "SAMX", which covers all 8 directions.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing the character grid
+///
+/// # Returns
+/// Total number of "XMAS" occurrences found in the grid
+///
+/// # Errors
+///
+/// Returns an error if the grid rows have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// # use day04::solve_part1_lines;
+/// assert_eq!(solve_part1_lines("XMASAMX").unwrap(), 2); // forwards and backwards
+/// ```
+pub fn solve_part1_lines(input: &str) -> Result<usize> {
+    let grid = parse_input(input)?;
+    let transposed = grid.transpose();
+
+    let straight = grid
+        .rows()
+        .chain(transposed.rows())
+        .map(|line| line.iter().collect::<String>());
+    let diagonal = grid
+        .diagonals()
+        .chain(grid.anti_diagonals())
+        .map(|line| line.into_iter().collect::<String>());
+
+    Ok(straight
+        .chain(diagonal)
+        .map(|line| line.matches("XMAS").count() + line.matches("SAMX").count())
+        .sum())
 }
 
 /// Counts the number of times "XMAS" appears starting from a specific
@@ -72,24 +144,15 @@ pub fn solve_part1(input: &str) -> usize {
 ///
 /// ```
 /// # use day04::{parse_input, count_xmas_at_position};
-/// let grid = parse_input("XMAS\nMASX");
+/// let grid = parse_input("XMAS\nMASX").unwrap();
 /// assert_eq!(count_xmas_at_position(&grid, 0, 0), 1); // "XMAS" right
 /// ```
-pub fn count_xmas_at_position(grid: &[Vec<char>], row: usize, col: usize) -> usize {
-    const DIRECTIONS: [(isize, isize); 8] = [
-        (0, 1),   // right
-        (0, -1),  // left
-        (1, 0),   // down
-        (-1, 0),  // up
-        (1, 1),   // down-right
-        (-1, -1), // up-left
-        (1, -1),  // down-left
-        (-1, 1),  // up-right
-    ];
+pub fn count_xmas_at_position(grid: &Grid<char>, row: usize, col: usize) -> usize {
+    let start = Point(row as i64, col as i64);
 
-    DIRECTIONS
+    ALL_DIRECTIONS
         .iter()
-        .filter(|&&(row_delta, col_delta)| check_direction(grid, row, col, row_delta, col_delta))
+        .filter(|&&direction| check_direction(grid, start, direction))
         .count()
 }
 
@@ -101,10 +164,8 @@ pub fn count_xmas_at_position(grid: &[Vec<char>], row: usize, col: usize) -> usi
 ///
 /// # Parameters
 /// * `grid` - The 2D character grid to search in
-/// * `start_row` - Starting row position (0-indexed)
-/// * `start_col` - Starting column position (0-indexed)
-/// * `row_delta` - Row direction (-1, 0, or 1)
-/// * `col_delta` - Column direction (-1, 0, or 1)
+/// * `start` - Starting position
+/// * `direction` - Direction in which the word is read
 ///
 /// # Returns
 /// `true` if "XMAS" is found in the specified direction, `false` otherwise
@@ -113,25 +174,20 @@ pub fn count_xmas_at_position(grid: &[Vec<char>], row: usize, col: usize) -> usi
 ///
 /// ```
 /// # use day04::{parse_input, check_direction};
-/// let grid = parse_input("XMAS\nABCD");
-/// assert!(check_direction(&grid, 0, 0, 0, 1)); // "XMAS" going right
-/// assert!(!check_direction(&grid, 0, 0, 1, 0)); // "XABC" going down
+/// # use shared::point::{Direction, Point};
+/// let grid = parse_input("XMAS\nABCD").unwrap();
+/// assert!(check_direction(&grid, Point(0, 0), Direction::East)); // "XMAS" going right
+/// assert!(!check_direction(&grid, Point(0, 0), Direction::South)); // "XABC" going down
 /// ```
-pub fn check_direction(
-    grid: &[Vec<char>],
-    start_row: usize,
-    start_col: usize,
-    row_delta: isize,
-    col_delta: isize,
-) -> bool {
+pub fn check_direction(grid: &Grid<char>, start: Point, direction: Direction) -> bool {
     const XMAS_CHARS: &[char] = &['X', 'M', 'A', 'S'];
 
-    XMAS_CHARS.iter().enumerate().all(|(i, &target_char)| {
-        let target_row = start_row as isize + (i as isize * row_delta);
-        let target_col = start_col as isize + (i as isize * col_delta);
-        char_matches_at(grid, target_row, target_col, target_char)
-    })
+    XMAS_CHARS
+        .iter()
+        .enumerate()
+        .all(|(i, &target_char)| char_matches_at(grid, start + direction * i as i64, target_char))
 }
+
 /// Solves Part 2: Finds all X-MAS patterns in the given grid.
 ///
 /// Searches for patterns where two "MAS" words intersect at their center
@@ -143,22 +199,59 @@ pub fn check_direction(
 /// # Returns
 /// Total number of X-MAS patterns found in the grid
 ///
+/// # Errors
+///
+/// Returns an error if the grid rows have different lengths.
+///
 /// # Examples
 /// ```
 /// # use day04::solve_part2;
 /// let input = "M.S\n.A.\nM.S";
-/// assert_eq!(solve_part2(input), 1);
+/// assert_eq!(solve_part2(input).unwrap(), 1);
+/// ```
+pub fn solve_part2(input: &str) -> Result<usize> {
+    let grid = parse_input(input)?;
+
+    Ok(grid
+        .positions()
+        .filter(|&(row, col)| is_xmas_pattern(&grid, row, col))
+        .count())
+}
+
+/// Solves Part 2 by matching a 3x3 stencil against every window of the grid.
+///
+/// Alternative to [`solve_part2`] built on
+/// [`Grid::windows`](shared::grid::Grid::windows): each 3x3 window must have
+/// an 'A' in the center and "MAS" or "SAM" along both diagonals.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing the character grid
+///
+/// # Returns
+/// Total number of X-MAS patterns found in the grid
+///
+/// # Errors
+///
+/// Returns an error if the grid rows have different lengths.
+///
+/// # Examples
 /// ```
-pub fn solve_part2(input: &str) -> usize {
-    let grid = parse_input(input);
+/// # use day04::solve_part2_stencil;
+/// assert_eq!(solve_part2_stencil("M.S\n.A.\nM.S").unwrap(), 1);
+/// ```
+pub fn solve_part2_stencil(input: &str) -> Result<usize> {
+    let grid = parse_input(input)?;
+    let is_mas = |ends: (char, char)| matches!(ends, ('M', 'S') | ('S', 'M'));
 
-    (0..grid.len())
-        .map(|row| {
-            (0..grid[row].len())
-                .filter(|&col| is_xmas_pattern(&grid, row, col))
-                .count()
+    Ok(grid
+        .windows::<3>()
+        .filter(|(window, _)| match window {
+            [[&top_left, _, &top_right], [_, &'A', _], [&bottom_left, _, &bottom_right]] => {
+                is_mas((top_left, bottom_right)) && is_mas((top_right, bottom_left))
+            }
+            _ => false,
         })
-        .sum()
+        .count())
 }
 
 /// Checks if a 3x3 region centered at the given position contains an X-MAS
@@ -179,30 +272,26 @@ pub fn solve_part2(input: &str) -> usize {
 /// # Examples
 /// ```
 /// # use day04::{parse_input, is_xmas_pattern};
-/// let grid = parse_input("M.S\n.A.\nM.S");
+/// let grid = parse_input("M.S\n.A.\nM.S").unwrap();
 /// assert!(is_xmas_pattern(&grid, 1, 1)); // X-MAS pattern at center
 /// ```
-pub fn is_xmas_pattern(grid: &[Vec<char>], center_row: usize, center_col: usize) -> bool {
+pub fn is_xmas_pattern(grid: &Grid<char>, center_row: usize, center_col: usize) -> bool {
     const MAS_PATTERN: [char; 3] = ['M', 'A', 'S'];
     const SAM_PATTERN: [char; 3] = ['S', 'A', 'M'];
 
-    let center_row_signed = center_row as isize;
-    let center_col_signed = center_col as isize;
+    let center = Point(center_row as i64, center_col as i64);
 
-    // Both diagonal directions: top-left to bottom-right (1,1) and
-    // top-right to bottom-left (1,-1)
-    let diagonal_directions = [(1, 1), (1, -1)];
+    // Both diagonals: top-left to bottom-right and top-right to bottom-left
+    let diagonal_directions = [Direction::SouthEast, Direction::SouthWest];
     let patterns = [&MAS_PATTERN, &SAM_PATTERN];
 
-    diagonal_directions.iter().all(|&(row_delta, col_delta)| {
+    diagonal_directions.iter().all(|&direction| {
         // Check if this diagonal contains either "MAS" or "SAM"
         patterns.iter().any(|&pattern| {
             // Check if pattern matches in this diagonal direction
             pattern.iter().enumerate().all(|(i, &expected_char)| {
-                let relative_pos = i as isize - 1; // -1, 0, 1 for positions relative to center
-                let row = center_row_signed + (relative_pos * row_delta);
-                let col = center_col_signed + (relative_pos * col_delta);
-                char_matches_at(grid, row, col, expected_char)
+                let relative_pos = i as i64 - 1; // -1, 0, 1 for positions relative to center
+                char_matches_at(grid, center + direction * relative_pos, expected_char)
             })
         })
     })
@@ -216,57 +305,40 @@ pub fn is_xmas_pattern(grid: &[Vec<char>], center_row: usize, center_col: usize)
 ///
 /// # Parameters
 /// * `grid` - The 2D character grid to access
-/// * `row` - Row position (can be negative, will return false if out of
-///   bounds)
-/// * `col` - Column position (can be negative, will return false if out of
-///   bounds)
+/// * `point` - Position to check (may lie outside the grid)
 /// * `expected` - The character to check for at this position
 ///
 /// # Returns
 /// `true` if the position is valid and contains the expected character,
 /// `false` otherwise
-fn char_matches_at(grid: &[Vec<char>], row: isize, col: isize, expected: char) -> bool {
-    if row < 0 || col < 0 {
-        return false;
-    }
-
-    let row = row as usize;
-    let col = col as usize;
-
-    if row >= grid.len() || col >= grid[row].len() {
-        return false;
-    }
-
-    grid[row][col] == expected
+fn char_matches_at(grid: &Grid<char>, point: Point, expected: char) -> bool {
+    grid.get_point(point) == Some(&expected)
 }
 
 /// Parses the input string into a 2D grid of characters.
 ///
-/// Takes the input text and converts it into a vector of character vectors,
-/// where each inner vector represents a row in the grid.
+/// Thin wrapper around [`parse_char_grid`]: blank lines are skipped and every
+/// row must have the same length.
 ///
 /// # Parameters
 /// * `input` - Multi-line string containing the character grid
 ///
 /// # Returns
-/// 2D vector of characters representing the grid
+/// Rectangular grid of characters
+///
+/// # Errors
+///
+/// Returns a [`ParseError`](shared::input::ParseError) locating the first
+/// row whose length differs from the first row's.
 ///
 /// # Examples
 ///
 /// ```
 /// # use day04::parse_input;
-/// let input = "ABC\nDEF";
-/// let grid = parse_input(input);
-/// assert_eq!(grid, vec![
-///     vec!['A', 'B', 'C'],
-///     vec!['D', 'E', 'F']
-/// ]);
+/// let grid = parse_input("ABC\nDEF").unwrap();
+/// assert_eq!((grid.width(), grid.height()), (3, 2));
+/// assert_eq!(grid.row(1), Some(&['D', 'E', 'F'][..]));
 /// ```
-pub fn parse_input(input: &str) -> Vec<Vec<char>> {
-    input
-        .lines()
-        .map(str::trim)
-        .filter(|line| !line.is_empty())
-        .map(|line| line.chars().collect())
-        .collect()
+pub fn parse_input(input: &str) -> Result<Grid<char>> {
+    parse_char_grid(input)
 }
@@ -1,20 +1,22 @@
 use day04::*;
 use rstest::rstest;
+use shared::input::ParseError;
+use shared::point::{Direction, Point};
 
 // ===== PARSE INPUT TESTS =====
 
 #[test]
 fn test_parse_input_example() {
-    let grid = parse_input(EXAMPLE_INPUT);
-    assert_eq!(grid.len(), 10); // 10 rows
-    assert_eq!(grid[0].len(), 10); // 10 columns
+    let grid = parse_input(EXAMPLE_INPUT).unwrap();
+    assert_eq!(grid.height(), 10); // 10 rows
+    assert_eq!(grid.width(), 10); // 10 columns
     assert_eq!(
-        grid[0],
-        vec!['M', 'M', 'M', 'S', 'X', 'X', 'M', 'A', 'S', 'M']
+        grid.row(0).unwrap(),
+        ['M', 'M', 'M', 'S', 'X', 'X', 'M', 'A', 'S', 'M']
     ); // First row
     assert_eq!(
-        grid[9],
-        vec!['M', 'X', 'M', 'X', 'A', 'X', 'M', 'A', 'S', 'X']
+        grid.row(9).unwrap(),
+        ['M', 'X', 'M', 'X', 'A', 'X', 'M', 'A', 'S', 'X']
     ); // Last row
 }
 
@@ -24,43 +26,62 @@ fn test_parse_input_example() {
 #[case("ABC", vec![vec!['A', 'B', 'C']], "single line")] // Single line
 #[case("A\nB", vec![vec!['A'], vec!['B']], "single column")] // Single column
 #[case("AB\nCD\nEF", vec![vec!['A', 'B'], vec!['C', 'D'], vec!['E', 'F']], "multiple lines")] // Multiple lines
-#[case("ABC\nDE", vec![vec!['A', 'B', 'C'], vec!['D', 'E']], "different line lengths")] // Different line lengths
+#[case("AB\r\nCD\r\n", vec![vec!['A', 'B'], vec!['C', 'D']], "CRLF line endings")] // Trailing \r stripped
+#[case("AB\n\nCD\n", vec![vec!['A', 'B'], vec!['C', 'D']], "blank lines")] // Blank lines skipped
 fn test_parse_input_edge_cases(
     #[case] input: &str,
     #[case] expected: Vec<Vec<char>>,
     #[case] description: &str,
 ) {
-    let grid = parse_input(input);
-    assert_eq!(grid, expected, "Failed for {description}");
+    let grid = parse_input(input).unwrap();
+    let rows: Vec<Vec<char>> = grid.rows().map(<[char]>::to_vec).collect();
+    assert_eq!(rows, expected, "Failed for {description}");
+}
+
+#[rstest]
+#[case("ABC\nDE", 2, "DE", "3 columns")] // Short row
+#[case("AB\nCDE", 2, "CDE", "2 columns")] // Long row
+#[case("ABC\n\nDE", 3, "DE", "3 columns")] // Blank lines count towards the line number
+fn test_parse_input_rejects_ragged_rows(
+    #[case] input: &str,
+    #[case] line: usize,
+    #[case] text: &str,
+    #[case] expected: &str,
+) {
+    let error = parse_input(input).unwrap_err();
+    let error = error
+        .downcast_ref::<ParseError>()
+        .expect("Ragged rows should be reported as a ParseError");
+    assert_eq!(error.line, line, "Line mismatch for input: {input:?}");
+    assert_eq!(error.text, text, "Text mismatch for input: {input:?}");
+    assert_eq!(
+        error.expected, expected,
+        "Expected mismatch for input: {input:?}"
+    );
 }
 
 // ===== CORE FUNCTION TESTS =====
 
 #[rstest]
-#[case("XMAS\nABCD", 0, 0, 0, 1, true)] // Horizontal right: "XMAS" from (0,0)
-#[case("XMAS\nABCD", 0, 0, 0, -1, false)] // Horizontal left: no "XMAS" from (0,0)
-#[case("SAMX\nABCD", 0, 3, 0, -1, true)] // Horizontal left: "SAMX" backwards is "XMAS"
-#[case("X\nM\nA\nS", 0, 0, 1, 0, true)] // Vertical down: "XMAS" from (0,0)
-#[case("X\nM\nA\nS", 0, 0, -1, 0, false)] // Vertical up: no "XMAS" from (0,0)
-#[case("S\nA\nM\nX", 3, 0, -1, 0, true)] // Vertical up: "SAMX" upwards is "XMAS"
-#[case("X...\n.M..\n..A.\n...S", 0, 0, 1, 1, true)] // Diagonal down-right: "XMAS" from (0,0)
-#[case("S...\n.A..\n..M.\n...X", 3, 3, -1, -1, true)] // Diagonal up-left: "SAMX" up-left is "XMAS"
-#[case("XM\nAS", 0, 0, 0, 1, false)] // Horizontal: can't fit "XMAS" in 2x2 grid
-#[case("XM\nAS", 0, 0, 1, 0, false)] // Vertical: can't fit "XMAS" in 2x2 grid
-#[case("XM\nAS", 0, 0, 1, 1, false)] // Diagonal: can't fit "XMAS" in 2x2 grid
+#[case("XMAS\nABCD", Point(0, 0), Direction::East, true)] // Horizontal right: "XMAS" from (0,0)
+#[case("XMAS\nABCD", Point(0, 0), Direction::West, false)] // Horizontal left: no "XMAS" from (0,0)
+#[case("SAMX\nABCD", Point(0, 3), Direction::West, true)] // Horizontal left: "SAMX" backwards is "XMAS"
+#[case("X\nM\nA\nS", Point(0, 0), Direction::South, true)] // Vertical down: "XMAS" from (0,0)
+#[case("X\nM\nA\nS", Point(0, 0), Direction::North, false)] // Vertical up: no "XMAS" from (0,0)
+#[case("S\nA\nM\nX", Point(3, 0), Direction::North, true)] // Vertical up: "SAMX" upwards is "XMAS"
+#[case("X...\n.M..\n..A.\n...S", Point(0, 0), Direction::SouthEast, true)] // Diagonal down-right: "XMAS" from (0,0)
+#[case("S...\n.A..\n..M.\n...X", Point(3, 3), Direction::NorthWest, true)] // Diagonal up-left: "SAMX" up-left is "XMAS"
+#[case("XM\nAS", Point(0, 0), Direction::East, false)] // Horizontal: can't fit "XMAS" in 2x2 grid
+#[case("XM\nAS", Point(0, 0), Direction::South, false)] // Vertical: can't fit "XMAS" in 2x2 grid
+#[case("XM\nAS", Point(0, 0), Direction::SouthEast, false)] // Diagonal: can't fit "XMAS" in 2x2 grid
 fn test_check_direction(
     #[case] grid_input: &str,
-    #[case] row: usize,
-    #[case] col: usize,
-    #[case] delta_row: isize,
-    #[case] delta_col: isize,
+    #[case] start: Point,
+    #[case] direction: Direction,
     #[case] expected: bool,
 ) {
-    let grid = parse_input(grid_input);
-    assert_eq!(
-        check_direction(&grid, row, col, delta_row, delta_col),
-        expected
-    );
+    let grid = parse_input(grid_input).unwrap();
+    assert_eq!(check_direction(&grid, start, direction), expected);
 }
 
 #[rstest]
@@ -81,7 +102,7 @@ fn test_count_xmas_at_position(
     #[case] col: usize,
     #[case] expected: usize,
 ) {
-    let grid = parse_input(grid_input);
+    let grid = parse_input(grid_input).unwrap();
     assert_eq!(count_xmas_at_position(&grid, row, col), expected);
 }
 
@@ -102,7 +123,7 @@ fn test_is_xmas_pattern(
     #[case] col: usize,
     #[case] expected: bool,
 ) {
-    let grid = parse_input(grid_input);
+    let grid = parse_input(grid_input).unwrap();
     assert_eq!(is_xmas_pattern(&grid, row, col), expected);
 }
 
@@ -110,13 +131,15 @@ fn test_is_xmas_pattern(
 
 #[rstest]
 #[case(solve_part1, EXAMPLE_INPUT, 18)] // Part 1 with example input
+#[case(solve_part1_lines, EXAMPLE_INPUT, 18)] // Part 1 line scan with example input
 #[case(solve_part2, EXAMPLE_INPUT, 9)] // Part 2 with example input
+#[case(solve_part2_stencil, EXAMPLE_INPUT, 9)] // Part 2 stencil with example input
 fn test_solve_functions_example(
-    #[case] solve_fn: fn(&str) -> usize,
+    #[case] solve_fn: fn(&str) -> anyhow::Result<usize>,
     #[case] input: &str,
     #[case] expected: usize,
 ) {
-    let result = solve_fn(input);
+    let result = solve_fn(input).unwrap();
     assert_eq!(result, expected);
 }
 
@@ -126,26 +149,40 @@ fn test_solve_functions_example(
 #[case(solve_part1, "X\nM\nA\nS", 1)] // Vertical: "XMAS" going down
 #[case(solve_part1, "", 0)] // Empty input
 #[case(solve_part1, "ABCD\nEFGH", 0)] // No matches
+#[case(solve_part1_lines, "XMAS\nMASX", 1)] // Line scan: row 0 has "XMAS" going right
+#[case(solve_part1_lines, "X\nM\nA\nS", 1)] // Line scan: "XMAS" in the only column
+#[case(solve_part1_lines, "...S\n..A.\n.M..\nX...", 1)] // Line scan: "XMAS" on an anti-diagonal
+#[case(solve_part1_lines, "", 0)] // Line scan: empty input
 #[case(solve_part2, "M.S\n.A.\nM.S", 1)] // Single X-MAS pattern
 #[case(solve_part2, "ABC\nDEF\nGHI", 0)] // No patterns
 #[case(solve_part2, "", 0)] // Empty input
 #[case(solve_part2, "AB\nCD", 0)] // Grid too small for X-MAS pattern
 #[case(solve_part2, "M.S.M.S\n.A...A.\nM.S.M.S", 2)] // Multiple X-MAS patterns
+#[case(solve_part2_stencil, "M.S\n.A.\nM.S", 1)] // Stencil: single X-MAS pattern
+#[case(solve_part2_stencil, "S.S\n.A.\nM.M", 1)] // Stencil: both diagonals reversed
+#[case(solve_part2_stencil, "M.M\n.A.\nM.S", 0)] // Stencil: one diagonal reads "MAM"
+#[case(solve_part2_stencil, "AB\nCD", 0)] // Stencil: grid smaller than the window
+#[case(solve_part2_stencil, "", 0)] // Stencil: empty input
 fn test_solve_functions_edge_cases(
-    #[case] solve_fn: fn(&str) -> usize,
+    #[case] solve_fn: fn(&str) -> anyhow::Result<usize>,
     #[case] input: &str,
     #[case] expected: usize,
 ) {
-    let result = solve_fn(input);
+    let result = solve_fn(input).unwrap();
     assert_eq!(result, expected);
 }
 
 #[rstest]
 #[case(solve_part1, 2447)] // Part 1 with real input
+#[case(solve_part1_lines, 2447)] // Part 1 line scan with real input
 #[case(solve_part2, 1868)] // Part 2 with real input
-fn test_solve_functions_real_input(#[case] solve_fn: fn(&str) -> usize, #[case] expected: usize) {
+#[case(solve_part2_stencil, 1868)] // Part 2 stencil with real input
+fn test_solve_functions_real_input(
+    #[case] solve_fn: fn(&str) -> anyhow::Result<usize>,
+    #[case] expected: usize,
+) {
     let input = std::fs::read_to_string("input.txt")
         .expect("Failed to read input.txt - make sure it exists");
-    let result = solve_fn(&input);
+    let result = solve_fn(&input).unwrap();
     assert_eq!(result, expected);
 }
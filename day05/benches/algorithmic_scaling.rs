@@ -1,10 +1,18 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use day05::{solve_part1, solve_part1_naive};
+use day05::{
+    parse_input, solve_part1, solve_part1_naive, solve_part1_naive_parsed, solve_part1_parsed,
+};
 use shared::benchmarking::{
-    create_criterion_benchmark, process_benchmark_results, run_dual_algorithm_benchmark, Algorithm,
-    PlotConfig, TestConfig,
+    create_criterion_benchmark, process_benchmark_results, process_staged_results,
+    run_algorithm_benchmark, run_staged_benchmark, write_panel_plot, Algorithm, AxisScale,
+    PlotConfig, StagedAlgorithm, TestConfig,
 };
 
+#[cfg(feature = "alloc-tracking")]
+#[global_allocator]
+static ALLOCATOR: shared::alloc_tracking::TrackingAllocator =
+    shared::alloc_tracking::TrackingAllocator;
+
 const SEQUENCE_LENGTHS: [usize; 6] = [10, 20, 40, 80, 120, 160];
 const RULE_COUNTS: [usize; 6] = [25, 50, 100, 200, 400, 600];
 
@@ -14,35 +22,46 @@ fn benchmark_sequence_length_scaling(c: &mut Criterion) {
     let data_dir = "data";
     let group_name = "sequence_length_scaling";
 
-    let algorithm1 = Algorithm {
-        name: "optimized",
-        function: solve_part1,
-    };
-    let algorithm2 = Algorithm {
-        name: "naive",
-        function: solve_part1_naive,
-    };
+    let algorithms = [
+        Algorithm::new("optimized", solve_part1),
+        Algorithm::new("naive", solve_part1_naive),
+    ];
+
+    // The real puzzle input is benchmarked too when it is present
+    let real_input = std::fs::read_to_string("input.txt").ok();
 
     let test_config = TestConfig {
         sizes: &SEQUENCE_LENGTHS,
         generate_input: generate_sequence_length_test,
+        verify_outputs: true,
+        settings: None,
+        real_input: real_input.as_deref(),
+        // Only the process's first group sees the solvers cold
+        cold_start: true,
     };
 
-    run_dual_algorithm_benchmark(c, group_name, &algorithm1, &algorithm2, &test_config);
+    run_algorithm_benchmark(c, data_dir, group_name, &algorithms, &test_config)
+        .expect("Benchmarked algorithms must agree on every input");
 
     let plot_config = PlotConfig {
         filename: "quadratic_vs_linear_sequence_scaling.svg",
         title: "Algorithmic Scaling Analysis: Execution Time vs Sequence Length",
-        algorithm1_name: "Linear O(N+M) Algorithm",
-        algorithm2_name: "Quadratic O(N²M) Algorithm",
+        algorithm_names: &["Linear O(N+M) Algorithm", "Quadratic O(N²M) Algorithm"],
         x_axis_label: "Sequence Length (N)",
+        // Log-log turns O(N) and O(N²) into lines of slope 1 and 2
+        x_scale: AxisScale::Log,
+        y_scale: AxisScale::Log,
+        speedup_filename: Some("quadratic_vs_linear_sequence_speedup.svg"),
+        distribution_filename: Some("quadratic_vs_linear_sequence_distribution.svg"),
+        fit_overlay: true,
+        part: Some(1),
+        ..PlotConfig::default()
     };
 
     process_benchmark_results(
         data_dir,
         group_name,
-        &algorithm1,
-        &algorithm2,
+        &algorithms,
         &plot_config,
         &test_config,
     );
@@ -54,38 +73,84 @@ fn benchmark_rule_count_scaling(c: &mut Criterion) {
     let data_dir = "data";
     let group_name = "rule_count_scaling";
 
-    let algorithm1 = Algorithm {
-        name: "optimized",
-        function: solve_part1,
-    };
-    let algorithm2 = Algorithm {
-        name: "naive",
-        function: solve_part1_naive,
-    };
+    let algorithms = [
+        Algorithm::new("optimized", solve_part1),
+        Algorithm::new("naive", solve_part1_naive),
+    ];
 
     let test_config = TestConfig {
         sizes: &RULE_COUNTS,
         generate_input: generate_rule_count_test,
+        verify_outputs: true,
+        settings: None,
+        real_input: None,
+        cold_start: false,
     };
 
-    run_dual_algorithm_benchmark(c, group_name, &algorithm1, &algorithm2, &test_config);
+    run_algorithm_benchmark(c, data_dir, group_name, &algorithms, &test_config)
+        .expect("Benchmarked algorithms must agree on every input");
 
     let plot_config = PlotConfig {
         filename: "rule_count_linear_slope_comparison.svg",
         title: "Algorithmic Scaling Analysis: Execution Time vs Rule Count",
-        algorithm1_name: "O(N+M) ≈ O(M) Linear",
-        algorithm2_name: "O(N²M) Linear with slope N²",
+        algorithm_names: &["O(N+M) ≈ O(M) Linear", "O(N²M) Linear with slope N²"],
         x_axis_label: "Rule Count (M)",
+        y_scale: AxisScale::Log,
+        fit_overlay: true,
+        part: Some(1),
+        ..PlotConfig::default()
     };
 
     process_benchmark_results(
         data_dir,
         group_name,
-        &algorithm1,
-        &algorithm2,
+        &algorithms,
         &plot_config,
         &test_config,
     );
+
+    // Both scaling groups have run by now, so chart them side by side
+    match write_panel_plot(
+        data_dir,
+        "scaling_overview.svg",
+        "Day 05 Scaling: Sequence Length vs Rule Count",
+        &["sequence_length_scaling", group_name],
+    ) {
+        Ok(path) => println!("📈 Overview plot: {path}"),
+        Err(e) => eprintln!("Failed to create overview plot: {e}"),
+    }
+}
+
+/// Benchmark: Parse vs Solve
+/// Separates the parsing cost from the validation cost when varying sequence
+/// length
+fn benchmark_parse_vs_solve(c: &mut Criterion) {
+    let data_dir = "data";
+    let group_name = "parse_vs_solve";
+
+    let parse = |input: &str| parse_input(input).expect("Generated input must parse");
+    let algorithms = [
+        StagedAlgorithm::new("optimized", parse, |(rules, sequences)| {
+            solve_part1_parsed(rules, sequences)
+        }),
+        StagedAlgorithm::new("naive", parse, |(rules, sequences)| {
+            solve_part1_naive_parsed(rules, sequences)
+        }),
+    ];
+
+    let test_config = TestConfig {
+        sizes: &SEQUENCE_LENGTHS,
+        generate_input: generate_sequence_length_test,
+        verify_outputs: true,
+        settings: None,
+        real_input: None,
+        cold_start: false,
+    };
+
+    run_staged_benchmark(c, data_dir, group_name, &algorithms, &test_config)
+        .expect("Benchmarked algorithms must agree on every input");
+
+    process_staged_results(data_dir, group_name, &algorithms, &test_config);
 }
 
 /// Test data: Variable sequence length, fixed rules
@@ -226,6 +291,7 @@ fn generate_fixed_length_sequences(
 criterion_group!(
     name = benches;
     config = create_criterion_benchmark("data");
-    targets = benchmark_sequence_length_scaling, benchmark_rule_count_scaling
+    targets = benchmark_sequence_length_scaling, benchmark_rule_count_scaling,
+        benchmark_parse_vs_solve
 );
 criterion_main!(benches);
@@ -25,6 +25,8 @@
 //! 1. **Naive O(N²M)**: For each pair of pages in sequence, check all rules
 //! 2. **Optimized O(N + M)**: Build position map, then validate each rule in
 //!    constant time
+//! 3. **Graph**: The same position check against a prebuilt
+//!    [`Graph`](shared::graph::Graph) of the rules
 //!
 //! The optimized approach leverages the insight that we only need to check rule
 //! violations, not construct a full topological sort.
@@ -37,6 +39,10 @@
 use anyhow::{Context, Result};
 use itertools::Itertools;
 use rustc_hash::FxHashMap;
+use shared::graph::Graph;
+use shared::input::{parse_lines_with, parse_pair, parse_token, split_two_sections, ParseError};
+use shared::runner::{Solution, Variant};
+use shared::validate::check_lines_from;
 
 /// Type alias for ordering rules: list of (before_page, after_page) pairs
 type Rules = Vec<(u32, u32)>;
@@ -74,6 +80,30 @@ pub const EXAMPLE_INPUT: &str = "47|53
 61,13,29
 97,13,75,29,47";
 
+/// Registry entry used by the workspace runner.
+pub const SOLUTION: Solution = Solution {
+    day: 5,
+    title: "Print Queue",
+    example_input: EXAMPLE_INPUT,
+    example_input_part2: None,
+    part1: |input| Ok(solve_part1(input)?.to_string()),
+    part2: None,
+    variants: &[
+        Variant {
+            name: "naive",
+            part: 1,
+            solver: |input| Ok(solve_part1_naive(input)?.to_string()),
+        },
+        Variant {
+            name: "graph",
+            part: 1,
+            solver: |input| Ok(solve_part1_graph(input)?.to_string()),
+        },
+    ],
+    validate: Some(validate_input),
+    explain: None,
+};
+
 /// Solves Part 1: Finds sum of middle pages from correctly-ordered sequences.
 ///
 /// Parses the input into rules and sequences, validates each sequence against
@@ -99,11 +129,36 @@ pub const EXAMPLE_INPUT: &str = "47|53
 /// ```
 pub fn solve_part1(input: &str) -> Result<u32> {
     let (rules, sequences) = parse_input(input)?;
+    solve_part1_parsed(&rules, &sequences)
+}
 
+/// Solves Part 1 on already parsed rules and sequences.
+///
+/// Lets benchmarks time validation separately from [`parse_input`].
+///
+/// # Parameters
+/// * `rules` - Vector of (before, after) precedence constraint pairs
+/// * `sequences` - Page sequences to validate
+///
+/// # Returns
+/// Sum of middle page numbers from sequences that respect all ordering rules
+///
+/// # Errors
+///
+/// Returns an error if a valid sequence is empty.
+///
+/// # Examples
+///
+/// ```
+/// # use day05::solve_part1_parsed;
+/// let sequences = vec![vec![75, 47, 53], vec![53, 47]];
+/// assert_eq!(solve_part1_parsed(&[(47, 53)], &sequences).unwrap(), 47);
+/// ```
+pub fn solve_part1_parsed(rules: &[(u32, u32)], sequences: &[Vec<u32>]) -> Result<u32> {
     sequences
         .iter()
         .filter_map(|sequence| {
-            is_valid_sequence(sequence, &rules).then_some(get_middle_page(sequence))
+            is_valid_sequence(sequence, rules).then_some(get_middle_page(sequence))
         })
         .sum()
 }
@@ -177,11 +232,72 @@ pub fn is_valid_sequence(sequence: &[u32], rules: &[(u32, u32)]) -> bool {
 /// ```
 pub fn solve_part1_naive(input: &str) -> Result<u32> {
     let (rules, sequences) = parse_input(input)?;
+    solve_part1_naive_parsed(&rules, &sequences)
+}
+
+/// Naive O(N²M) Part 1 on already parsed rules and sequences.
+///
+/// # Parameters
+/// * `rules` - Vector of (before, after) precedence constraint pairs
+/// * `sequences` - Page sequences to validate
+///
+/// # Returns
+/// Sum of middle page numbers from sequences that respect all ordering rules
+///
+/// # Errors
+///
+/// Returns an error if a valid sequence is empty.
+///
+/// # Examples
+///
+/// ```
+/// # use day05::solve_part1_naive_parsed;
+/// let sequences = vec![vec![75, 47, 53], vec![53, 47]];
+/// assert_eq!(solve_part1_naive_parsed(&[(47, 53)], &sequences).unwrap(), 47);
+/// ```
+pub fn solve_part1_naive_parsed(rules: &[(u32, u32)], sequences: &[Vec<u32>]) -> Result<u32> {
+    sequences
+        .iter()
+        .filter_map(|sequence| {
+            is_valid_sequence_naive(sequence, rules).then_some(get_middle_page(sequence))
+        })
+        .sum()
+}
+
+/// Implementation of Part 1 on top of the shared [`Graph`] type.
+///
+/// Builds the rule graph once and checks each sequence with
+/// [`Graph::is_consistent_order`], which applies the same first/last position
+/// test as [`is_valid_sequence`] without rebuilding hash maps per sequence.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing rules and sequences sections
+///   separated by blank line
+///
+/// # Returns
+/// Sum of middle page numbers from sequences that respect all ordering rules
+///
+/// # Errors
+///
+/// Returns an error if input parsing fails.
+///
+/// # Examples
+///
+/// ```
+/// # use day05::solve_part1_graph;
+/// let input = "47|53\n\n75,47,53";
+/// assert_eq!(solve_part1_graph(input).unwrap(), 47);
+/// ```
+pub fn solve_part1_graph(input: &str) -> Result<u32> {
+    let (rules, sequences) = parse_input(input)?;
+    let graph = Graph::from_edges(rules);
 
     sequences
         .iter()
         .filter_map(|sequence| {
-            is_valid_sequence_naive(sequence, &rules).then_some(get_middle_page(sequence))
+            graph
+                .is_consistent_order(sequence)
+                .then_some(get_middle_page(sequence))
         })
         .sum()
 }
@@ -230,11 +346,14 @@ pub fn is_valid_sequence_naive(sequence: &[u32], rules: &[(u32, u32)]) -> bool {
 ///
 /// # Errors
 ///
-/// Returns an error if:
-/// - Input doesn't have exactly 2 sections
+/// Returns a [`SectionCountError`](shared::input::SectionCountError) if the
+/// input doesn't have exactly 2 sections, or a
+/// [`ParseError`](shared::input::ParseError) if:
 /// - Any rule doesn't have exactly 2 parts when split on '|'
 /// - Any page number cannot be parsed as u32
 ///
+/// Parse error line numbers count from the start of the whole input.
+///
 /// # Examples
 ///
 /// ```
@@ -246,36 +365,92 @@ pub fn is_valid_sequence_naive(sequence: &[u32], rules: &[(u32, u32)]) -> bool {
 pub fn parse_input(input: &str) -> Result<(Rules, Sequences)> {
     // Parse input into exactly 2 sections: rules and sequences
 
-    let (rules_section, sequences_section) = input
-        .split("\n\n")
-        .map(|section| section.trim())
-        .filter(|section| !section.is_empty())
-        .collect_tuple()
-        .context("Input must have exactly 2 sections")?;
+    let (rules_section, sequences_section) = split_two_sections(input)?;
 
     // Parse rules: "X|Y" format with whitespace tolerance
 
-    let rules = rules_section
-        .lines()
-        .map(|line| {
-            line.split('|')
-                .map(str::trim)
-                .collect_tuple()
-                .context(format!("Rule must have format 'X|Y', found: {line}"))
-                .and_then(|(a, b)| Ok((a.parse()?, b.parse()?)))
-        })
-        .try_collect()?;
+    let rules = parse_lines_with(rules_section, |line| parse_pair(line, '|', "a page number"))
+        .map_err(|error| offset_error_line(error, input, rules_section))?;
 
     // Parse sequences: comma-separated page numbers with whitespace tolerance
 
-    let sequences = sequences_section
-        .lines()
-        .map(|line| line.split(',').map(|s| s.trim().parse()).collect())
-        .try_collect()?;
+    let sequences = parse_lines_with(sequences_section, parse_sequence)
+        .map_err(|error| offset_error_line(error, input, sequences_section))?;
 
     Ok((rules, sequences))
 }
 
+/// Moves a [`ParseError`]'s section-relative line number to the line of
+/// `input` it is on; `section` must be a subslice of `input`.
+fn offset_error_line(mut error: anyhow::Error, input: &str, section: &str) -> anyhow::Error {
+    if let Some(parse_error) = error.downcast_mut::<ParseError>() {
+        parse_error.line += lines_before(input, section);
+    }
+    error
+}
+
+/// Counts the lines of `input` before `section`, which must be a subslice
+/// of `input`.
+fn lines_before(input: &str, section: &str) -> usize {
+    let section_start = section.as_ptr() as usize - input.as_ptr() as usize;
+    input[..section_start].matches('\n').count()
+}
+
+/// Checks the input shape and reports every problem at once.
+///
+/// Verifies that there are exactly two sections, that every rule is a
+/// `X|Y` pair of page numbers, and that every sequence is a comma-separated
+/// list of page numbers.
+///
+/// # Parameters
+/// * `input` - Multi-line string with rules and sequences sections
+///
+/// # Returns
+/// One message per problem, prefixed with the section it was found in;
+/// line numbers count from the start of the whole input, as in
+/// [`parse_input`]
+///
+/// # Examples
+///
+/// ```
+/// # use day05::validate_input;
+/// assert!(validate_input("47|53\n\n75,47,53").is_empty());
+/// assert_eq!(validate_input("47\n53|x\n\n75,47,53").len(), 2);
+/// ```
+pub fn validate_input(input: &str) -> Vec<String> {
+    let (rules_section, sequences_section) = match split_two_sections(input) {
+        Ok(sections) => sections,
+        Err(error) => return vec![error.to_string()],
+    };
+
+    let rule_problems = check_lines_from(
+        rules_section,
+        lines_before(input, rules_section) + 1,
+        |line| parse_pair::<u32>(line, '|', "a page number"),
+    );
+    let sequence_problems = check_lines_from(
+        sequences_section,
+        lines_before(input, sequences_section) + 1,
+        parse_sequence,
+    );
+    rule_problems
+        .into_iter()
+        .map(|problem| format!("Rules: {problem}"))
+        .chain(
+            sequence_problems
+                .into_iter()
+                .map(|problem| format!("Sequences: {problem}")),
+        )
+        .collect()
+}
+
+/// Parses one comma-separated sequence line.
+fn parse_sequence(line: &str) -> Result<Vec<u32>> {
+    line.split(',')
+        .map(|page| parse_token(line, page, "a page number"))
+        .collect()
+}
+
 /// Gets the middle page number from a sequence.
 ///
 /// For sequences with odd length, returns the true middle element.
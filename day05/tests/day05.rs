@@ -1,11 +1,20 @@
 use day05::{
     get_middle_page, is_valid_sequence, is_valid_sequence_naive, parse_input, solve_part1,
-    solve_part1_naive, EXAMPLE_INPUT,
+    solve_part1_graph, solve_part1_naive, solve_part1_naive_parsed, solve_part1_parsed,
+    validate_input, EXAMPLE_INPUT,
 };
 use rstest::rstest;
+use shared::graph::Graph;
+use shared::input::ParseError;
 
 // Type alias for validator function to avoid clippy complexity warnings
 type Validator = fn(&[u32], &[(u32, u32)]) -> bool;
+type ParsedSolver = fn(&[(u32, u32)], &[Vec<u32>]) -> anyhow::Result<u32>;
+
+// Adapts the shared graph check to the validator signature
+fn is_valid_sequence_graph(sequence: &[u32], rules: &[(u32, u32)]) -> bool {
+    Graph::from_edges(rules.iter().copied()).is_consistent_order(sequence)
+}
 
 // ===== PARSE INPUT TESTS =====
 
@@ -23,6 +32,9 @@ fn test_parse_input_example() {
 #[case("47 | 53\n\n75, 47, 53", vec![(47, 53)], vec![vec![75, 47, 53]])] // whitespace handling
 #[case("  47|53  \n\n  75,47,53  ", vec![(47, 53)], vec![vec![75, 47, 53]])] // leading/trailing whitespace
 #[case("47|53\n\n75,47,53\n", vec![(47, 53)], vec![vec![75, 47, 53]])] // trailing newline
+#[case("47|53\r\n\r\n75,47,53\r\n", vec![(47, 53)], vec![vec![75, 47, 53]])] // CRLF line endings
+#[case("47|53\n\n\n\n75,47,53", vec![(47, 53)], vec![vec![75, 47, 53]])] // repeated blank lines
+#[case("47|53\n  \n75,47,53", vec![(47, 53)], vec![vec![75, 47, 53]])] // whitespace-only separator
 fn test_parse_input_valid_cases(
     #[case] input: &str,
     #[case] expected_rules: Vec<(u32, u32)>,
@@ -41,10 +53,6 @@ fn test_parse_input_valid_cases(
 #[case("", "Input must have exactly 2 sections")] // empty input
 #[case("47|53", "Input must have exactly 2 sections")] // only one section
 #[case("47|53\n\n75,47\n\nextra", "Input must have exactly 2 sections")] // three sections
-// Invalid rule formats
-#[case("47|\n\n75,47", "cannot parse integer from empty string")] // missing after value
-#[case("47\n\n75,47", "Rule must have format 'X|Y', found: 47")] // no pipe separator
-#[case("47|53|61\n\n75,47", "Rule must have format 'X|Y', found: 47|53|61")] // too many parts
 fn test_parse_input_error_cases_with_messages(#[case] input: &str, #[case] expected_error: &str) {
     let result = parse_input(input);
     assert!(result.is_err(), "Expected error for input: {input:?}");
@@ -57,23 +65,56 @@ fn test_parse_input_error_cases_with_messages(#[case] input: &str, #[case] expec
 }
 
 #[rstest]
-// Numeric parsing errors (standard library error messages)
-#[case("abc|53\n\n75,47")] // non-numeric before value
-#[case("47|def\n\n75,47")] // non-numeric after value
-#[case("47|53\n\n75,abc")] // non-numeric page in sequence
-fn test_parse_input_numeric_error_cases(#[case] input: &str) {
-    let result = parse_input(input);
-    assert!(
-        result.is_err(),
-        "Expected numeric parsing error for input: {input:?}"
+// Invalid rule formats
+#[case("47|\n\n75,47", 1, 4, "", "a page number")] // missing after value
+#[case("47\n\n75,47", 1, 1, "47", "2 values separated by '|'")] // no pipe separator
+#[case("47|53|61\n\n75,47", 1, 1, "47|53|61", "2 values separated by '|'")] // too many parts
+#[case("47|53\n97|13\n61 | x\n\n75,47", 3, 6, "x", "a page number")] // bad rule on line 3
+// Numeric parsing errors
+#[case("abc|53\n\n75,47", 1, 1, "abc", "a page number")] // non-numeric before value
+#[case("47|def\n\n75,47", 1, 4, "def", "a page number")] // non-numeric after value
+#[case("47|53\n\n75,abc", 3, 4, "abc", "a page number")] // lines count from input start
+#[case("47|53\n\n75,47\n61, 1x", 4, 5, "1x", "a page number")] // second sequence line
+#[case("\n47|53\n97|13\n\n\n75,47\nx", 7, 1, "x", "a page number")] // extra blank lines before sections
+fn test_parse_input_error_locations(
+    #[case] input: &str,
+    #[case] line: usize,
+    #[case] column: usize,
+    #[case] text: &str,
+    #[case] expected: &str,
+) {
+    let error = parse_input(input).unwrap_err();
+    let error = error
+        .downcast_ref::<ParseError>()
+        .expect("Expected a ParseError");
+    assert_eq!(error.line, line, "Line mismatch for input: {input:?}");
+    assert_eq!(error.column, column, "Column mismatch for input: {input:?}");
+    assert_eq!(error.text, text, "Text mismatch for input: {input:?}");
+    assert_eq!(
+        error.expected, expected,
+        "Expected mismatch for input: {input:?}"
     );
+}
 
-    // These will have parse error messages from the standard library
-    let error_msg = result.unwrap_err().to_string();
-    assert!(
-        error_msg.contains("invalid digit") || error_msg.contains("ParseIntError"),
-        "Expected numeric parsing error for input {input:?}, got: {error_msg}"
-    );
+// ===== VALIDATE INPUT TESTS =====
+
+#[rstest]
+#[case(EXAMPLE_INPUT, vec![])] // well-formed example
+#[case("47|53", vec!["Input must have exactly 2 sections, found 1"])] // missing sequences
+#[case(
+    "47\n53|x\n\n75,47,53\n61,,13",
+    vec![
+        "Rules: Line 1, column 1: expected 2 values separated by '|', found '47'",
+        "Rules: Line 2, column 4: expected a page number, found 'x'",
+        "Sequences: Line 5, column 4: expected a page number, found ''",
+    ]
+)] // every problem is reported, not just the first, at its input line
+#[case(
+    "\n47|53\n\n\n75,x",
+    vec!["Sequences: Line 5, column 4: expected a page number, found 'x'"]
+)] // leading and repeated blank lines count towards the line number
+fn test_validate_input(#[case] input: &str, #[case] expected: Vec<&str>) {
+    assert_eq!(validate_input(input), expected, "Input: {input:?}");
 }
 
 // ===== CORE FUNCTION TESTS =====
@@ -112,7 +153,8 @@ fn test_get_middle_page_empty() {
 #[case(&[1, 2, 3], &[], true)] // no rules - always valid
 #[case(&[47, 53, 29], &[(47, 53), (53, 29), (47, 29)], true)] // transitive constraints
 fn test_is_valid_sequence(
-    #[values(is_valid_sequence, is_valid_sequence_naive)] validator: Validator,
+    #[values(is_valid_sequence, is_valid_sequence_naive, is_valid_sequence_graph)]
+    validator: Validator,
     #[case] sequence: &[u32],
     #[case] rules: &[(u32, u32)],
     #[case] expected: bool,
@@ -141,7 +183,8 @@ fn test_is_valid_sequence(
 #[case(&[1, 3, 2, 1], &[(1, 2)], false)] // XZYX pattern with X|Y rule - should fail
 #[case(&[1, 3, 2, 3], &[(1, 2)], true)] // XZYZ pattern with X|Y rule - should pass
 fn test_is_valid_sequence_duplicates(
-    #[values(is_valid_sequence, is_valid_sequence_naive)] validator: Validator,
+    #[values(is_valid_sequence, is_valid_sequence_naive, is_valid_sequence_graph)]
+    validator: Validator,
     #[case] sequence: &[u32],
     #[case] rules: &[(u32, u32)],
     #[case] expected: bool,
@@ -196,7 +239,8 @@ fn test_is_valid_sequence_duplicates(
 #[case(&[2, 1, 3], &[(1, 3), (2, 3)], true)] // both before 3, different order
 #[case(&[3, 1, 2], &[(1, 3), (2, 3)], false)] // 3 comes first
 fn test_is_valid_sequence_complex(
-    #[values(is_valid_sequence, is_valid_sequence_naive)] validator: Validator,
+    #[values(is_valid_sequence, is_valid_sequence_naive, is_valid_sequence_graph)]
+    validator: Validator,
     #[case] sequence: &[u32],
     #[case] rules: &[(u32, u32)],
     #[case] expected: bool,
@@ -213,6 +257,7 @@ fn test_is_valid_sequence_complex(
 #[rstest]
 #[case(solve_part1_naive, 143)] // Naive solve function
 #[case(solve_part1, 143)] // Optimized solve function
+#[case(solve_part1_graph, 143)] // Graph solve function
 fn test_solve_functions_example(
     #[case] solve_fn: fn(&str) -> Result<u32, anyhow::Error>,
     #[case] expected: u32,
@@ -233,7 +278,12 @@ fn test_solve_functions_example(
 #[case("1|2\n\n1\n2\n1,2", 5)] // single-element sequences: 1+2+2=5
 #[case("1|2\n2|3\n\n1,2,3,4,5", 3)] // rules subset of sequence
 fn test_solve_functions(
-    #[values(solve_part1, solve_part1_naive)] solver: fn(&str) -> Result<u32, anyhow::Error>,
+    #[values(solve_part1, solve_part1_naive, solve_part1_graph)] solver: fn(
+        &str,
+    ) -> Result<
+        u32,
+        anyhow::Error,
+    >,
     #[case] input: &str,
     #[case] expected: u32,
 ) {
@@ -244,9 +294,21 @@ fn test_solve_functions(
     );
 }
 
+#[rstest]
+fn test_solve_parsed_matches_solve(
+    #[values(solve_part1_parsed, solve_part1_naive_parsed)] solver: ParsedSolver,
+) {
+    let (rules, sequences) = parse_input(EXAMPLE_INPUT).unwrap();
+    assert_eq!(
+        solver(&rules, &sequences).unwrap(),
+        solve_part1(EXAMPLE_INPUT).unwrap()
+    );
+}
+
 #[rstest]
 #[case(solve_part1_naive, 4578)] // Naive solve function
 #[case(solve_part1, 4578)] // Optimized solve function
+#[case(solve_part1_graph, 4578)] // Graph solve function
 fn test_solve_functions_with_real_input(
     #[case] solve_fn: fn(&str) -> Result<u32, anyhow::Error>,
     #[case] expected: u32,
@@ -0,0 +1,132 @@
+//! Heap usage measurement for benchmarks.
+//!
+//! Enable the `alloc-tracking` feature and install [`TrackingAllocator`] as
+//! the global allocator of a bench binary to record allocations:
+//!
+//! ```ignore
+//! #[cfg(feature = "alloc-tracking")]
+//! #[global_allocator]
+//! static ALLOCATOR: shared::alloc_tracking::TrackingAllocator =
+//!     shared::alloc_tracking::TrackingAllocator;
+//! ```
+//!
+//! Without the allocator installed, [`measure`] still runs the closure but
+//! reports no statistics.
+
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Set once the tracking allocator serves its first allocation.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+/// Bytes currently allocated.
+static CURRENT_BYTES: AtomicUsize = AtomicUsize::new(0);
+/// Highest value of `CURRENT_BYTES` since the last reset.
+static PEAK_BYTES: AtomicUsize = AtomicUsize::new(0);
+/// Bytes allocated in total, never decreased by deallocations.
+static TOTAL_BYTES: AtomicUsize = AtomicUsize::new(0);
+/// Number of allocations made.
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// Heap usage of one measured invocation.
+///
+/// # Attributes
+/// * `peak_bytes` - Largest amount of memory held at once beyond what was
+///   already allocated when the invocation started
+/// * `total_bytes` - Sum of all allocation sizes
+/// * `allocations` - Number of allocations (including reallocations)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    pub peak_bytes: usize,
+    pub total_bytes: usize,
+    pub allocations: usize,
+}
+
+/// Runs a closure and records the heap usage it causes.
+///
+/// Measurements are process-wide, so allocations from other threads running
+/// at the same time are included.
+///
+/// # Returns
+/// The closure's result and its heap usage, or `None` for the usage if
+/// [`TrackingAllocator`] is not the global allocator
+///
+/// # Examples
+///
+/// ```
+/// # use shared::alloc_tracking::measure;
+/// let (sum, stats) = measure(|| (1..=10).sum::<u32>());
+/// assert_eq!(sum, 55);
+/// // Doc tests use the system allocator, so nothing is recorded
+/// assert_eq!(stats, None);
+/// ```
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<MemoryStats>) {
+    if !ACTIVE.load(Ordering::Relaxed) {
+        return (f(), None);
+    }
+
+    let start_bytes = CURRENT_BYTES.load(Ordering::Relaxed);
+    PEAK_BYTES.store(start_bytes, Ordering::Relaxed);
+    let start_total = TOTAL_BYTES.load(Ordering::Relaxed);
+    let start_allocations = ALLOCATIONS.load(Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = MemoryStats {
+        peak_bytes: PEAK_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(start_bytes),
+        total_bytes: TOTAL_BYTES.load(Ordering::Relaxed) - start_total,
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - start_allocations,
+    };
+    (result, Some(stats))
+}
+
+/// Global allocator that forwards to the system allocator and counts usage.
+#[cfg(feature = "alloc-tracking")]
+pub struct TrackingAllocator;
+
+#[cfg(feature = "alloc-tracking")]
+impl TrackingAllocator {
+    /// Records an allocation of `size` bytes.
+    fn record_alloc(size: usize) {
+        ACTIVE.store(true, Ordering::Relaxed);
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        TOTAL_BYTES.fetch_add(size, Ordering::Relaxed);
+        let current = CURRENT_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_BYTES.fetch_max(current, Ordering::Relaxed);
+    }
+}
+
+// SAFETY: every call is forwarded unchanged to the system allocator; the
+// bookkeeping only touches atomics and never allocates.
+#[cfg(feature = "alloc-tracking")]
+unsafe impl std::alloc::GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+        let ptr = std::alloc::System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: std::alloc::Layout) -> *mut u8 {
+        let ptr = std::alloc::System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+        std::alloc::System.dealloc(ptr, layout);
+        CURRENT_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: std::alloc::Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = std::alloc::System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
@@ -1,25 +1,622 @@
-use anyhow::{Context, Result};
-use criterion::{BenchmarkId, Criterion};
+use crate::alloc_tracking::{measure, MemoryStats};
+use crate::complexity::best_fit;
+use crate::perf_counters::{measure_counters, HardwareCounters};
+use crate::plotting::{AlgorithmChart, PlotTheme};
+use anyhow::{bail, Context, Result};
+use criterion::measurement::WallTime;
+use criterion::{BenchmarkGroup, BenchmarkId, Criterion};
+use rustc_hash::FxHashMap;
 use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Display, Write};
+use std::hash::{Hash, Hasher};
 use std::hint::black_box;
 use std::path::Path;
-use std::time::Duration;
+use std::process;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
-/// Benchmark results type: (size, algorithm1_time, algorithm2_time, speedup)
-pub type BenchmarkResults = Vec<(usize, f64, f64, f64)>;
+/// Mean execution times of every benchmarked algorithm at one input size.
+///
+/// # Attributes
+/// * `size` - Input size the times were measured at
+/// * `times` - Mean time in nanoseconds per algorithm, in benchmark order
+/// * `std_devs` - Standard deviation in nanoseconds per algorithm, in
+///   benchmark order
+/// * `memory` - Heap usage per algorithm, in benchmark order; empty unless
+///   the bench binary installs [`TrackingAllocator`](crate::alloc_tracking)
+/// * `counters` - Hardware counters per algorithm, in benchmark order; empty
+///   unless [`perf_counters`](crate::perf_counters) are available
+/// * `intervals` - Confidence interval of each mean time, in benchmark
+///   order; empty if unknown
+/// * `distributions` - Statistics of each algorithm's raw samples, in
+///   benchmark order; empty if Criterion's samples are unavailable
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BenchmarkResult {
+    pub size: usize,
+    pub times: Vec<f64>,
+    pub std_devs: Vec<f64>,
+    pub memory: Vec<MemoryStats>,
+    pub counters: Vec<HardwareCounters>,
+    pub intervals: Vec<ConfidenceInterval>,
+    pub distributions: Vec<SampleStats>,
+}
+
+/// Criterion's bootstrap confidence interval of a mean time.
+///
+/// # Attributes
+/// * `lower` - Lower bound in nanoseconds
+/// * `upper` - Upper bound in nanoseconds
+/// * `standard_error` - Standard error of the mean in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfidenceInterval {
+    pub lower: f64,
+    pub upper: f64,
+    pub standard_error: f64,
+}
+
+/// Distribution of a benchmark's per-iteration times, in nanoseconds.
+///
+/// Outliers use the Tukey fences Criterion reports: mild outliers lie more
+/// than 1.5 and severe outliers more than 3 interquartile ranges outside
+/// the quartiles.
+///
+/// # Attributes
+/// * `count` - Number of samples
+/// * `min` - Fastest sample
+/// * `p5` - 5th percentile
+/// * `q1` - 25th percentile
+/// * `median` - 50th percentile
+/// * `q3` - 75th percentile
+/// * `p95` - 95th percentile
+/// * `max` - Slowest sample
+/// * `mild_outliers` - Samples between the inner and outer fences
+/// * `severe_outliers` - Samples beyond the outer fences
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SampleStats {
+    pub count: usize,
+    pub min: f64,
+    pub p5: f64,
+    pub q1: f64,
+    pub median: f64,
+    pub q3: f64,
+    pub p95: f64,
+    pub max: f64,
+    pub mild_outliers: usize,
+    pub severe_outliers: usize,
+}
+
+impl SampleStats {
+    /// Computes the distribution statistics of raw samples.
+    ///
+    /// # Parameters
+    /// * `samples` - Per-iteration times in any order
+    ///
+    /// # Returns
+    /// The statistics, or `None` if there are no finite samples
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::benchmarking::SampleStats;
+    /// let mut samples: Vec<f64> = (1..=20).map(f64::from).collect();
+    /// samples.push(1000.0);
+    /// let stats = SampleStats::from_samples(&samples).unwrap();
+    /// assert_eq!(stats.median, 11.0);
+    /// assert_eq!(stats.severe_outliers, 1);
+    /// ```
+    pub fn from_samples(samples: &[f64]) -> Option<SampleStats> {
+        let mut sorted: Vec<f64> = samples.iter().copied().filter(|s| s.is_finite()).collect();
+        sorted.sort_by(f64::total_cmp);
+
+        let q1 = percentile(&sorted, 25.0)?;
+        let q3 = percentile(&sorted, 75.0)?;
+        let iqr = q3 - q1;
+        let outside = |width: f64| {
+            sorted
+                .iter()
+                .filter(|&&sample| sample < q1 - width * iqr || sample > q3 + width * iqr)
+                .count()
+        };
+        let severe_outliers = outside(3.0);
+
+        Some(SampleStats {
+            count: sorted.len(),
+            min: *sorted.first()?,
+            p5: percentile(&sorted, 5.0)?,
+            q1,
+            median: percentile(&sorted, 50.0)?,
+            q3,
+            p95: percentile(&sorted, 95.0)?,
+            max: *sorted.last()?,
+            mild_outliers: outside(1.5) - severe_outliers,
+            severe_outliers,
+        })
+    }
+}
+
+/// Returns a percentile of sorted samples, interpolating between ranks.
+///
+/// # Parameters
+/// * `sorted` - Samples in ascending order
+/// * `percent` - Percentile between 0 and 100
+///
+/// # Returns
+/// The percentile, or `None` if there are no samples
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::percentile;
+/// assert_eq!(percentile(&[1.0, 2.0, 3.0, 4.0], 50.0), Some(2.5));
+/// assert_eq!(percentile(&[], 50.0), None);
+/// ```
+pub fn percentile(sorted: &[f64], percent: f64) -> Option<f64> {
+    let last = sorted.len().checked_sub(1)?;
+    let rank = percent.clamp(0.0, 100.0) / 100.0 * last as f64;
+    let (below, above) = (rank.floor() as usize, rank.ceil() as usize);
+    let fraction = rank - below as f64;
+    Some(sorted[below] + (sorted[above] - sorted[below]) * fraction)
+}
+
+impl ConfidenceInterval {
+    /// Returns `true` if the two intervals share any value.
+    pub fn overlaps(&self, other: &ConfidenceInterval) -> bool {
+        self.lower <= other.upper && other.lower <= self.upper
+    }
+}
+
+impl BenchmarkResult {
+    /// Creates a result with times only; memory, counters, intervals and
+    /// distributions start empty.
+    pub fn new(size: usize, times: Vec<f64>, std_devs: Vec<f64>) -> Self {
+        BenchmarkResult {
+            size,
+            times,
+            std_devs,
+            ..BenchmarkResult::default()
+        }
+    }
+
+    /// Returns how many times slower an algorithm is than the first
+    /// (baseline) algorithm.
+    ///
+    /// # Parameters
+    /// * `index` - Position of the algorithm in `times`
+    ///
+    /// # Returns
+    /// The ratio `times[index] / times[0]`, or `None` if either is missing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::benchmarking::BenchmarkResult;
+    /// let result = BenchmarkResult::new(1000, vec![2000.0, 5000.0], vec![40.0, 90.0]);
+    /// assert_eq!(result.speedup(1), Some(2.5));
+    /// ```
+    pub fn speedup(&self, index: usize) -> Option<f64> {
+        Some(self.times.get(index)? / self.times.first()?)
+    }
+
+    /// Returns the standard error of [`speedup`](Self::speedup).
+    ///
+    /// Propagates both means' standard errors through the ratio with the
+    /// first-order (delta method) approximation.
+    ///
+    /// # Parameters
+    /// * `index` - Position of the algorithm in `times`
+    ///
+    /// # Returns
+    /// The speedup's standard error, or `None` without confidence intervals
+    pub fn speedup_error(&self, index: usize) -> Option<f64> {
+        let speedup = self.speedup(index)?;
+        let relative_error = |position: usize| -> Option<f64> {
+            Some(self.intervals.get(position)?.standard_error / self.times.get(position)?)
+        };
+        Some(speedup * relative_error(index)?.hypot(relative_error(0)?))
+    }
+
+    /// Returns whether an algorithm's difference from the baseline is
+    /// statistically meaningful.
+    ///
+    /// # Parameters
+    /// * `index` - Position of the algorithm in `times`
+    ///
+    /// # Returns
+    /// `true` if the confidence intervals of the algorithm and the baseline
+    /// do not overlap, or `None` without confidence intervals
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::benchmarking::{BenchmarkResult, ConfidenceInterval};
+    /// let interval = |lower, upper| ConfidenceInterval { lower, upper, standard_error: 5.0 };
+    /// let result = BenchmarkResult {
+    ///     intervals: vec![interval(95.0, 105.0), interval(100.0, 120.0), interval(290.0, 310.0)],
+    ///     ..BenchmarkResult::new(1000, vec![100.0, 110.0, 300.0], vec![10.0, 10.0, 10.0])
+    /// };
+    /// assert_eq!(result.is_significant(1), Some(false)); // 1.1x is noise
+    /// assert_eq!(result.is_significant(2), Some(true));
+    /// ```
+    pub fn is_significant(&self, index: usize) -> Option<bool> {
+        let baseline = self.intervals.first()?;
+        Some(!self.intervals.get(index)?.overlaps(baseline))
+    }
+
+    /// Returns how many input elements an algorithm processes per second.
+    ///
+    /// An element is whatever the benchmark's sizes count, e.g. lines,
+    /// sequences or bytes.
+    ///
+    /// # Parameters
+    /// * `index` - Position of the algorithm in `times`
+    ///
+    /// # Returns
+    /// `size / mean time` in elements per second, or `None` if the time is
+    /// missing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::benchmarking::BenchmarkResult;
+    /// let result = BenchmarkResult::new(1000, vec![2000.0], vec![40.0]);
+    /// assert_eq!(result.throughput(0), Some(500_000_000.0));
+    /// ```
+    pub fn throughput(&self, index: usize) -> Option<f64> {
+        let seconds = self.times.get(index)? / 1e9;
+        Some(self.size as f64 / seconds)
+    }
+}
+
+/// Unit of the benchmark sizes when plotting throughput.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThroughputUnit {
+    /// Sizes count items such as lines or sequences
+    Elements,
+    /// Sizes count input bytes, as for parser-style workloads
+    Bytes,
+}
+
+/// Quantity shown on the y-axis of a benchmark plot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlotMetric {
+    /// Mean execution time in microseconds
+    #[default]
+    Latency,
+    /// Processed input per second, in millions of the given unit
+    Throughput(ThroughputUnit),
+}
+
+impl PlotMetric {
+    /// Returns the y-axis label.
+    pub fn axis_label(self) -> &'static str {
+        match self {
+            PlotMetric::Latency => "Time (microseconds)",
+            PlotMetric::Throughput(ThroughputUnit::Elements) => "Throughput (Melem/s)",
+            PlotMetric::Throughput(ThroughputUnit::Bytes) => "Throughput (MB/s)",
+        }
+    }
+
+    /// Returns the plotted value of one algorithm at one size.
+    ///
+    /// # Parameters
+    /// * `result` - Benchmark result for one size
+    /// * `index` - Position of the algorithm in `times`
+    ///
+    /// # Returns
+    /// Microseconds for latency, millions of units per second for
+    /// throughput, or `None` if the time is missing
+    pub fn value(self, result: &BenchmarkResult, index: usize) -> Option<f64> {
+        match self {
+            PlotMetric::Latency => Some(result.times.get(index)? / 1000.0),
+            PlotMetric::Throughput(_) => Some(result.throughput(index)? / 1e6),
+        }
+    }
+
+    /// Returns the plotted range of one algorithm's confidence interval at
+    /// one size.
+    ///
+    /// # Parameters
+    /// * `result` - Benchmark result for one size
+    /// * `index` - Position of the algorithm in `intervals`
+    ///
+    /// # Returns
+    /// `(low, high)` in the units of [`value`](Self::value), or `None` if
+    /// no interval was extracted
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::benchmarking::{BenchmarkResult, ConfidenceInterval, PlotMetric};
+    /// let result = BenchmarkResult {
+    ///     intervals: vec![ConfidenceInterval { lower: 1000.0, upper: 4000.0, standard_error: 30.0 }],
+    ///     ..BenchmarkResult::new(1000, vec![2000.0], vec![40.0])
+    /// };
+    /// assert_eq!(PlotMetric::Latency.interval(&result, 0), Some((1.0, 4.0)));
+    /// ```
+    pub fn interval(self, result: &BenchmarkResult, index: usize) -> Option<(f64, f64)> {
+        let interval = result.intervals.get(index)?;
+        match self {
+            PlotMetric::Latency => Some((interval.lower / 1000.0, interval.upper / 1000.0)),
+            // The slowest time bounds the throughput from below
+            PlotMetric::Throughput(_) => {
+                let per_second = |nanos: f64| result.size as f64 / (nanos / 1e9) / 1e6;
+                Some((per_second(interval.upper), per_second(interval.lower)))
+            }
+        }
+    }
+
+    /// Returns the name used to persist the metric in plot metadata.
+    fn key(self) -> &'static str {
+        match self {
+            PlotMetric::Latency => "latency",
+            PlotMetric::Throughput(ThroughputUnit::Elements) => "elements",
+            PlotMetric::Throughput(ThroughputUnit::Bytes) => "bytes",
+        }
+    }
+
+    /// Parses a name written by `key`.
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "latency" => Some(PlotMetric::Latency),
+            "elements" => Some(PlotMetric::Throughput(ThroughputUnit::Elements)),
+            "bytes" => Some(PlotMetric::Throughput(ThroughputUnit::Bytes)),
+            _ => None,
+        }
+    }
+}
+
+/// Scale of an axis of a benchmark plot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AxisScale {
+    /// Evenly spaced sizes
+    #[default]
+    Linear,
+    /// Logarithmic sizes; with the log y-axis, polynomial growth becomes a
+    /// straight line whose slope is the exponent
+    Log,
+}
+
+impl AxisScale {
+    /// Maps a value to its position on the axis.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::benchmarking::AxisScale;
+    /// assert_eq!(AxisScale::Linear.coordinate(100.0), 100.0);
+    /// assert_eq!(AxisScale::Log.coordinate(100.0), 2.0);
+    /// ```
+    pub fn coordinate(self, value: f64) -> f64 {
+        match self {
+            AxisScale::Linear => value,
+            AxisScale::Log => value.log10(),
+        }
+    }
+
+    /// Maps an axis position back to the value it stands for; the inverse
+    /// of [`coordinate`](Self::coordinate).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::benchmarking::AxisScale;
+    /// assert_eq!(AxisScale::Linear.value(100.0), 100.0);
+    /// assert_eq!(AxisScale::Log.value(2.0), 100.0);
+    /// ```
+    pub fn value(self, coordinate: f64) -> f64 {
+        match self {
+            AxisScale::Linear => coordinate,
+            AxisScale::Log => 10f64.powf(coordinate),
+        }
+    }
+
+    /// Returns the name stored in plot metadata.
+    fn key(self) -> &'static str {
+        match self {
+            AxisScale::Linear => "linear",
+            AxisScale::Log => "log",
+        }
+    }
+
+    /// Parses a name written by `key`.
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "linear" => Some(AxisScale::Linear),
+            "log" => Some(AxisScale::Log),
+            _ => None,
+        }
+    }
+}
+
+/// Text drawn next to the data points of a benchmark plot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PointAnnotation {
+    /// Speedup relative to the first algorithm at every point
+    #[default]
+    Speedup,
+    /// Plotted value at every point, e.g. `12.50μs`
+    Value,
+    /// Speedup only at the smallest and largest size, for sizes too close
+    /// together to label each one
+    MinMax,
+    /// No labels
+    None,
+}
+
+impl PointAnnotation {
+    /// Returns the name stored in plot metadata.
+    fn key(self) -> &'static str {
+        match self {
+            PointAnnotation::Speedup => "speedup",
+            PointAnnotation::Value => "value",
+            PointAnnotation::MinMax => "min_max",
+            PointAnnotation::None => "none",
+        }
+    }
+
+    /// Parses a name written by `key`.
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "speedup" => Some(PointAnnotation::Speedup),
+            "value" => Some(PointAnnotation::Value),
+            "min_max" => Some(PointAnnotation::MinMax),
+            "none" => Some(PointAnnotation::None),
+            _ => None,
+        }
+    }
+}
+
+/// Benchmark results for every input size, in ascending size order.
+pub type BenchmarkResults = Vec<BenchmarkResult>;
 
 /// Algorithm definition pairing name with implementation.
 ///
 /// Groups semantically related parameters: an algorithm's identifier and its function.
-/// Uses function pointers for maximum performance and type compatibility.
+/// The function is boxed so one slice can mix plain functions with closures
+/// that capture configuration or precomputed state.
 ///
 /// # Attributes
 /// * `name` - Unique identifier for the algorithm (used in Criterion folder names)
-/// * `function` - Function pointer to the algorithm implementation (should match expected signature)
+/// * `function` - Algorithm implementation taking the generated input
+///
+/// # Examples
 ///
+/// ```
+/// # use shared::benchmarking::Algorithm;
+/// fn count_lines(input: &str) -> usize {
+///     input.lines().count()
+/// }
+///
+/// let threshold = 3;
+/// let algorithms = [
+///     Algorithm::new("plain", count_lines),
+///     Algorithm::new("configured", move |input: &str| {
+///         input.lines().filter(|line| line.len() > threshold).count()
+///     }),
+/// ];
+/// assert_eq!((algorithms[1].function)("a\nlong line"), 1);
+/// ```
 pub struct Algorithm<'a, R> {
     pub name: &'a str,
-    pub function: fn(&str) -> R,
+    pub function: Box<dyn Fn(&str) -> R + 'a>,
+}
+
+impl<'a, R> Algorithm<'a, R> {
+    /// Creates an algorithm from a function or closure.
+    pub fn new(name: &'a str, function: impl Fn(&str) -> R + 'a) -> Self {
+        Algorithm {
+            name,
+            function: Box::new(function),
+        }
+    }
+}
+
+/// Algorithm split into a parse stage and a solve stage timed separately.
+///
+/// # Attributes
+/// * `name` - Unique identifier for the algorithm; its stages appear in
+///   Criterion folder names as `{name}_parse` and `{name}_solve`
+/// * `parse` - Turns the generated input into the parsed representation
+/// * `solve` - Computes the answer from the parsed representation
+///
+/// # Type Parameters
+/// * `P` - Parsed representation passed from `parse` to `solve`
+/// * `R` - Result type of `solve`
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::StagedAlgorithm;
+/// let algorithm = StagedAlgorithm::new(
+///     "sum",
+///     |input: &str| input.lines().map(|line| line.len()).collect::<Vec<_>>(),
+///     |lengths: &Vec<usize>| lengths.iter().sum::<usize>(),
+/// );
+/// assert_eq!(algorithm.stage_names(), ["sum_parse", "sum_solve"]);
+/// assert_eq!((algorithm.solve)(&(algorithm.parse)("ab\nc")), 3);
+/// ```
+pub struct StagedAlgorithm<'a, P, R> {
+    pub name: &'a str,
+    pub parse: Box<dyn Fn(&str) -> P + 'a>,
+    pub solve: Box<dyn Fn(&P) -> R + 'a>,
+}
+
+impl<'a, P, R> StagedAlgorithm<'a, P, R> {
+    /// Creates a staged algorithm from its parse and solve functions.
+    pub fn new(
+        name: &'a str,
+        parse: impl Fn(&str) -> P + 'a,
+        solve: impl Fn(&P) -> R + 'a,
+    ) -> Self {
+        StagedAlgorithm {
+            name,
+            parse: Box::new(parse),
+            solve: Box::new(solve),
+        }
+    }
+
+    /// Returns the Criterion folder names of the parse and solve stages.
+    pub fn stage_names(&self) -> [String; 2] {
+        [
+            format!("{}_parse", self.name),
+            format!("{}_solve", self.name),
+        ]
+    }
+}
+
+/// Parallel algorithm benchmarked across rayon thread pool sizes.
+///
+/// The function must be shareable across threads because it runs inside a
+/// dedicated rayon pool.
+///
+/// # Attributes
+/// * `name` - Unique identifier for the algorithm (used in Criterion folder names)
+/// * `function` - Algorithm implementation; rayon parallel iterators inside it
+///   use the pool of the thread count being measured
+///
+/// # Examples
+///
+/// ```
+/// # use rayon::prelude::*;
+/// # use shared::benchmarking::ParallelAlgorithm;
+/// let algorithm = ParallelAlgorithm::new("par_sum", |input: &str| {
+///     input.par_lines().map(|line| line.len()).sum::<usize>()
+/// });
+/// assert_eq!((algorithm.function)("ab\nc"), 3);
+/// ```
+#[cfg(feature = "parallel")]
+pub struct ParallelAlgorithm<'a, R> {
+    pub name: &'a str,
+    pub function: Box<dyn Fn(&str) -> R + Send + Sync + 'a>,
+}
+
+#[cfg(feature = "parallel")]
+impl<'a, R> ParallelAlgorithm<'a, R> {
+    /// Creates a parallel algorithm from a function or closure.
+    pub fn new(name: &'a str, function: impl Fn(&str) -> R + Send + Sync + 'a) -> Self {
+        ParallelAlgorithm {
+            name,
+            function: Box::new(function),
+        }
+    }
+}
+
+/// Configuration of a thread-count scaling benchmark.
+///
+/// # Attributes
+/// * `thread_counts` - Rayon pool sizes to measure; they take the place of
+///   input sizes on the x-axis and in Criterion folder names
+/// * `input` - Input passed to every algorithm at every thread count
+/// * `verify_outputs` - Require every algorithm to return the same output at
+///   every thread count before timing
+/// * `settings` - Timing settings for this group, overriding those of the
+///   Criterion instance; `None` keeps the instance's settings
+#[cfg(feature = "parallel")]
+pub struct ThreadScalingConfig<'a> {
+    pub thread_counts: &'a [usize],
+    pub input: &'a str,
+    pub verify_outputs: bool,
+    pub settings: Option<CriterionSettings>,
 }
 
 /// Test configuration for benchmark execution.
@@ -29,6 +626,14 @@ pub struct Algorithm<'a, R> {
 /// # Attributes
 /// * `sizes` - Array of input sizes to test (e.g., [1000, 5000, 10000])
 /// * `generate_input` - Function to generate input for each size
+/// * `verify_outputs` - Run every algorithm once per input and require equal
+///   outputs before timing
+/// * `settings` - Timing settings for this group, overriding those of the
+///   Criterion instance; `None` keeps the instance's settings
+/// * `real_input` - Actual puzzle input benchmarked as one extra point
+///   labelled [`REAL_INPUT_LABEL`] instead of a size; `None` skips it
+/// * `cold_start` - Time each algorithm's first invocation before anything
+///   else runs, see [`measure_cold_start`]
 ///
 /// # Type Parameters
 /// * `G` - Function type for input generation (e.g., `Fn(usize) -> String`)
@@ -39,6 +644,103 @@ where
 {
     pub sizes: &'a [usize],
     pub generate_input: G,
+    pub verify_outputs: bool,
+    pub settings: Option<CriterionSettings>,
+    pub real_input: Option<&'a str>,
+    pub cold_start: bool,
+}
+
+/// Criterion parameter naming the benchmark point of the real puzzle input.
+pub const REAL_INPUT_LABEL: &str = "real";
+
+/// Criterion timing parameters.
+///
+/// The defaults suit typical AoC solutions; slow algorithms need a longer
+/// measurement window to collect their samples, while fast ones benefit from
+/// more samples. Built with [`CriterionSettings::new`], which rejects values
+/// Criterion would panic on.
+///
+/// # Attributes
+/// * `warm_up` - Time spent running the benchmark before measuring
+/// * `measurement` - Time budget for collecting samples
+/// * `sample_size` - Number of samples to collect (at least 10)
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::CriterionSettings;
+/// # use std::time::Duration;
+/// let warm_up = Duration::from_millis(500);
+/// let slow = CriterionSettings::new(warm_up, Duration::from_secs(10), 20)?;
+/// assert_eq!(slow.sample_size(), 20);
+/// let too_few = CriterionSettings::new(warm_up, Duration::from_secs(1), 5);
+/// assert!(too_few.is_err());
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CriterionSettings {
+    warm_up: Duration,
+    measurement: Duration,
+    sample_size: usize,
+}
+
+impl CriterionSettings {
+    /// Smallest sample size Criterion accepts.
+    pub const MIN_SAMPLE_SIZE: usize = 10;
+
+    /// Creates timing parameters.
+    ///
+    /// # Parameters
+    /// * `warm_up` - Time spent running the benchmark before measuring
+    /// * `measurement` - Time budget for collecting samples
+    /// * `sample_size` - Number of samples to collect
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `sample_size` is below
+    /// [`MIN_SAMPLE_SIZE`](Self::MIN_SAMPLE_SIZE) or either duration is zero,
+    /// all of which make Criterion panic.
+    pub fn new(warm_up: Duration, measurement: Duration, sample_size: usize) -> Result<Self> {
+        if sample_size < Self::MIN_SAMPLE_SIZE {
+            bail!(
+                "Criterion needs at least {} samples, got {sample_size}",
+                Self::MIN_SAMPLE_SIZE
+            );
+        }
+        if warm_up.is_zero() || measurement.is_zero() {
+            bail!("Criterion needs nonzero warm-up and measurement times");
+        }
+        Ok(CriterionSettings {
+            warm_up,
+            measurement,
+            sample_size,
+        })
+    }
+
+    /// Returns the time spent running the benchmark before measuring.
+    pub fn warm_up(&self) -> Duration {
+        self.warm_up
+    }
+
+    /// Returns the time budget for collecting samples.
+    pub fn measurement(&self) -> Duration {
+        self.measurement
+    }
+
+    /// Returns the number of samples to collect.
+    pub fn sample_size(&self) -> usize {
+        self.sample_size
+    }
+}
+
+impl Default for CriterionSettings {
+    fn default() -> Self {
+        CriterionSettings {
+            warm_up: Duration::from_millis(500),
+            measurement: Duration::from_millis(2000),
+            sample_size: 20,
+        }
+    }
 }
 
 /// Plot configuration for benchmark visualization.
@@ -49,16 +751,65 @@ where
 /// # Attributes
 /// * `filename` - Output SVG filename (should be filesystem-safe)
 /// * `title` - Human-readable title displayed on the plot (can contain any characters)
-/// * `algorithm1_name` - Human-readable name for algorithm 1 in plot legend
-/// * `algorithm2_name` - Human-readable name for algorithm 2 in plot legend
+/// * `algorithm_names` - Human-readable legend names, one per algorithm in
+///   benchmark order
 /// * `x_axis_label` - Label for the x-axis (e.g., "Sequence Length (N)", "Rule Count (M)")
-/// ```
+/// * `metric` - Whether the y-axis shows latency or throughput
+/// * `x_scale` - Whether the x-axis is linear or logarithmic
+/// * `y_scale` - Whether the y-axis is linear or logarithmic; linear keeps
+///   the differences between near-equal algorithms visible
+/// * `x_range` - Explicit `(start, end)` sizes shown; `None` fits every size
+/// * `y_range` - Explicit `(start, end)` values shown, in the metric's
+///   units; `None` fits every value
+/// * `speedup_filename` - Also chart only the speedups relative to the first
+///   algorithm into this file; `None` skips the speedup chart
+/// * `distribution_filename` - Also box plot each algorithm's raw sample
+///   distribution per size into this file; `None` skips the chart
+/// * `fit_overlay` - Overlay each latency series with its fitted power law
+///   and name the exponent in the legend
+/// * `annotation` - Which labels are drawn next to the data points
+/// * `theme` - Dimensions, colors and fonts of every chart
+/// * `part` - Puzzle part the algorithms solve; only groups with a part
+///   appear in the cross-day runtime chart
+///
+/// The default is an unnamed latency chart on linear axes with speedup
+/// labels and no extra charts, for struct-update syntax.
+#[derive(Default)]
 pub struct PlotConfig<'a> {
     pub filename: &'a str,
     pub title: &'a str,
-    pub algorithm1_name: &'a str,
-    pub algorithm2_name: &'a str,
+    pub algorithm_names: &'a [&'a str],
     pub x_axis_label: &'a str,
+    pub metric: PlotMetric,
+    pub x_scale: AxisScale,
+    pub y_scale: AxisScale,
+    pub x_range: Option<(f64, f64)>,
+    pub y_range: Option<(f64, f64)>,
+    pub speedup_filename: Option<&'a str>,
+    pub distribution_filename: Option<&'a str>,
+    pub fit_overlay: bool,
+    pub annotation: PointAnnotation,
+    pub theme: PlotTheme,
+    pub part: Option<u8>,
+}
+
+impl PlotConfig<'_> {
+    /// Describes the chart of `results` drawn with this configuration.
+    fn chart<'b>(&'b self, results: &'b [BenchmarkResult]) -> AlgorithmChart<'b> {
+        AlgorithmChart {
+            title: self.title,
+            series_names: self.algorithm_names,
+            x_axis_label: self.x_axis_label,
+            metric: self.metric,
+            x_scale: self.x_scale,
+            y_scale: self.y_scale,
+            x_range: self.x_range,
+            y_range: self.y_range,
+            fit_overlay: self.fit_overlay,
+            annotation: self.annotation,
+            results,
+        }
+    }
 }
 
 /// Creates a Criterion instance optimized for fast benchmarking.
@@ -80,229 +831,2604 @@ pub struct PlotConfig<'a> {
 /// // Use the criterion instance for benchmarking...
 /// ```
 pub fn create_criterion_benchmark(data_dir: &str) -> Criterion {
+    create_criterion_benchmark_with(data_dir, &CriterionSettings::default())
+}
+
+/// Creates a Criterion instance with custom timing parameters.
+///
+/// # Parameters
+/// * `data_dir` - Directory path where benchmark data will be stored
+/// * `settings` - Timing parameters applied to every group of the instance
+///
+/// # Returns
+/// Configured Criterion instance
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::{create_criterion_benchmark_with, CriterionSettings};
+/// # use std::time::Duration;
+/// let second = Duration::from_secs(1);
+/// let many = CriterionSettings::new(second, second, 100)?;
+/// let mut c = create_criterion_benchmark_with("day03/data", &many);
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn create_criterion_benchmark_with(data_dir: &str, settings: &CriterionSettings) -> Criterion {
     Criterion::default()
         .output_directory(Path::new(data_dir))
-        .warm_up_time(Duration::from_millis(500))
-        .measurement_time(Duration::from_millis(2000))
-        .sample_size(20)
+        .warm_up_time(settings.warm_up)
+        .measurement_time(settings.measurement)
+        .sample_size(settings.sample_size)
 }
 
-/// Benchmarks two algorithms against each other across multiple input sizes.
+/// Benchmarks several algorithms against each other across multiple input
+/// sizes.
 ///
 /// Generates statistical data for performance analysis and comparison using Criterion.
-/// Optimized for fast benchmarking with reduced timing parameters.
+/// Optimized for fast benchmarking with reduced timing parameters. Skipped
+/// when [`is_benchmark_unchanged`] finds the stored results still current.
 ///
 /// # Parameters
 /// * `c` - Criterion instance for benchmarking
+/// * `data_dir` - Directory where benchmark data is stored
 /// * `group_name` - Benchmark group name (used for organization in reports)
-/// * `algorithm1` - First algorithm definition (name + function)
-/// * `algorithm2` - Second algorithm definition (name + function)
-/// * `config` - Test configuration (sizes + input generation)
-pub fn run_dual_algorithm_benchmark<'a, R, G>(
+/// * `algorithms` - Algorithm definitions (name + function); the first is
+///   the baseline that speedups are reported against
+/// * `config` - Test configuration (sizes, input generation, timing settings)
+///
+/// # Errors
+///
+/// Returns an error before timing a size if `config.verify_outputs` is set
+/// and the algorithms disagree on that size's input.
+pub fn run_algorithm_benchmark<'a, R, G>(
     c: &mut Criterion,
+    data_dir: &str,
     group_name: &str,
-    algorithm1: &Algorithm<'a, R>,
-    algorithm2: &Algorithm<'a, R>,
+    algorithms: &[Algorithm<'a, R>],
     config: &TestConfig<'a, G>,
-) where
+) -> Result<()>
+where
+    R: Debug,
     G: Fn(usize) -> String,
 {
+    let names: Vec<&str> = algorithms.iter().map(|algorithm| algorithm.name).collect();
+    if is_benchmark_unchanged(data_dir, group_name, &names, config) {
+        println!("⏭️  {group_name} unchanged, reusing stored results");
+        return Ok(());
+    }
+
+    // Must come first: verification below would already pay one-time costs
+    if config.cold_start {
+        report_cold_start(group_name, algorithms, config);
+    }
+
     let mut group = c.benchmark_group(group_name);
+    if let Some(settings) = &config.settings {
+        group
+            .warm_up_time(settings.warm_up)
+            .measurement_time(settings.measurement)
+            .sample_size(settings.sample_size);
+    }
 
     for &size in config.sizes {
         let input = (config.generate_input)(size);
+        if config.verify_outputs {
+            verify_algorithm_outputs(algorithms, &input)
+                .with_context(|| format!("Output mismatch in '{group_name}' at size {size}"))?;
+        }
+
+        bench_algorithms(&mut group, algorithms, size, &input);
+    }
 
-        for (name, func) in [
-            (algorithm1.name, algorithm1.function),
-            (algorithm2.name, algorithm2.function),
-        ] {
-            group.bench_with_input(BenchmarkId::new(name, size), &input, |b, input| {
-                b.iter(|| black_box(func(black_box(input))))
-            });
+    if let Some(input) = config.real_input {
+        if config.verify_outputs {
+            verify_algorithm_outputs(algorithms, input)
+                .with_context(|| format!("Output mismatch in '{group_name}' on the real input"))?;
         }
+        bench_algorithms(&mut group, algorithms, REAL_INPUT_LABEL, input);
     }
 
     group.finish();
+    Ok(())
 }
 
-/// Processes benchmark results and generates visualization outputs.
+/// Benchmarks parallel algorithms on one input across rayon thread counts.
 ///
-/// Extracts timing data from Criterion JSON files, creates custom SVG plots,
-/// and prints performance summaries. Uses Algorithm instances and TestConfig for
-/// consistent signature with run_dual_algorithm_benchmark.
+/// Each thread count gets its own pool, and the whole Criterion timing loop
+/// runs inside it, so pool dispatch is not part of the measured time.
 ///
 /// # Parameters
-/// * `data_dir` - Directory where benchmark data is stored
-/// * `group_name` - Benchmark group name used by Criterion
-/// * `algorithm1` - Algorithm instance (name used for Criterion folder lookup)
-/// * `algorithm2` - Algorithm instance (name used for Criterion folder lookup)
-/// * `plot_config` - Display configuration for plot generation
-/// * `test_config` - Test configuration containing sizes and input generation
+/// * `c` - Criterion instance for benchmarking
+/// * `group_name` - Benchmark group name (used for organization in reports)
+/// * `algorithms` - Parallel algorithm definitions; the first is the
+///   baseline that speedups are reported against
+/// * `config` - Thread counts, input and timing settings
 ///
-/// # Examples
-/// ```no_run
-/// # use shared::benchmarking::{process_benchmark_results, PlotConfig, Algorithm, TestConfig};
-/// # fn solve_naive(_: &str) -> i32 { 0 }
-/// # fn solve_hashmap(_: &str) -> i32 { 0 }
-/// # fn gen_input(_: usize) -> String { String::new() }
-/// let algo1 = Algorithm { name: "naive", function: solve_naive as fn(&str) -> i32 };
-/// let algo2 = Algorithm { name: "hashmap", function: solve_hashmap as fn(&str) -> i32 };
-/// let test_config = TestConfig { sizes: &[1000, 5000], generate_input: gen_input };
-/// let plot_config = PlotConfig {
-///     filename: "naive_vs_hashmap.svg",
-///     title: "Algorithm Performance Comparison",
-///     algorithm1_name: "O(n²) Naive Algorithm",
-///     algorithm2_name: "O(n) HashMap Solution",
-///     x_axis_label: "Input Size (N)",
-/// };
-/// process_benchmark_results("data", "criterion", &algo1, &algo2, &plot_config, &test_config);
-/// ```
-pub fn process_benchmark_results<R, G>(
-    data_dir: &str,
+/// # Errors
+///
+/// Returns an error if a thread pool cannot be built, or before timing a
+/// thread count if `config.verify_outputs` is set and an algorithm's output
+/// differs from the first algorithm's single pool output.
+#[cfg(feature = "parallel")]
+pub fn run_thread_scaling_benchmark<R>(
+    c: &mut Criterion,
     group_name: &str,
-    algorithm1: &Algorithm<R>,
-    algorithm2: &Algorithm<R>,
-    plot_config: &PlotConfig,
-    test_config: &TestConfig<G>,
-) where
-    G: Fn(usize) -> String,
+    algorithms: &[ParallelAlgorithm<R>],
+    config: &ThreadScalingConfig,
+) -> Result<()>
+where
+    R: Debug,
 {
-    let plot_path = format!("{data_dir}/{}", plot_config.filename);
+    let mut group = c.benchmark_group(group_name);
+    if let Some(settings) = &config.settings {
+        group
+            .warm_up_time(settings.warm_up)
+            .measurement_time(settings.measurement)
+            .sample_size(settings.sample_size);
+    }
 
-    match extract_criterion_results(
-        data_dir,
-        group_name,
-        algorithm1.name,
-        algorithm2.name,
-        test_config.sizes,
-    ) {
-        Ok(results) => {
-            // Generate custom plot co-located with data
-            if let Err(e) = crate::plotting::create_dual_algorithm_plot(
-                &plot_path,
-                plot_config.title,
-                plot_config.algorithm1_name,
-                plot_config.algorithm2_name,
-                plot_config.x_axis_label,
-                &results,
-            ) {
-                eprintln!("Failed to create plot: {e}");
-            } else {
-                println!("✅ Benchmark complete!");
-                println!("📊 View HTML reports: {data_dir}/{group_name}/report/index.html");
-                println!("📈 Custom plot: {plot_path}");
+    let mut expected: Option<String> = None;
+    for &threads in config.thread_counts {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .with_context(|| format!("Failed to build a pool of {threads} threads"))?;
+
+        for algorithm in algorithms {
+            let func = &algorithm.function;
+            if config.verify_outputs {
+                let actual = pool.install(|| format!("{:?}", func(config.input)));
+                match &expected {
+                    Some(expected) if *expected != actual => bail!(
+                        "Algorithm '{}' returned {actual} with {threads} threads but {expected} was expected in '{group_name}'",
+                        algorithm.name
+                    ),
+                    Some(_) => {}
+                    None => expected = Some(actual),
+                }
             }
 
-            // Print results summary
-            print_benchmark_summary(&results);
-        }
-        Err(e) => {
-            eprintln!("Failed to extract benchmark results: {e}");
+            group.bench_with_input(
+                BenchmarkId::new(algorithm.name, threads),
+                config.input,
+                |b, input| pool.install(|| b.iter(|| black_box(func(black_box(input))))),
+            );
         }
     }
+
+    group.finish();
+    Ok(())
 }
-/// Extracts timing data from Criterion JSON files.
+
+/// Benchmarks the parse and solve stages of several algorithms separately
+/// across multiple input sizes.
 ///
-/// Reads mean execution times from Criterion's estimates.json files and calculates
-/// speedup ratios for performance comparison. Uses Criterion folder names to locate
-/// the correct benchmark data directories.
+/// The solve stage is timed on input parsed once beforehand, so the two
+/// stages add up to the cost of the whole algorithm. Skipped when
+/// [`is_benchmark_unchanged`] finds the stored results still current.
 ///
 /// # Parameters
-/// * `base_path` - Base path where Criterion data is stored
-/// * `group_name` - Benchmark group name used by Criterion
-/// * `algo1_name` - Criterion folder name for first algorithm
-/// * `algo2_name` - Criterion folder name for second algorithm  
-/// * `sizes` - Array of input sizes that were tested
+/// * `c` - Criterion instance for benchmarking
+/// * `data_dir` - Directory where benchmark data is stored
+/// * `group_name` - Benchmark group name (used for organization in reports)
+/// * `algorithms` - Staged algorithm definitions
+/// * `config` - Test configuration (sizes, input generation, timing settings)
 ///
-/// # Returns
-/// Benchmark results as (size, algorithm1_time_ns, algorithm2_time_ns, speedup) tuples
+/// # Errors
+///
+/// Returns an error before timing a size if `config.verify_outputs` is set
+/// and the algorithms' solve stages disagree on that size's input.
+pub fn run_staged_benchmark<'a, P, R, G>(
+    c: &mut Criterion,
+    data_dir: &str,
+    group_name: &str,
+    algorithms: &[StagedAlgorithm<'a, P, R>],
+    config: &TestConfig<'a, G>,
+) -> Result<()>
+where
+    R: Debug,
+    G: Fn(usize) -> String,
+{
+    let stage_names: Vec<String> = algorithms
+        .iter()
+        .flat_map(|algorithm| algorithm.stage_names())
+        .collect();
+    let names: Vec<&str> = stage_names.iter().map(String::as_str).collect();
+    if is_benchmark_unchanged(data_dir, group_name, &names, config) {
+        println!("⏭️  {group_name} unchanged, reusing stored results");
+        return Ok(());
+    }
+
+    let mut group = c.benchmark_group(group_name);
+    if let Some(settings) = &config.settings {
+        group
+            .warm_up_time(settings.warm_up)
+            .measurement_time(settings.measurement)
+            .sample_size(settings.sample_size);
+    }
+
+    // Whole algorithms, for verifying outputs with the unstaged helper
+    let combined: Vec<Algorithm<R>> = algorithms
+        .iter()
+        .map(|algorithm| {
+            Algorithm::new(algorithm.name, |input: &str| {
+                (algorithm.solve)(&(algorithm.parse)(input))
+            })
+        })
+        .collect();
+    if config.cold_start {
+        report_cold_start(group_name, &combined, config);
+    }
+
+    let inputs = config
+        .sizes
+        .iter()
+        .map(|&size| (size.to_string(), (config.generate_input)(size)))
+        .chain(
+            config
+                .real_input
+                .map(|input| (REAL_INPUT_LABEL.to_string(), input.to_string())),
+        );
+    for (parameter, input) in inputs {
+        if config.verify_outputs {
+            verify_algorithm_outputs(&combined, &input).with_context(|| {
+                format!("Output mismatch in '{group_name}' at input '{parameter}'")
+            })?;
+        }
+
+        for algorithm in algorithms {
+            let [parse_name, solve_name] = algorithm.stage_names();
+            let parse = &algorithm.parse;
+            group.bench_with_input(
+                BenchmarkId::new(parse_name, &parameter),
+                input.as_str(),
+                |b, input| b.iter(|| black_box(parse(black_box(input)))),
+            );
+
+            let solve = &algorithm.solve;
+            let parsed = parse(&input);
+            group.bench_with_input(
+                BenchmarkId::new(solve_name, &parameter),
+                &parsed,
+                |b, parsed| b.iter(|| black_box(solve(black_box(parsed)))),
+            );
+        }
+    }
+
+    group.finish();
+    Ok(())
+}
+
+/// Number of invocations after the first whose median is the warm time.
+const WARM_RUNS: usize = 10;
+
+/// First-invocation and steady-state time of one algorithm.
+///
+/// # Attributes
+/// * `algorithm` - Name of the algorithm
+/// * `first_ns` - Time of the first invocation in nanoseconds
+/// * `warm_ns` - Median time of the following invocations in nanoseconds
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColdStart {
+    pub algorithm: String,
+    pub first_ns: f64,
+    pub warm_ns: f64,
+}
+
+impl ColdStart {
+    /// Returns the one-time cost paid by the first invocation.
+    pub fn overhead_ns(&self) -> f64 {
+        self.first_ns - self.warm_ns
+    }
+}
+
+/// Times the first invocation of every algorithm against its warm time.
+///
+/// The first call pays one-time costs such as compiling `LazyLock` regexes
+/// and growing the allocator's heap, which Criterion's warm-up hides. Only
+/// the first invocation in the process is truly cold, so this must run
+/// before the algorithms are called for anything else; process-wide costs
+/// like allocator warm-up are attributed to the first algorithm.
+///
+/// # Parameters
+/// * `algorithms` - Algorithms to time, in order
+/// * `input` - Input passed to every invocation
+///
+/// # Returns
+/// One measurement per algorithm, in order
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::{measure_cold_start, Algorithm};
+/// let algorithms = [Algorithm::new("lines", |input: &str| input.lines().count())];
+/// let cold = measure_cold_start(&algorithms, "a\nb");
+/// assert_eq!(cold[0].algorithm, "lines");
+/// assert!(cold[0].first_ns > 0.0);
+/// ```
+pub fn measure_cold_start<R>(algorithms: &[Algorithm<R>], input: &str) -> Vec<ColdStart> {
+    let time = |function: &dyn Fn(&str) -> R| {
+        let start = Instant::now();
+        black_box(function(black_box(input)));
+        start.elapsed().as_secs_f64() * 1e9
+    };
+
+    algorithms
+        .iter()
+        .map(|algorithm| {
+            let first_ns = time(&algorithm.function);
+            let mut warm: Vec<f64> = (0..WARM_RUNS).map(|_| time(&algorithm.function)).collect();
+            warm.sort_by(f64::total_cmp);
+            ColdStart {
+                algorithm: algorithm.name.to_string(),
+                first_ns,
+                warm_ns: warm[WARM_RUNS / 2],
+            }
+        })
+        .collect()
+}
+
+/// Measures and prints the cold-start cost of a group's algorithms.
+///
+/// Uses the real input when configured, since that is what a full run
+/// pays for, and the first generated size otherwise.
+fn report_cold_start<R, G>(group_name: &str, algorithms: &[Algorithm<R>], config: &TestConfig<G>)
+where
+    G: Fn(usize) -> String,
+{
+    let input = match (config.real_input, config.sizes.first()) {
+        (Some(input), _) => input.to_string(),
+        (None, Some(&size)) => (config.generate_input)(size),
+        (None, None) => return,
+    };
+
+    println!("🥶 Cold start of '{group_name}':");
+    for cold in measure_cold_start(algorithms, &input) {
+        println!(
+            "  {}: first {:.2}μs, warm {:.2}μs (+{:.2}μs one-time)",
+            cold.algorithm,
+            cold.first_ns / 1000.0,
+            cold.warm_ns / 1000.0,
+            cold.overhead_ns() / 1000.0
+        );
+    }
+}
+
+/// Times every algorithm on one input within a benchmark group.
+///
+/// # Parameters
+/// * `group` - Criterion group the benchmarks belong to
+/// * `algorithms` - Algorithms to time
+/// * `parameter` - Label of the input in benchmark ids (a size or
+///   [`REAL_INPUT_LABEL`])
+/// * `input` - Input passed to every algorithm
+fn bench_algorithms<R>(
+    group: &mut BenchmarkGroup<'_, WallTime>,
+    algorithms: &[Algorithm<R>],
+    parameter: impl Display,
+    input: &str,
+) {
+    for algorithm in algorithms {
+        let func = &algorithm.function;
+        group.bench_with_input(
+            BenchmarkId::new(algorithm.name, &parameter),
+            input,
+            |b, input| b.iter(|| black_box(func(black_box(input)))),
+        );
+    }
+}
+
+/// Runs every algorithm once on an input and checks that they agree.
+///
+/// Outputs are compared by their `Debug` representation so solvers returning
+/// `anyhow::Result` can be checked too; two errors only match if their
+/// messages do.
+///
+/// # Parameters
+/// * `algorithms` - Algorithms to compare; the first is the reference
+/// * `input` - Input passed to every algorithm
+///
+/// # Errors
+///
+/// Returns an error naming the first algorithm whose output differs from the
+/// reference algorithm's.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::{verify_algorithm_outputs, Algorithm};
+/// let algorithms = [
+///     Algorithm::new("bytes", |input: &str| input.len()),
+///     Algorithm::new("chars", |input: &str| input.chars().count()),
+/// ];
+/// assert!(verify_algorithm_outputs(&algorithms, "abc").is_ok());
+/// assert!(verify_algorithm_outputs(&algorithms, "héllo").is_err());
+/// ```
+pub fn verify_algorithm_outputs<R: Debug>(algorithms: &[Algorithm<R>], input: &str) -> Result<()> {
+    let Some((reference, others)) = algorithms.split_first() else {
+        return Ok(());
+    };
+    let expected = format!("{:?}", (reference.function)(input));
+    for algorithm in others {
+        let actual = format!("{:?}", (algorithm.function)(input));
+        if actual != expected {
+            bail!(
+                "Algorithm '{}' returned {actual} but '{}' returned {expected}",
+                algorithm.name,
+                reference.name
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Processes benchmark results and generates visualization outputs.
+///
+/// Extracts timing data from Criterion JSON files, creates custom SVG plots,
+/// and prints performance summaries. Uses Algorithm instances and TestConfig for
+/// consistent signature with run_algorithm_benchmark.
+///
+/// # Parameters
+/// * `data_dir` - Directory where benchmark data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+/// * `algorithms` - Algorithm instances (names used for Criterion folder
+///   lookup)
+/// * `plot_config` - Display configuration for plot generation
+/// * `test_config` - Test configuration containing sizes and input generation
+///
+/// # Examples
+/// ```no_run
+/// # use shared::benchmarking::{
+/// #     process_benchmark_results, Algorithm, AxisScale, PlotConfig, TestConfig,
+/// # };
+/// # fn solve_naive(_: &str) -> i32 { 0 }
+/// # fn solve_hashmap(_: &str) -> i32 { 0 }
+/// # fn gen_input(_: usize) -> String { String::new() }
+/// let algorithms = [
+///     Algorithm::new("hashmap", solve_hashmap),
+///     Algorithm::new("naive", solve_naive),
+/// ];
+/// let test_config = TestConfig {
+///     sizes: &[1000, 5000],
+///     generate_input: gen_input,
+///     verify_outputs: true,
+///     settings: None,
+///     real_input: None,
+///     cold_start: false,
+/// };
+/// let plot_config = PlotConfig {
+///     filename: "hashmap_vs_naive.svg",
+///     title: "Algorithm Performance Comparison",
+///     algorithm_names: &["O(n) HashMap Solution", "O(n²) Naive Algorithm"],
+///     x_axis_label: "Input Size (N)",
+///     y_scale: AxisScale::Log,
+///     ..PlotConfig::default()
+/// };
+/// process_benchmark_results("data", "criterion", &algorithms, &plot_config, &test_config);
+/// ```
+pub fn process_benchmark_results<R, G>(
+    data_dir: &str,
+    group_name: &str,
+    algorithms: &[Algorithm<R>],
+    plot_config: &PlotConfig,
+    test_config: &TestConfig<G>,
+) where
+    G: Fn(usize) -> String,
+{
+    let plot_path = format!("{data_dir}/{}", plot_config.filename);
+    let names: Vec<&str> = algorithms.iter().map(|algorithm| algorithm.name).collect();
+
+    match extract_criterion_results(data_dir, group_name, &names, test_config.sizes) {
+        Ok(mut results) => {
+            measure_resource_usage(&mut results, algorithms, test_config);
+
+            // Generate custom plot co-located with data
+            if let Err(e) = crate::plotting::create_algorithm_plot(
+                &plot_path,
+                &plot_config.theme,
+                &plot_config.chart(&results),
+            ) {
+                eprintln!("Failed to create plot: {e}");
+            } else {
+                println!("✅ Benchmark complete!");
+                println!("📊 View HTML reports: {data_dir}/{group_name}/report/index.html");
+                println!("📈 Custom plot: {plot_path}");
+            }
+            write_speedup_plot(data_dir, plot_config, &results);
+            write_distribution_plot(data_dir, plot_config, &results);
+
+            // Persist plot metadata so `aoc plot` can regenerate the chart
+            if let Err(e) =
+                write_plot_spec(data_dir, group_name, &names, plot_config, test_config.sizes)
+            {
+                eprintln!("Failed to save plot metadata: {e}");
+            }
+
+            // Export machine-readable results for tracking over time
+            match write_results_json(data_dir, group_name, &names, &results) {
+                Ok(path) => println!("🧾 Results JSON: {path}"),
+                Err(e) => eprintln!("Failed to export results: {e}"),
+            }
+
+            match write_markdown_report(data_dir, group_name, plot_config, &results) {
+                Ok(path) => println!("📝 Markdown report: {path}"),
+                Err(e) => eprintln!("Failed to write Markdown report: {e}"),
+            }
+
+            if let Err(e) = record_benchmark_fingerprint(data_dir, group_name, &names, test_config)
+            {
+                eprintln!("Failed to record benchmark fingerprint: {e}");
+            }
+
+            // Print results summary
+            print_benchmark_summary(&names, &results);
+            print_distribution_summary(&names, &results);
+            print_complexity_fits(&names, &results);
+        }
+        Err(e) => {
+            eprintln!("Failed to extract benchmark results: {e}");
+        }
+    }
+
+    if test_config.real_input.is_some() {
+        match extract_real_input_result(data_dir, group_name, &names) {
+            Ok(Some(result)) => print_real_input_summary(&names, &result),
+            Ok(None) => eprintln!("No real input results found for '{group_name}'"),
+            Err(e) => eprintln!("Failed to extract real input results: {e}"),
+        }
+    }
+}
+
+/// Extracts, plots, exports and summarises a thread-count scaling benchmark.
+///
+/// Thread counts are stored in each result's `size`, so the plot's x-axis
+/// and the exported results use the thread count in place of an input size.
+///
+/// # Parameters
+/// * `data_dir` - Directory where benchmark data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+/// * `algorithms` - Parallel algorithm definitions used for the benchmark
+/// * `plot_config` - Display configuration; `x_axis_label` should name the
+///   thread count
+/// * `config` - Thread scaling configuration used for the benchmark
+#[cfg(feature = "parallel")]
+pub fn process_thread_scaling_results<R>(
+    data_dir: &str,
+    group_name: &str,
+    algorithms: &[ParallelAlgorithm<R>],
+    plot_config: &PlotConfig,
+    config: &ThreadScalingConfig,
+) {
+    let plot_path = format!("{data_dir}/{}", plot_config.filename);
+    let names: Vec<&str> = algorithms.iter().map(|algorithm| algorithm.name).collect();
+
+    match extract_criterion_results(data_dir, group_name, &names, config.thread_counts) {
+        Ok(results) => {
+            if let Err(e) = crate::plotting::create_algorithm_plot(
+                &plot_path,
+                &plot_config.theme,
+                &plot_config.chart(&results),
+            ) {
+                eprintln!("Failed to create plot: {e}");
+            } else {
+                println!("📈 Custom plot: {plot_path}");
+            }
+            write_speedup_plot(data_dir, plot_config, &results);
+            write_distribution_plot(data_dir, plot_config, &results);
+
+            if let Err(e) = write_plot_spec(
+                data_dir,
+                group_name,
+                &names,
+                plot_config,
+                config.thread_counts,
+            ) {
+                eprintln!("Failed to save plot metadata: {e}");
+            }
+
+            match write_results_json(data_dir, group_name, &names, &results) {
+                Ok(path) => println!("🧾 Results JSON: {path}"),
+                Err(e) => eprintln!("Failed to export results: {e}"),
+            }
+
+            print_thread_scaling_summary(&names, &results);
+        }
+        Err(e) => {
+            eprintln!("Failed to extract benchmark results: {e}");
+        }
+    }
+}
+
+/// Prints each algorithm's parallel speedup and efficiency per thread count.
+///
+/// Speedup is relative to the algorithm's own time at the first thread
+/// count; efficiency divides that speedup by the increase in threads.
+///
+/// # Parameters
+/// * `algorithm_names` - Names of the algorithms, in the order of each
+///   result's `times`
+/// * `results` - Results whose `size` holds the thread count
+pub fn print_thread_scaling_summary(algorithm_names: &[&str], results: &[BenchmarkResult]) {
+    let Some(first) = results.first() else {
+        return;
+    };
+    println!("\n🧵 Thread scaling:");
+    for result in results {
+        let entries: Vec<String> = algorithm_names
+            .iter()
+            .zip(result.times.iter().zip(&first.times))
+            .map(|(name, (time, base))| {
+                let speedup = base / time;
+                let efficiency = 100.0 * speedup * first.size as f64 / result.size as f64;
+                format!(
+                    "{name} {:.2}μs ({speedup:.2}x, {efficiency:.0}% efficient)",
+                    time / 1000.0
+                )
+            })
+            .collect();
+        println!("  {} threads: {}", result.size, entries.join(", "));
+    }
+}
+
+/// Draws the speedup chart requested by a plot configuration, if any,
+/// reporting failures without stopping.
+///
+/// # Parameters
+/// * `data_dir` - Directory the chart is written to
+/// * `plot_config` - Display configuration naming the chart file
+/// * `results` - Benchmark results to chart
+fn write_speedup_plot(data_dir: &str, plot_config: &PlotConfig, results: &[BenchmarkResult]) {
+    let Some(filename) = plot_config.speedup_filename else {
+        return;
+    };
+    let speedup_path = format!("{data_dir}/{filename}");
+    match crate::plotting::create_speedup_plot(
+        &speedup_path,
+        plot_config.title,
+        plot_config.algorithm_names,
+        plot_config.x_axis_label,
+        plot_config.x_scale,
+        &plot_config.theme,
+        results,
+    ) {
+        Ok(()) => println!("📈 Speedup plot: {speedup_path}"),
+        Err(e) => eprintln!("Failed to create speedup plot: {e}"),
+    }
+}
+
+/// Draws the sample distribution chart requested by a plot configuration,
+/// if any, reporting failures without stopping.
+///
+/// # Parameters
+/// * `data_dir` - Directory the chart is written to
+/// * `plot_config` - Display configuration naming the chart file
+/// * `results` - Benchmark results whose distributions are charted
+fn write_distribution_plot(data_dir: &str, plot_config: &PlotConfig, results: &[BenchmarkResult]) {
+    let Some(filename) = plot_config.distribution_filename else {
+        return;
+    };
+    let distribution_path = format!("{data_dir}/{filename}");
+    match crate::plotting::create_distribution_plot(
+        &distribution_path,
+        plot_config.title,
+        plot_config.algorithm_names,
+        plot_config.x_axis_label,
+        &plot_config.theme,
+        results,
+    ) {
+        Ok(()) => println!("📦 Distribution plot: {distribution_path}"),
+        Err(e) => eprintln!("Failed to create distribution plot: {e}"),
+    }
+}
+
+/// Extracts, exports and summarises the results of a staged benchmark.
+///
+/// Writes the results JSON with one entry per stage and prints how each
+/// algorithm's time divides between parsing and solving, including on the
+/// real puzzle input when it was benchmarked.
+///
+/// # Parameters
+/// * `data_dir` - Directory where benchmark data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+/// * `algorithms` - Staged algorithm definitions used for the benchmark
+/// * `test_config` - Test configuration used for the benchmark
+pub fn process_staged_results<P, R, G>(
+    data_dir: &str,
+    group_name: &str,
+    algorithms: &[StagedAlgorithm<P, R>],
+    test_config: &TestConfig<G>,
+) where
+    G: Fn(usize) -> String,
+{
+    let stage_names: Vec<String> = algorithms
+        .iter()
+        .flat_map(|algorithm| algorithm.stage_names())
+        .collect();
+    let names: Vec<&str> = stage_names.iter().map(String::as_str).collect();
+    let algorithm_names: Vec<&str> = algorithms.iter().map(|algorithm| algorithm.name).collect();
+
+    match extract_criterion_results(data_dir, group_name, &names, test_config.sizes) {
+        Ok(results) => {
+            match write_results_json(data_dir, group_name, &names, &results) {
+                Ok(path) => println!("🧾 Results JSON: {path}"),
+                Err(e) => eprintln!("Failed to export results: {e}"),
+            }
+            if let Err(e) = record_benchmark_fingerprint(data_dir, group_name, &names, test_config)
+            {
+                eprintln!("Failed to record benchmark fingerprint: {e}");
+            }
+            print_stage_summary(&algorithm_names, &results);
+        }
+        Err(e) => {
+            eprintln!("Failed to extract benchmark results: {e}");
+        }
+    }
+
+    if test_config.real_input.is_some() {
+        match extract_real_input_result(data_dir, group_name, &names) {
+            Ok(Some(result)) => {
+                println!("  Real input: {}", format_stages(&algorithm_names, &result))
+            }
+            Ok(None) => eprintln!("No real input results found for '{group_name}'"),
+            Err(e) => eprintln!("Failed to extract real input results: {e}"),
+        }
+    }
+}
+
+/// Prints the parse and solve time of every algorithm at each size.
+///
+/// # Parameters
+/// * `algorithm_names` - Names of the staged algorithms
+/// * `results` - Results whose `times` hold each algorithm's parse time
+///   followed by its solve time
+pub fn print_stage_summary(algorithm_names: &[&str], results: &[BenchmarkResult]) {
+    println!("\n🧩 Parse vs solve:");
+    for result in results {
+        println!(
+            "  Size {}: {}",
+            result.size,
+            format_stages(algorithm_names, result)
+        );
+    }
+}
+
+/// Formats the parse and solve time of every algorithm at one point.
+///
+/// # Parameters
+/// * `algorithm_names` - Names of the staged algorithms
+/// * `result` - Result whose `times` hold each algorithm's parse time
+///   followed by its solve time
+fn format_stages(algorithm_names: &[&str], result: &BenchmarkResult) -> String {
+    let stages: Vec<String> = algorithm_names
+        .iter()
+        .zip(result.times.chunks_exact(2))
+        .map(|(name, times)| {
+            let (parse, solve) = (times[0], times[1]);
+            let share = 100.0 * parse / (parse + solve);
+            format!(
+                "{name} parse {:.2}μs + solve {:.2}μs ({share:.0}% parsing)",
+                parse / 1000.0,
+                solve / 1000.0
+            )
+        })
+        .collect();
+    stages.join(", ")
+}
+
+/// Extracts timing data from Criterion JSON files.
+///
+/// Reads mean execution times from Criterion's estimates.json files. Uses
+/// Criterion folder names to locate the correct benchmark data directories.
+/// The latest run in `new` is preferred over the `base` baseline, which only
+/// changes when promoted with [`promote_baseline`].
+///
+/// # Parameters
+/// * `base_path` - Base path where Criterion data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+/// * `algorithm_names` - Criterion folder names of the algorithms, baseline
+///   first
+/// * `sizes` - Array of input sizes that were tested
+///
+/// # Returns
+/// One result per size holding each algorithm's mean time, standard
+/// deviation and confidence interval in nanoseconds
+///
+/// # Errors
+///
+/// Returns an error if JSON files cannot be read or parsed.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::extract_criterion_results;
+/// // Function extracts timing data from Criterion JSON files
+/// // Example usage (requires actual benchmark data files):
+/// // let results = extract_criterion_results(
+/// //     "day01/data/criterion",
+/// //     "performance_comparison",
+/// //     &["hashmap", "naive"], // Criterion folder names
+/// //     &[1000, 5000, 10000]
+/// // )?;
+/// // results[0].times holds [hashmap_time_ns, naive_time_ns] for size 1000
+/// ```
+pub fn extract_criterion_results(
+    base_path: &str,
+    group_name: &str,
+    algorithm_names: &[&str],
+    sizes: &[usize],
+) -> Result<BenchmarkResults> {
+    sizes
+        .iter()
+        .map(|&size| {
+            extract_point(
+                base_path,
+                group_name,
+                algorithm_names,
+                &size.to_string(),
+                size,
+            )
+        })
+        .collect()
+}
+
+/// Records every algorithm's heap usage and hardware counters on each
+/// benchmarked input size.
+///
+/// Runs each algorithm twice per size outside of Criterion's timing loop,
+/// once per measurement so allocator bookkeeping does not skew the counters.
+/// Leaves `memory` empty when the tracking allocator is not installed and
+/// `counters` empty when hardware counters are unavailable.
+///
+/// # Parameters
+/// * `results` - Results to extend, one per size in `test_config.sizes`
+/// * `algorithms` - Algorithms in the order of each result's `times`
+/// * `test_config` - Test configuration used to regenerate the inputs
+fn measure_resource_usage<R, G>(
+    results: &mut BenchmarkResults,
+    algorithms: &[Algorithm<R>],
+    test_config: &TestConfig<G>,
+) where
+    G: Fn(usize) -> String,
+{
+    for result in results.iter_mut() {
+        let input = (test_config.generate_input)(result.size);
+        let memory: Option<Vec<MemoryStats>> = algorithms
+            .iter()
+            .map(|algorithm| measure(|| black_box((algorithm.function)(&input))).1)
+            .collect();
+        result.memory = memory.unwrap_or_default();
+
+        let counters: Option<Vec<HardwareCounters>> = algorithms
+            .iter()
+            .map(|algorithm| measure_counters(|| black_box((algorithm.function)(&input))).1)
+            .collect();
+        result.counters = counters.unwrap_or_default();
+    }
+}
+
+/// Reads the results of every algorithm at one benchmark point.
+///
+/// # Parameters
+/// * `base_path` - Base path where Criterion data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+/// * `algorithm_names` - Criterion folder names of the algorithms
+/// * `parameter` - Criterion folder name of the point (a size or
+///   [`REAL_INPUT_LABEL`])
+/// * `size` - Size recorded in the result
+///
+/// # Errors
+///
+/// Returns an error if an estimates file cannot be read or parsed.
+fn extract_point(
+    base_path: &str,
+    group_name: &str,
+    algorithm_names: &[&str],
+    parameter: &str,
+    size: usize,
+) -> Result<BenchmarkResult> {
+    let mut result = BenchmarkResult::new(size, Vec::new(), Vec::new());
+    let mut distributions = Some(Vec::new());
+    for algo_name in algorithm_names {
+        let benchmark_dir = format!("{base_path}/{group_name}/{algo_name}/{parameter}");
+        let estimate = read_criterion_estimate(&latest_estimate_path(&benchmark_dir))?;
+        result.times.push(estimate.mean);
+        result.std_devs.push(estimate.std_dev);
+        result.intervals.push(estimate.interval);
+
+        // Distributions are only kept if every algorithm has samples
+        let stats = read_criterion_samples(&latest_sample_path(&benchmark_dir))
+            .ok()
+            .and_then(|samples| SampleStats::from_samples(&samples));
+        if let (Some(distributions), Some(stats)) = (distributions.as_mut(), stats) {
+            distributions.push(stats);
+        } else {
+            distributions = None;
+        }
+    }
+    result.distributions = distributions.unwrap_or_default();
+    Ok(result)
+}
+
+/// Extracts the results of the real puzzle input benchmark point.
+///
+/// # Parameters
+/// * `base_path` - Base path where Criterion data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+/// * `algorithm_names` - Criterion folder names of the algorithms
+///
+/// # Returns
+/// The real input's result with `size` 0, since the point is labelled
+/// rather than sized, or `None` if the group was not run on the real input
+///
+/// # Errors
+///
+/// Returns an error if the real input results exist but cannot be read.
+pub fn extract_real_input_result(
+    base_path: &str,
+    group_name: &str,
+    algorithm_names: &[&str],
+) -> Result<Option<BenchmarkResult>> {
+    let Some(first) = algorithm_names.first() else {
+        return Ok(None);
+    };
+    if !Path::new(&format!(
+        "{base_path}/{group_name}/{first}/{REAL_INPUT_LABEL}"
+    ))
+    .is_dir()
+    {
+        return Ok(None);
+    }
+    extract_point(base_path, group_name, algorithm_names, REAL_INPUT_LABEL, 0).map(Some)
+}
+
+/// Prints the real puzzle input results on one labelled line.
+///
+/// # Parameters
+/// * `algorithm_names` - Names of the algorithms, in the order of `times`
+/// * `result` - Result of the real input benchmark point
+pub fn print_real_input_summary(algorithm_names: &[&str], result: &BenchmarkResult) {
+    let times: Vec<String> = algorithm_names
+        .iter()
+        .zip(&result.times)
+        .enumerate()
+        .map(
+            |(index, (name, time))| match result.speedup(index).filter(|_| index > 0) {
+                Some(speedup) => format!("{name} {:.2}μs ({speedup:.1}x)", time / 1000.0),
+                None => format!("{name} {:.2}μs", time / 1000.0),
+            },
+        )
+        .collect();
+    println!("Real input: {}", times.join(", "));
+}
+
+/// Returns the estimates file of a benchmark's latest run.
+///
+/// # Parameters
+/// * `benchmark_dir` - Criterion directory of one algorithm at one size
+///
+/// # Returns
+/// The `new` estimates path if that run exists, otherwise the `base` one
+fn latest_estimate_path(benchmark_dir: &str) -> String {
+    latest_run_file(benchmark_dir, "estimates.json")
+}
+
+/// Returns the raw samples file of a benchmark's latest run.
+///
+/// # Parameters
+/// * `benchmark_dir` - Criterion directory of one algorithm at one size
+///
+/// # Returns
+/// The `new` samples path if that run exists, otherwise the `base` one
+pub fn latest_sample_path(benchmark_dir: &str) -> String {
+    latest_run_file(benchmark_dir, "sample.json")
+}
+
+/// Returns a file of the `new` run if present, otherwise of the `base` run.
+fn latest_run_file(benchmark_dir: &str, file: &str) -> String {
+    let new_path = format!("{benchmark_dir}/new/{file}");
+    if Path::new(&new_path).is_file() {
+        new_path
+    } else {
+        format!("{benchmark_dir}/base/{file}")
+    }
+}
+
+/// Reads per-iteration times from a Criterion sample.json file.
+///
+/// Criterion stores each sample as a total time over a number of
+/// iterations; dividing the two gives the time of one iteration.
+///
+/// # Parameters
+/// * `path` - Path to the Criterion sample.json file
+///
+/// # Returns
+/// One time in nanoseconds per sample, in measurement order
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, the JSON cannot be parsed or
+/// the iteration and time lists differ in length.
+pub fn read_criterion_samples(path: &str) -> Result<Vec<f64>> {
+    let json_str =
+        std::fs::read_to_string(path).with_context(|| format!("Failed to read {path}"))?;
+    let json: Value = serde_json::from_str(&json_str)?;
+    let numbers = |key: &str| -> Result<Vec<f64>> {
+        json[key]
+            .as_array()
+            .with_context(|| format!("Missing '{key}' in {path}"))?
+            .iter()
+            .map(|value| {
+                value
+                    .as_f64()
+                    .with_context(|| format!("Invalid '{key}' entry in {path}"))
+            })
+            .collect()
+    };
+
+    let (iterations, times) = (numbers("iters")?, numbers("times")?);
+    if iterations.len() != times.len() {
+        bail!(
+            "{path} has {} iteration counts but {} times",
+            iterations.len(),
+            times.len()
+        );
+    }
+    Ok(times
+        .iter()
+        .zip(&iterations)
+        .map(|(time, iterations)| time / iterations)
+        .collect())
+}
+
+/// Promotes the latest results of a group to its comparison baseline.
+///
+/// Copies every `<algorithm>/<size>/new` directory over the matching `base`
+/// directory, so later runs are compared against the promoted results.
+///
+/// # Parameters
+/// * `data_dir` - Directory where benchmark data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+///
+/// # Returns
+/// Number of benchmarks whose baseline was replaced
+///
+/// # Errors
+///
+/// Returns an error if the group cannot be read or a file cannot be copied.
+pub fn promote_baseline(data_dir: &str, group_name: &str) -> Result<usize> {
+    let group_path = format!("{data_dir}/{group_name}");
+    let mut promoted = 0;
+
+    for algorithm in subdirectory_names(&group_path)? {
+        for size in subdirectory_names(&format!("{group_path}/{algorithm}"))? {
+            let benchmark_dir = format!("{group_path}/{algorithm}/{size}");
+            let new_dir = Path::new(&benchmark_dir).join("new");
+            if !new_dir.is_dir() {
+                continue;
+            }
+
+            let base_dir = Path::new(&benchmark_dir).join("base");
+            std::fs::create_dir_all(&base_dir)
+                .with_context(|| format!("Failed to create {}", base_dir.display()))?;
+            for entry in std::fs::read_dir(&new_dir)? {
+                let entry = entry?;
+                if entry.file_type()?.is_file() {
+                    let target = base_dir.join(entry.file_name());
+                    std::fs::copy(entry.path(), &target)
+                        .with_context(|| format!("Failed to write {}", target.display()))?;
+                }
+            }
+            promoted += 1;
+        }
+    }
+
+    Ok(promoted)
+}
+
+/// Timing statistics of one benchmark read from Criterion, in nanoseconds.
+struct CriterionEstimate {
+    mean: f64,
+    std_dev: f64,
+    interval: ConfidenceInterval,
+}
+
+/// Reads mean execution time from Criterion estimates.json file.
+///
+/// Parses the JSON structure generated by Criterion benchmarking to extract
+/// the point estimates of mean execution time and its standard deviation,
+/// plus the mean's confidence interval and standard error. The mean is the
+/// primary timing metric used for performance comparisons.
+///
+/// # Parameters
+/// * `path` - Path to the Criterion estimates.json file
+///
+/// # Returns
+/// The benchmark's timing statistics in nanoseconds
+///
+/// # Errors
+///
+/// Returns an error if file cannot be read or JSON cannot be parsed.
+fn read_criterion_estimate(path: &str) -> Result<CriterionEstimate> {
+    let json_str = std::fs::read_to_string(path)?;
+    let json: Value = serde_json::from_str(&json_str)?;
+    let number = |value: &Value, what: &str| {
+        value
+            .as_f64()
+            .with_context(|| format!("Failed to parse {what} in {path}"))
+    };
+
+    // Extract estimates (in nanoseconds)
+    let mean = &json["mean"];
+    Ok(CriterionEstimate {
+        mean: number(&mean["point_estimate"], "mean estimate")?,
+        std_dev: number(&json["std_dev"]["point_estimate"], "standard deviation")?,
+        interval: ConfidenceInterval {
+            lower: number(&mean["confidence_interval"]["lower_bound"], "lower bound")?,
+            upper: number(&mean["confidence_interval"]["upper_bound"], "upper bound")?,
+            standard_error: number(&mean["standard_error"], "standard error")?,
+        },
+    })
+}
+
+/// Lists the benchmark groups stored in a Criterion output directory.
+///
+/// Every subdirectory except Criterion's own `report` folder is a group.
+///
+/// # Parameters
+/// * `base_path` - Base path where Criterion data is stored
+///
+/// # Returns
+/// Sorted group names
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be read.
+pub fn list_criterion_groups(base_path: &str) -> Result<Vec<String>> {
+    subdirectory_names(base_path)
+}
+
+/// Discovers the algorithms and input sizes recorded for a benchmark group.
+///
+/// Walks the `<group>/<algorithm>/<size>` folder layout that Criterion
+/// creates, so results can be summarized without knowing how the benchmark
+/// was configured.
+///
+/// # Parameters
+/// * `base_path` - Base path where Criterion data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+///
+/// # Returns
+/// Tuple of (sorted algorithm folder names, sorted input sizes)
+///
+/// # Errors
+///
+/// Returns an error if the group directory cannot be read.
+pub fn discover_criterion_group(
+    base_path: &str,
+    group_name: &str,
+) -> Result<(Vec<String>, Vec<usize>)> {
+    let group_path = format!("{base_path}/{group_name}");
+    let algorithms = subdirectory_names(&group_path)?;
+
+    let mut sizes = Vec::new();
+    for algorithm in &algorithms {
+        for size in subdirectory_names(&format!("{group_path}/{algorithm}"))? {
+            if let Ok(size) = size.parse::<usize>() {
+                sizes.push(size);
+            }
+        }
+    }
+    sizes.sort_unstable();
+    sizes.dedup();
+
+    Ok((algorithms, sizes))
+}
+
+/// Prints a summary of every algorithm and size recorded for a group.
+///
+/// Algorithms are listed in folder-name order, so the speedups are relative
+/// to the alphabetically first algorithm.
+///
+/// # Parameters
+/// * `base_path` - Base path where Criterion data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+///
+/// # Errors
+///
+/// Returns an error if the group cannot be discovered or its estimates
+/// cannot be read.
+pub fn print_group_summary(base_path: &str, group_name: &str) -> Result<()> {
+    let (algorithms, sizes) = discover_criterion_group(base_path, group_name)?;
+    println!("📊 {group_name}: {}", algorithms.join(" vs "));
+
+    let names: Vec<&str> = algorithms.iter().map(String::as_str).collect();
+    let results = extract_criterion_results(base_path, group_name, &names, &sizes)?;
+    print_benchmark_summary(&names, &results);
+    print_distribution_summary(&names, &results);
+    print_complexity_fits(&names, &results);
+    if let Some(real) = extract_real_input_result(base_path, group_name, &names)? {
+        print_real_input_summary(&names, &real);
+    }
+    Ok(())
+}
+
+/// Name of the plot metadata file stored inside each group directory.
+const PLOT_SPEC_FILE: &str = "plot.json";
+
+/// Saves the display configuration of a benchmark plot next to its data.
+///
+/// Bench binaries own their plot titles and legend names; persisting them
+/// lets [`regenerate_plot`] redraw charts after styling changes without
+/// re-running the benchmarks.
+///
+/// # Parameters
+/// * `data_dir` - Directory where benchmark data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+/// * `algorithm_names` - Criterion folder names of the plotted algorithms
+/// * `plot_config` - Display configuration for plot generation
+/// * `sizes` - Input sizes that were benchmarked
+///
+/// # Errors
+///
+/// Returns an error if the metadata file cannot be written.
+fn write_plot_spec(
+    data_dir: &str,
+    group_name: &str,
+    algorithm_names: &[&str],
+    plot_config: &PlotConfig,
+    sizes: &[usize],
+) -> Result<()> {
+    let labels = plot_config.algorithm_names;
+    let spec = serde_json::json!({
+        "filename": plot_config.filename,
+        "title": plot_config.title,
+        "x_axis_label": plot_config.x_axis_label,
+        "metric": plot_config.metric.key(),
+        "x_scale": plot_config.x_scale.key(),
+        "y_scale": plot_config.y_scale.key(),
+        "x_range": plot_config.x_range.map(|(start, end)| [start, end]),
+        "y_range": plot_config.y_range.map(|(start, end)| [start, end]),
+        "speedup_filename": plot_config.speedup_filename,
+        "distribution_filename": plot_config.distribution_filename,
+        "fit_overlay": plot_config.fit_overlay,
+        "annotation": plot_config.annotation.key(),
+        "theme": plot_config.theme.to_json(),
+        "part": plot_config.part,
+        "algorithms": algorithm_names
+            .iter()
+            .zip(labels)
+            .map(|(name, label)| serde_json::json!({ "name": name, "label": label }))
+            .collect::<Vec<_>>(),
+        "sizes": sizes,
+    });
+    let path = format!("{data_dir}/{group_name}/{PLOT_SPEC_FILE}");
+    std::fs::write(&path, serde_json::to_string_pretty(&spec)?)
+        .with_context(|| format!("Failed to write {path}"))
+}
+
+/// Name of the exported results file stored inside each group directory.
+pub const RESULTS_FILE: &str = "results.json";
+
+/// Version of the results document layout, bumped on incompatible changes.
+pub const RESULTS_SCHEMA_VERSION: u32 = 1;
+
+/// Builds the machine-readable results document of a benchmark run.
+///
+/// The document has this layout (times in nanoseconds, `speedup` relative to
+/// the first algorithm, `git_commit` null outside a git checkout; `memory`
+/// and `counters` are null when they were not measured):
+///
+/// ```text
+/// {
+///   "schema_version": 1,
+///   "group": "criterion",
+///   "timestamp": 1733011200,
+///   "git_commit": "9a5d0a7...",
+///   "algorithms": ["hashmap", "naive"],
+///   "sizes": [500, 1000],
+///   "results": [
+///     {
+///       "size": 500,
+///       "measurements": [
+///         {
+///           "algorithm": "hashmap", "mean_ns": 2100.0, "std_dev_ns": 35.2, "speedup": 1.0,
+///           "speedup_error": 0.02, "significant": false,
+///           "interval_ns": { "lower": 2080.1, "upper": 2121.4, "standard_error": 10.5 },
+///           "memory": { "peak_bytes": 65536, "total_bytes": 98304, "allocations": 12 },
+///           "counters": {
+///             "instructions": 41000, "cycles": 9800, "branch_misses": 120, "cache_misses": 8
+///           }
+///         },
+///         { "algorithm": "naive", "mean_ns": 98000.0, "std_dev_ns": 410.7, "speedup": 46.7,
+///           "speedup_error": 0.3, "significant": true,
+///           "interval_ns": { "lower": 97300.2, "upper": 98702.9, "standard_error": 357.8 },
+///           "memory": null, "counters": null }
+///       ]
+///     }
+///   ]
+/// }
+/// ```
+///
+/// # Parameters
+/// * `group_name` - Benchmark group name used by Criterion
+/// * `algorithm_names` - Criterion folder names, in the order of each
+///   result's `times`
+/// * `results` - Benchmark results to export
+/// * `timestamp` - Seconds since the Unix epoch when the run finished
+/// * `git_commit` - Commit hash the benchmarks were built from, if known
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::{results_document, BenchmarkResult};
+/// let results = vec![BenchmarkResult::new(100, vec![200.0, 500.0], vec![4.0, 9.0])];
+/// let document = results_document("demo", &["fast", "slow"], &results, 0, None);
+/// assert_eq!(document["results"][0]["measurements"][1]["speedup"], 2.5);
+/// ```
+pub fn results_document(
+    group_name: &str,
+    algorithm_names: &[&str],
+    results: &BenchmarkResults,
+    timestamp: u64,
+    git_commit: Option<&str>,
+) -> Value {
+    let entries: Vec<Value> = results
+        .iter()
+        .map(|result| {
+            let measurements: Vec<Value> = algorithm_names
+                .iter()
+                .zip(result.times.iter().zip(&result.std_devs))
+                .enumerate()
+                .map(|(index, (name, (mean, std_dev)))| {
+                    serde_json::json!({
+                        "algorithm": name,
+                        "mean_ns": mean,
+                        "std_dev_ns": std_dev,
+                        "speedup": result.speedup(index),
+                        "speedup_error": result.speedup_error(index),
+                        "significant": result.is_significant(index),
+                        "interval_ns": result.intervals.get(index).map(|interval| serde_json::json!({
+                            "lower": interval.lower,
+                            "upper": interval.upper,
+                            "standard_error": interval.standard_error,
+                        })),
+                        "memory": result.memory.get(index).map(|memory| serde_json::json!({
+                            "peak_bytes": memory.peak_bytes,
+                            "total_bytes": memory.total_bytes,
+                            "allocations": memory.allocations,
+                        })),
+                        "counters": result.counters.get(index).map(|counters| serde_json::json!({
+                            "instructions": counters.instructions,
+                            "cycles": counters.cycles,
+                            "branch_misses": counters.branch_misses,
+                            "cache_misses": counters.cache_misses,
+                        })),
+                    })
+                })
+                .collect();
+            serde_json::json!({ "size": result.size, "measurements": measurements })
+        })
+        .collect();
+
+    serde_json::json!({
+        "schema_version": RESULTS_SCHEMA_VERSION,
+        "group": group_name,
+        "timestamp": timestamp,
+        "git_commit": git_commit,
+        "algorithms": algorithm_names,
+        "sizes": results.iter().map(|result| result.size).collect::<Vec<_>>(),
+        "results": entries,
+    })
+}
+
+/// Writes the results document of a benchmark run next to its data.
+///
+/// Stamps the document with the current time and the checked-out git
+/// commit; see [`results_document`] for the layout.
+///
+/// # Parameters
+/// * `data_dir` - Directory where benchmark data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+/// * `algorithm_names` - Criterion folder names, in the order of each
+///   result's `times`
+/// * `results` - Benchmark results to export
+///
+/// # Returns
+/// Path of the written JSON file
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+pub fn write_results_json(
+    data_dir: &str,
+    group_name: &str,
+    algorithm_names: &[&str],
+    results: &BenchmarkResults,
+) -> Result<String> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let commit = current_git_commit();
+    let document = results_document(
+        group_name,
+        algorithm_names,
+        results,
+        timestamp,
+        commit.as_deref(),
+    );
+
+    let path = format!("{data_dir}/{group_name}/{RESULTS_FILE}");
+    std::fs::write(&path, serde_json::to_string_pretty(&document)?)
+        .with_context(|| format!("Failed to write {path}"))?;
+    Ok(path)
+}
+
+/// Name of the stored results document that regressions are checked against.
+pub const BASELINE_RESULTS_FILE: &str = "baseline.json";
+
+/// A benchmark that slowed down beyond the allowed threshold.
+///
+/// # Attributes
+/// * `algorithm` - Criterion folder name of the algorithm
+/// * `size` - Input size that slowed down
+/// * `baseline_ns` - Mean time in the baseline document
+/// * `current_ns` - Mean time in the current document
+/// * `change_pct` - Slowdown in percent of the baseline time
+#[derive(Debug, Clone, PartialEq)]
+pub struct Regression {
+    pub algorithm: String,
+    pub size: usize,
+    pub baseline_ns: f64,
+    pub current_ns: f64,
+    pub change_pct: f64,
+}
+
+/// Compares two results documents and reports every slowdown.
+///
+/// Both documents use the layout of [`results_document`]. Algorithm and size
+/// combinations present in only one document are ignored, so adding a new
+/// algorithm or size never fails the check. Baseline points with a zero
+/// time are ignored too, as [`compare_runs`] cannot compare against them.
+///
+/// # Parameters
+/// * `current` - Results document of the run being checked
+/// * `baseline` - Results document to compare against
+/// * `threshold_pct` - Largest allowed slowdown in percent (e.g. `10.0`)
+///
+/// # Returns
+/// Regressions in the order they appear in `current`; empty if none
+///
+/// # Errors
+///
+/// Returns an error if either document is malformed or uses a different
+/// schema version.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::{check_regression, results_document, BenchmarkResult};
+/// let run = |mean: f64| {
+///     let results = vec![BenchmarkResult::new(100, vec![mean], vec![1.0])];
+///     results_document("demo", &["solver"], &results, 0, None)
+/// };
+/// assert!(check_regression(&run(105.0), &run(100.0), 10.0).unwrap().is_empty());
+/// let regressions = check_regression(&run(150.0), &run(100.0), 10.0).unwrap();
+/// assert_eq!(regressions[0].change_pct, 50.0);
+/// ```
+pub fn check_regression(
+    current: &Value,
+    baseline: &Value,
+    threshold_pct: f64,
+) -> Result<Vec<Regression>> {
+    Ok(compare_runs(baseline, current)?
+        .into_iter()
+        .filter(|delta| delta.change_pct > threshold_pct)
+        .map(|delta| Regression {
+            algorithm: delta.algorithm,
+            size: delta.size,
+            baseline_ns: delta.old_ns,
+            current_ns: delta.new_ns,
+            change_pct: delta.change_pct,
+        })
+        .collect())
+}
+
+/// Change of one benchmark point between two runs.
+///
+/// # Attributes
+/// * `group` - Benchmark group name
+/// * `algorithm` - Criterion folder name of the algorithm
+/// * `size` - Input size
+/// * `old_ns` - Mean time in the old run
+/// * `new_ns` - Mean time in the new run
+/// * `change_pct` - Change in percent of the old time; positive is slower
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunDelta {
+    pub group: String,
+    pub algorithm: String,
+    pub size: usize,
+    pub old_ns: f64,
+    pub new_ns: f64,
+    pub change_pct: f64,
+}
+
+/// Aligns two exported result sets and computes the change of every point.
+///
+/// Each set is either one results document (see [`results_document`]) or an
+/// array of them, e.g. one per group. Points are matched by group,
+/// algorithm and size; points present in only one set are skipped, as are
+/// points whose old time is zero or not finite, since no percentage change
+/// can be computed against them.
+///
+/// # Parameters
+/// * `old` - Result set of the earlier run
+/// * `new` - Result set of the later run
+///
+/// # Returns
+/// One delta per matched point, in the order of `new`
+///
+/// # Errors
+///
+/// Returns an error if either set is malformed or uses a different schema
+/// version.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::{compare_runs, results_document, BenchmarkResult};
+/// let run = |mean: f64| {
+///     let results = vec![BenchmarkResult::new(100, vec![mean], vec![1.0])];
+///     results_document("demo", &["solver"], &results, 0, None)
+/// };
+/// let deltas = compare_runs(&run(200.0), &run(150.0)).unwrap();
+/// assert_eq!(deltas[0].change_pct, -25.0);
+///
+/// // An empty baseline point is not comparable
+/// assert!(compare_runs(&run(0.0), &run(150.0)).unwrap().is_empty());
+/// ```
+pub fn compare_runs(old: &Value, new: &Value) -> Result<Vec<RunDelta>> {
+    let old_means: FxHashMap<PointKey, f64> = result_set_means(old)?.into_iter().collect();
+
+    Ok(result_set_means(new)?
+        .into_iter()
+        .filter_map(|((group, algorithm, size), new_ns)| {
+            let old_ns = *old_means.get(&(group.clone(), algorithm.clone(), size))?;
+            if !(old_ns.is_finite() && old_ns > 0.0) {
+                return None;
+            }
+            Some(RunDelta {
+                group,
+                algorithm,
+                size,
+                old_ns,
+                new_ns,
+                change_pct: (new_ns - old_ns) / old_ns * 100.0,
+            })
+        })
+        .collect())
+}
+
+/// Loads an exported result set from disk.
+///
+/// # Parameters
+/// * `path` - A results JSON file (one document or an array of them), or a
+///   benchmark data directory whose groups each hold a results file
+///
+/// # Returns
+/// The result set as a JSON array of documents
+///
+/// # Errors
+///
+/// Returns an error if a file cannot be read or parsed.
+pub fn load_result_set(path: &str) -> Result<Value> {
+    let read = |file: &str| -> Result<Value> {
+        let json_str =
+            std::fs::read_to_string(file).with_context(|| format!("Failed to read {file}"))?;
+        serde_json::from_str(&json_str).with_context(|| format!("Invalid JSON in {file}"))
+    };
+
+    if !Path::new(path).is_dir() {
+        return match read(path)? {
+            Value::Array(documents) => Ok(Value::Array(documents)),
+            document => Ok(Value::Array(vec![document])),
+        };
+    }
+    let mut documents = Vec::new();
+    for group in subdirectory_names(path)? {
+        let file = format!("{path}/{group}/{RESULTS_FILE}");
+        if Path::new(&file).is_file() {
+            documents.push(read(&file)?);
+        }
+    }
+    Ok(Value::Array(documents))
+}
+
+/// Identifies a benchmark point across runs: (group, algorithm, size).
+type PointKey = (String, String, usize);
+
+/// Extracts every mean time from one results document or an array of them.
+///
+/// # Returns
+/// `((group, algorithm, size), mean_ns)` pairs in set order
+///
+/// # Errors
+///
+/// Returns an error if a document is malformed.
+fn result_set_means(set: &Value) -> Result<Vec<(PointKey, f64)>> {
+    let documents = match set {
+        Value::Array(documents) => documents.iter().collect(),
+        document => vec![document],
+    };
+    let mut means = Vec::new();
+    for document in documents {
+        let group = document["group"]
+            .as_str()
+            .context("Missing 'group' in results document")?;
+        for ((algorithm, size), mean_ns) in document_means(document)? {
+            means.push(((group.to_string(), algorithm, size), mean_ns));
+        }
+    }
+    Ok(means)
+}
+
+/// Checks a group's exported results against its stored baseline.
+///
+/// # Parameters
+/// * `data_dir` - Directory where benchmark data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+/// * `threshold_pct` - Largest allowed slowdown in percent
+///
+/// # Returns
+/// Regressions found, see [`check_regression`]
 ///
 /// # Errors
 ///
-/// Returns an error if JSON files cannot be read or parsed.
+/// Returns an error if either document is missing or malformed.
+pub fn check_group_regression(
+    data_dir: &str,
+    group_name: &str,
+    threshold_pct: f64,
+) -> Result<Vec<Regression>> {
+    let read = |file: &str| -> Result<Value> {
+        let path = format!("{data_dir}/{group_name}/{file}");
+        let json_str =
+            std::fs::read_to_string(&path).with_context(|| format!("Failed to read {path}"))?;
+        serde_json::from_str(&json_str).with_context(|| format!("Invalid JSON in {path}"))
+    };
+    check_regression(
+        &read(RESULTS_FILE)?,
+        &read(BASELINE_RESULTS_FILE)?,
+        threshold_pct,
+    )
+}
+
+/// Stores a group's exported results as the baseline for regression checks.
+///
+/// # Parameters
+/// * `data_dir` - Directory where benchmark data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+///
+/// # Returns
+/// Path of the stored baseline document
+///
+/// # Errors
+///
+/// Returns an error if the results document cannot be copied.
+pub fn save_results_baseline(data_dir: &str, group_name: &str) -> Result<String> {
+    let source = format!("{data_dir}/{group_name}/{RESULTS_FILE}");
+    let target = format!("{data_dir}/{group_name}/{BASELINE_RESULTS_FILE}");
+    std::fs::copy(&source, &target)
+        .with_context(|| format!("Failed to copy {source} to {target}"))?;
+    Ok(target)
+}
+
+/// Extracts every mean time from a results document.
+///
+/// # Returns
+/// `((algorithm, size), mean_ns)` pairs in document order
+///
+/// # Errors
+///
+/// Returns an error if the schema version differs or a field is missing.
+fn document_means(document: &Value) -> Result<Vec<((String, usize), f64)>> {
+    let version = document["schema_version"].as_u64();
+    if version != Some(u64::from(RESULTS_SCHEMA_VERSION)) {
+        bail!("Unsupported results schema version {version:?}, expected {RESULTS_SCHEMA_VERSION}");
+    }
+
+    let mut means = Vec::new();
+    for entry in document["results"]
+        .as_array()
+        .context("Missing 'results' in results document")?
+    {
+        let size = entry["size"]
+            .as_u64()
+            .context("Missing 'size' in results entry")?;
+        for measurement in entry["measurements"]
+            .as_array()
+            .context("Missing 'measurements' in results entry")?
+        {
+            let algorithm = measurement["algorithm"]
+                .as_str()
+                .context("Missing 'algorithm' in measurement")?;
+            let mean_ns = measurement["mean_ns"]
+                .as_f64()
+                .context("Missing 'mean_ns' in measurement")?;
+            means.push(((algorithm.to_string(), size as usize), mean_ns));
+        }
+    }
+    Ok(means)
+}
+
+/// Renders benchmark results as a GitHub-flavored Markdown report.
+///
+/// The report holds a heading, an image link to the plot and a table with
+/// one row per size and one column per algorithm. Cells show the mean and
+/// standard deviation in microseconds, plus the factor relative to the first
+/// algorithm for every other column.
+///
+/// # Parameters
+/// * `plot_config` - Display configuration; supplies the heading, column
+///   names and plot filename
+/// * `plot_link` - Path of the SVG plot relative to the report
+/// * `results` - Benchmark results to render
+///
+/// # Returns
+/// Markdown ready to paste into a PR description
 ///
 /// # Examples
 ///
 /// ```
-/// # use shared::benchmarking::extract_criterion_results;
-/// // Function extracts timing data from Criterion JSON files
-/// // Example usage (requires actual benchmark data files):
-/// // let results = extract_criterion_results(
-/// //     "day01/data/criterion",
-/// //     "performance_comparison",
-/// //     "naive",        // Criterion folder name
-/// //     "hashmap",      // Criterion folder name
-/// //     &[1000, 5000, 10000]
-/// // )?;
-/// // results contains (size, naive_time_ns, hashmap_time_ns, speedup) tuples
+/// # use shared::benchmarking::{render_markdown_report, AxisScale, BenchmarkResult, PlotConfig};
+/// let plot_config = PlotConfig {
+///     filename: "demo.svg",
+///     title: "Demo",
+///     algorithm_names: &["Fast", "Slow"],
+///     x_axis_label: "Input Size (N)",
+///     y_scale: AxisScale::Log,
+///     ..PlotConfig::default()
+/// };
+/// let results = vec![BenchmarkResult::new(100, vec![2000.0, 5000.0], vec![40.0, 90.0])];
+/// let report = render_markdown_report(&plot_config, "demo.svg", &results);
+/// assert!(report.contains("| 100 | 2.00 ± 0.04 μs | 5.00 ± 0.09 μs (2.5x) |"));
 /// ```
-pub fn extract_criterion_results(
-    base_path: &str,
+pub fn render_markdown_report(
+    plot_config: &PlotConfig,
+    plot_link: &str,
+    results: &BenchmarkResults,
+) -> String {
+    let names = plot_config.algorithm_names;
+    let mut report = format!(
+        "## {}\n\n![{}]({plot_link})\n\n",
+        plot_config.title, plot_config.title
+    );
+
+    // Writing to a String cannot fail
+    let _ = writeln!(
+        report,
+        "| {} | {} |",
+        plot_config.x_axis_label,
+        names.join(" | ")
+    );
+    let _ = writeln!(report, "|---|{}", "---|".repeat(names.len()));
+    for result in results {
+        let cells: Vec<String> = result
+            .times
+            .iter()
+            .zip(&result.std_devs)
+            .enumerate()
+            .map(|(index, (mean, std_dev))| {
+                let cell = format!("{:.2} ± {:.2} μs", mean / 1000.0, std_dev / 1000.0);
+                match result.speedup(index).filter(|_| index > 0) {
+                    Some(speedup) => format!("{cell} ({speedup:.1}x)"),
+                    None => cell,
+                }
+            })
+            .collect();
+        let _ = writeln!(report, "| {} | {} |", result.size, cells.join(" | "));
+    }
+    report
+}
+
+/// Writes the Markdown report of a benchmark group next to its plot.
+///
+/// The report is saved as `<group>.md` in the data directory so its image
+/// link to the plot stays relative.
+///
+/// # Parameters
+/// * `data_dir` - Directory where benchmark data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+/// * `plot_config` - Display configuration for the report
+/// * `results` - Benchmark results to render
+///
+/// # Returns
+/// Path of the written Markdown file
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+pub fn write_markdown_report(
+    data_dir: &str,
     group_name: &str,
-    algo1_name: &str,
-    algo2_name: &str,
-    sizes: &[usize],
-) -> Result<BenchmarkResults> {
-    let mut results = Vec::new();
+    plot_config: &PlotConfig,
+    results: &BenchmarkResults,
+) -> Result<String> {
+    let path = format!("{data_dir}/{group_name}.md");
+    let report = render_markdown_report(plot_config, plot_config.filename, results);
+    std::fs::write(&path, report).with_context(|| format!("Failed to write {path}"))?;
+    Ok(path)
+}
 
-    for &size in sizes {
-        let mut times: [f64; 2] = [0.0, 0.0];
-        for (algo_name, time) in [algo1_name, algo2_name].iter().zip(&mut times) {
-            // TODO: Consider reading from 'new' directory when it exists (latest results)
-            // and providing manual baseline promotion functionality for better comparison workflow
-            let path = format!("{base_path}/{group_name}/{algo_name}/{size}/base/estimates.json");
-            let estimate = read_criterion_estimate(&path)?;
-            *time = estimate;
-        }
-        let [algo1_time, algo2_time] = times;
-        results.push((size, algo1_time, algo2_time, algo2_time / algo1_time));
+/// Returns the hash of the checked-out git commit, if git is available.
+fn current_git_commit() -> Option<String> {
+    let output = process::Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let commit = String::from_utf8(output.stdout).ok()?;
+    Some(commit.trim().to_string())
+}
+
+/// Redraws a group's plot from existing Criterion data.
+///
+/// Uses the plot metadata saved by [`process_benchmark_results`]. Groups
+/// without metadata fall back to the discovered folder names, the group
+/// name as title and `<group>.svg` as filename.
+///
+/// # Parameters
+/// * `data_dir` - Directory where benchmark data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+///
+/// # Returns
+/// Path of the regenerated SVG plot
+///
+/// # Errors
+///
+/// Returns an error if the metadata is malformed, the group holds no
+/// algorithms, or reading the data or drawing the plot fails.
+pub fn regenerate_plot(data_dir: &str, group_name: &str) -> Result<String> {
+    let spec = read_plot_spec(data_dir, group_name)?;
+    if spec.algorithms.is_empty() {
+        bail!("Group '{group_name}' has no algorithms to plot");
     }
 
-    Ok(results)
+    let (names, labels): (Vec<&str>, Vec<&str>) = spec
+        .algorithms
+        .iter()
+        .map(|(name, label)| (name.as_str(), label.as_str()))
+        .unzip();
+    let results = extract_criterion_results(data_dir, group_name, &names, &spec.sizes)?;
+    let plot_path = format!("{data_dir}/{}", spec.filename);
+    crate::plotting::create_algorithm_plot(
+        &plot_path,
+        &spec.theme,
+        &spec.chart(&labels, &results),
+    )?;
+    if let Some(filename) = &spec.speedup_filename {
+        crate::plotting::create_speedup_plot(
+            &format!("{data_dir}/{filename}"),
+            &spec.title,
+            &labels,
+            &spec.x_axis_label,
+            spec.x_scale,
+            &spec.theme,
+            &results,
+        )?;
+    }
+    if let Some(filename) = &spec.distribution_filename {
+        crate::plotting::create_distribution_plot(
+            &format!("{data_dir}/{filename}"),
+            &spec.title,
+            &labels,
+            &spec.x_axis_label,
+            &spec.theme,
+            &results,
+        )?;
+    }
+    Ok(plot_path)
 }
 
-/// Reads mean execution time from Criterion estimates.json file.
+/// Draws several groups' plots side by side in one image.
 ///
-/// Parses the JSON structure generated by Criterion benchmarking to extract
-/// the point estimate of mean execution time. This provides the primary timing
-/// metric used for performance comparisons.
+/// Each group is charted from its stored plot metadata and Criterion data,
+/// like [`regenerate_plot`], and the first group's theme styles the whole
+/// image.
 ///
 /// # Parameters
-/// * `path` - Path to the Criterion estimates.json file
+/// * `data_dir` - Directory where benchmark data is stored
+/// * `filename` - Output filename, relative to `data_dir`
+/// * `title` - Title above all panels
+/// * `group_names` - Benchmark groups to chart, left to right
 ///
 /// # Returns
-/// Mean execution time in nanoseconds
+/// Path of the written plot
 ///
 /// # Errors
 ///
-/// Returns an error if file cannot be read or JSON cannot be parsed.
-fn read_criterion_estimate(path: &str) -> Result<f64> {
-    let json_str = std::fs::read_to_string(path)?;
+/// Returns an error if there are no groups, a group's metadata is
+/// malformed, or reading the data or drawing the plot fails.
+pub fn write_panel_plot(
+    data_dir: &str,
+    filename: &str,
+    title: &str,
+    group_names: &[&str],
+) -> Result<String> {
+    let specs = group_names
+        .iter()
+        .map(|group_name| read_plot_spec(data_dir, group_name))
+        .collect::<Result<Vec<_>>>()?;
+    let series: Vec<(Vec<&str>, Vec<&str>)> = specs
+        .iter()
+        .map(|spec| {
+            spec.algorithms
+                .iter()
+                .map(|(name, label)| (name.as_str(), label.as_str()))
+                .unzip()
+        })
+        .collect();
+    let results = group_names
+        .iter()
+        .zip(&specs)
+        .zip(&series)
+        .map(|((group_name, spec), (names, _))| {
+            extract_criterion_results(data_dir, group_name, names, &spec.sizes)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let panels: Vec<_> = specs
+        .iter()
+        .zip(&series)
+        .zip(&results)
+        .map(|((spec, (_, labels)), results)| spec.chart(labels, results))
+        .collect();
+    let theme = specs
+        .first()
+        .map(|spec| spec.theme.clone())
+        .unwrap_or_default();
+    let plot_path = format!("{data_dir}/{filename}");
+    crate::plotting::create_panel_plot(&plot_path, title, &theme, panels.len(), &panels)?;
+    Ok(plot_path)
+}
+
+/// Plot metadata loaded from a group's `plot.json` (or discovered).
+///
+/// # Attributes
+/// * `filename` - Output SVG filename
+/// * `title` - Plot title
+/// * `x_axis_label` - Label for the x-axis
+/// * `metric` - Quantity plotted on the y-axis
+/// * `x_scale` - Scale of the x-axis
+/// * `y_scale` - Scale of the y-axis
+/// * `x_range` - Explicit x-axis range, if one was configured
+/// * `y_range` - Explicit y-axis range, if one was configured
+/// * `speedup_filename` - Speedup chart filename, if one is drawn
+/// * `distribution_filename` - Distribution chart filename, if one is drawn
+/// * `fit_overlay` - Whether fitted power laws are overlaid
+/// * `annotation` - Labels drawn next to the data points
+/// * `theme` - Dimensions, colors and fonts of the charts
+/// * `algorithms` - (Criterion folder name, legend label) pairs
+/// * `sizes` - Benchmarked input sizes
+struct PlotSpec {
+    filename: String,
+    title: String,
+    x_axis_label: String,
+    metric: PlotMetric,
+    x_scale: AxisScale,
+    y_scale: AxisScale,
+    x_range: Option<(f64, f64)>,
+    y_range: Option<(f64, f64)>,
+    speedup_filename: Option<String>,
+    distribution_filename: Option<String>,
+    fit_overlay: bool,
+    annotation: PointAnnotation,
+    theme: PlotTheme,
+    algorithms: Vec<(String, String)>,
+    sizes: Vec<usize>,
+}
+
+impl PlotSpec {
+    /// Describes the chart of `results` under the stored display settings.
+    fn chart<'a>(
+        &'a self,
+        series_names: &'a [&'a str],
+        results: &'a [BenchmarkResult],
+    ) -> AlgorithmChart<'a> {
+        AlgorithmChart {
+            title: &self.title,
+            series_names,
+            x_axis_label: &self.x_axis_label,
+            metric: self.metric,
+            x_scale: self.x_scale,
+            y_scale: self.y_scale,
+            x_range: self.x_range,
+            y_range: self.y_range,
+            fit_overlay: self.fit_overlay,
+            annotation: self.annotation,
+            results,
+        }
+    }
+}
+
+/// Loads a group's plot metadata, falling back to discovery when the group
+/// has no `plot.json`.
+///
+/// # Errors
+///
+/// Returns an error if the metadata is malformed or discovery fails.
+fn read_plot_spec(data_dir: &str, group_name: &str) -> Result<PlotSpec> {
+    let spec_path = format!("{data_dir}/{group_name}/{PLOT_SPEC_FILE}");
+    let Ok(json_str) = std::fs::read_to_string(&spec_path) else {
+        let (names, sizes) = discover_criterion_group(data_dir, group_name)?;
+        return Ok(PlotSpec {
+            filename: format!("{group_name}.svg"),
+            title: group_name.to_string(),
+            x_axis_label: "Input Size (n)".to_string(),
+            metric: PlotMetric::Latency,
+            x_scale: AxisScale::Linear,
+            y_scale: AxisScale::Log,
+            x_range: None,
+            y_range: None,
+            speedup_filename: None,
+            distribution_filename: None,
+            fit_overlay: false,
+            annotation: PointAnnotation::Speedup,
+            theme: PlotTheme::default(),
+            algorithms: names.into_iter().map(|name| (name.clone(), name)).collect(),
+            sizes,
+        });
+    };
+
     let json: Value = serde_json::from_str(&json_str)?;
+    let field = |key: &str| {
+        json[key]
+            .as_str()
+            .map(str::to_string)
+            .with_context(|| format!("Missing '{key}' in {spec_path}"))
+    };
+
+    let algorithms = json["algorithms"]
+        .as_array()
+        .with_context(|| format!("Missing 'algorithms' in {spec_path}"))?
+        .iter()
+        .map(|algorithm| {
+            let name = algorithm["name"]
+                .as_str()
+                .context("Missing algorithm name")?;
+            let label = algorithm["label"].as_str().unwrap_or(name);
+            Ok((name.to_string(), label.to_string()))
+        })
+        .collect::<Result<_>>()?;
+
+    let sizes = json["sizes"]
+        .as_array()
+        .with_context(|| format!("Missing 'sizes' in {spec_path}"))?
+        .iter()
+        .filter_map(|size| size.as_u64().map(|size| size as usize))
+        .collect();
+
+    Ok(PlotSpec {
+        filename: field("filename")?,
+        title: field("title")?,
+        x_axis_label: field("x_axis_label")?,
+        // Metadata written before throughput plots existed has no metric
+        metric: match json["metric"].as_str() {
+            Some(key) => PlotMetric::from_key(key)
+                .with_context(|| format!("Unknown metric '{key}' in {spec_path}"))?,
+            None => PlotMetric::Latency,
+        },
+        x_scale: match json["x_scale"].as_str() {
+            Some(key) => AxisScale::from_key(key)
+                .with_context(|| format!("Unknown x scale '{key}' in {spec_path}"))?,
+            None => AxisScale::Linear,
+        },
+        // Metadata written before linear y-axes existed plotted on a log axis
+        y_scale: match json["y_scale"].as_str() {
+            Some(key) => AxisScale::from_key(key)
+                .with_context(|| format!("Unknown y scale '{key}' in {spec_path}"))?,
+            None => AxisScale::Log,
+        },
+        x_range: range_from_json(&json["x_range"]),
+        y_range: range_from_json(&json["y_range"]),
+        speedup_filename: json["speedup_filename"].as_str().map(str::to_string),
+        distribution_filename: json["distribution_filename"].as_str().map(str::to_string),
+        fit_overlay: json["fit_overlay"].as_bool().unwrap_or(false),
+        annotation: match json["annotation"].as_str() {
+            Some(key) => PointAnnotation::from_key(key)
+                .with_context(|| format!("Unknown annotation '{key}' in {spec_path}"))?,
+            None => PointAnnotation::Speedup,
+        },
+        // Metadata written before themes existed falls back to the default
+        theme: PlotTheme::from_json(&json["theme"])
+            .with_context(|| format!("Invalid theme in {spec_path}"))?,
+        algorithms,
+        sizes,
+    })
+}
+
+/// Reads an axis range stored as `[start, end]`.
+fn range_from_json(json: &Value) -> Option<(f64, f64)> {
+    Some((json[0].as_f64()?, json[1].as_f64()?))
+}
+
+/// Name of the file recording what a group's stored results were measured on.
+pub const FINGERPRINT_FILE: &str = "fingerprint.txt";
+
+/// Environment variable that forces benchmarks to run even when unchanged.
+pub const FORCE_BENCH_ENV: &str = "AOC_BENCH_FORCE";
+
+/// Sources that decide a day's benchmark results, relative to its crate.
+const FINGERPRINT_SOURCES: [&str; 5] = [
+    "src",
+    "benches",
+    "Cargo.toml",
+    "../shared/src",
+    "../shared/Cargo.toml",
+];
+
+/// Shared features that change what a day's benchmarks measure or export.
+///
+/// Days forward their own features to these, so they reflect the build of
+/// the bench binary.
+const FINGERPRINT_FEATURES: [(&str, bool); 4] = [
+    ("alloc-tracking", cfg!(feature = "alloc-tracking")),
+    ("perf-counters", cfg!(feature = "perf-counters")),
+    ("parallel", cfg!(feature = "parallel")),
+    ("png-plots", cfg!(feature = "png-plots")),
+];
+
+/// 64-bit FNV-1a, whose mixing stays the same across Rust releases, unlike
+/// [`std::hash::DefaultHasher`].
+///
+/// It is fed through std's `Hash` impls, whose byte encoding std does not
+/// promise to keep, so a toolchain update may still change fingerprints and
+/// cause unchanged benchmarks to be re-measured once.
+struct StableHasher(u64);
+
+impl StableHasher {
+    fn new() -> Self {
+        StableHasher(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
 
-    // Extract mean estimate (in nanoseconds)
-    let mean_ns = json["mean"]["point_estimate"]
-        .as_f64()
-        .context("Failed to parse mean estimate")?;
+/// Hashes the path and contents of a file, or of every file below a
+/// directory in sorted order.
+fn hash_source(path: &Path, hasher: &mut StableHasher) -> std::io::Result<()> {
+    if path.is_dir() {
+        let mut entries = std::fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<std::io::Result<Vec<_>>>()?;
+        entries.sort();
+        for entry in entries {
+            hash_source(&entry, hasher)?;
+        }
+    } else {
+        path.to_string_lossy().hash(hasher);
+        std::fs::read(path)?.hash(hasher);
+    }
+    Ok(())
+}
+
+/// Hashes everything a benchmark group's results depend on.
+///
+/// Covers the contents of the day's and `shared`'s sources, committed or
+/// not, the enabled features, the group and algorithm names, and the
+/// configuration including every generated input, so changing a generator's
+/// parameters changes the fingerprint too. Must run from the day's crate
+/// directory, which is where `cargo bench` starts bench binaries.
+///
+/// # Parameters
+/// * `group_name` - Benchmark group name used by Criterion
+/// * `algorithm_names` - Criterion folder names of the algorithms
+/// * `test_config` - Test configuration of the benchmark
+///
+/// # Returns
+/// Hexadecimal fingerprint, or `None` if a source cannot be read
+pub fn benchmark_fingerprint<G>(
+    group_name: &str,
+    algorithm_names: &[&str],
+    test_config: &TestConfig<G>,
+) -> Option<String>
+where
+    G: Fn(usize) -> String,
+{
+    let mut hasher = StableHasher::new();
+    for source in FINGERPRINT_SOURCES {
+        hash_source(Path::new(source), &mut hasher).ok()?;
+    }
+    FINGERPRINT_FEATURES.hash(&mut hasher);
+    group_name.hash(&mut hasher);
+    algorithm_names.hash(&mut hasher);
+    test_config.sizes.hash(&mut hasher);
+    for &size in test_config.sizes {
+        (test_config.generate_input)(size).hash(&mut hasher);
+    }
+    test_config.verify_outputs.hash(&mut hasher);
+    test_config.settings.hash(&mut hasher);
+    test_config.real_input.hash(&mut hasher);
+    test_config.cold_start.hash(&mut hasher);
+    Some(format!("{:016x}", hasher.finish()))
+}
+
+/// Checks whether a group's stored results still match its sources and
+/// configuration, so re-running Criterion can be skipped.
+///
+/// Always `false` when [`FORCE_BENCH_ENV`] is set, when no results were
+/// exported yet, or when no fingerprint can be computed.
+///
+/// # Parameters
+/// * `data_dir` - Directory where benchmark data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+/// * `algorithm_names` - Criterion folder names of the algorithms
+/// * `test_config` - Test configuration of the benchmark
+pub fn is_benchmark_unchanged<G>(
+    data_dir: &str,
+    group_name: &str,
+    algorithm_names: &[&str],
+    test_config: &TestConfig<G>,
+) -> bool
+where
+    G: Fn(usize) -> String,
+{
+    if std::env::var_os(FORCE_BENCH_ENV).is_some()
+        || !Path::new(&format!("{data_dir}/{group_name}/{RESULTS_FILE}")).is_file()
+    {
+        return false;
+    }
+    let stored = std::fs::read_to_string(format!("{data_dir}/{group_name}/{FINGERPRINT_FILE}"));
+    match (
+        stored,
+        benchmark_fingerprint(group_name, algorithm_names, test_config),
+    ) {
+        (Ok(stored), Some(current)) => stored.trim() == current,
+        _ => false,
+    }
+}
+
+/// Stores the fingerprint of the results just exported for a group.
+///
+/// Nothing is written if no fingerprint can be computed.
+///
+/// # Parameters
+/// * `data_dir` - Directory where benchmark data is stored
+/// * `group_name` - Benchmark group name used by Criterion
+/// * `algorithm_names` - Criterion folder names of the algorithms
+/// * `test_config` - Test configuration of the benchmark
+///
+/// # Errors
+///
+/// Returns an error if the fingerprint file cannot be written.
+pub fn record_benchmark_fingerprint<G>(
+    data_dir: &str,
+    group_name: &str,
+    algorithm_names: &[&str],
+    test_config: &TestConfig<G>,
+) -> Result<()>
+where
+    G: Fn(usize) -> String,
+{
+    let Some(fingerprint) = benchmark_fingerprint(group_name, algorithm_names, test_config) else {
+        return Ok(());
+    };
+    let path = format!("{data_dir}/{group_name}/{FINGERPRINT_FILE}");
+    std::fs::write(&path, fingerprint).with_context(|| format!("Failed to write {path}"))
+}
+
+/// Name of the workspace-wide file that aggregated benchmarks are written to.
+pub const AGGREGATE_FILE: &str = "benchmarks.json";
+
+/// Name of the workspace-wide chart of where the runtime is spent.
+pub const AGGREGATE_CHART_FILE: &str = "benchmarks.svg";
+
+/// Real-input time of one algorithm, gathered across the workspace.
+///
+/// # Attributes
+/// * `day` - Day whose crate holds the benchmark
+/// * `group` - Benchmark group name used by Criterion
+/// * `algorithm` - Criterion folder name of the algorithm
+/// * `part` - Puzzle part the group's algorithms solve, if it was recorded
+/// * `real_input_ns` - Mean time on the real puzzle input in nanoseconds
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayBenchmark {
+    pub day: u8,
+    pub group: String,
+    pub algorithm: String,
+    pub part: Option<u8>,
+    pub real_input_ns: f64,
+}
+
+/// Collects the real-input time of every benchmarked algorithm of every day.
+///
+/// Looks for `dayXX/data` directories below `root` and reads the latest
+/// [`REAL_INPUT_LABEL`] point of each group; groups benchmarked only on
+/// generated inputs are skipped.
+///
+/// # Parameters
+/// * `root` - Workspace root containing the `dayXX` crates
+///
+/// # Returns
+/// One entry per algorithm, ordered by day, group and algorithm
+///
+/// # Errors
+///
+/// Returns an error if a directory cannot be read or an estimates file is
+/// malformed.
+pub fn aggregate_benchmarks(root: &str) -> Result<Vec<DayBenchmark>> {
+    let mut benchmarks = Vec::new();
+    for crate_name in subdirectory_names(root)? {
+        let Some(day) = crate_name
+            .strip_prefix("day")
+            .and_then(|day| day.parse::<u8>().ok())
+        else {
+            continue;
+        };
+        let data_dir = format!("{root}/{crate_name}/data");
+        if !Path::new(&data_dir).is_dir() {
+            continue;
+        }
+
+        for group in list_criterion_groups(&data_dir)? {
+            let part = recorded_part(&data_dir, &group)?;
+            for algorithm in subdirectory_names(&format!("{data_dir}/{group}"))? {
+                let benchmark_dir = format!("{data_dir}/{group}/{algorithm}/{REAL_INPUT_LABEL}");
+                if !Path::new(&benchmark_dir).is_dir() {
+                    continue;
+                }
+                let estimate = read_criterion_estimate(&latest_estimate_path(&benchmark_dir))?;
+                benchmarks.push(DayBenchmark {
+                    day,
+                    group: group.clone(),
+                    algorithm,
+                    part,
+                    real_input_ns: estimate.mean,
+                });
+            }
+        }
+    }
+    Ok(benchmarks)
+}
+
+/// Reads the puzzle part recorded in a group's plot metadata.
+///
+/// # Errors
+///
+/// Returns an error if the metadata exists but is malformed.
+fn recorded_part(data_dir: &str, group_name: &str) -> Result<Option<u8>> {
+    let spec_path = format!("{data_dir}/{group_name}/{PLOT_SPEC_FILE}");
+    let Ok(json_str) = std::fs::read_to_string(&spec_path) else {
+        return Ok(None);
+    };
+    let json: Value =
+        serde_json::from_str(&json_str).with_context(|| format!("Malformed {spec_path}"))?;
+    Ok(json["part"]
+        .as_u64()
+        .and_then(|part| u8::try_from(part).ok()))
+}
+
+/// Picks the time of the fastest algorithm benchmarked for each puzzle part.
+///
+/// Benchmarks without a recorded part are left out, since they may time
+/// only a stage of a solution.
+///
+/// # Parameters
+/// * `benchmarks` - Entries gathered by [`aggregate_benchmarks`]
+///
+/// # Returns
+/// Real-input nanoseconds keyed by `(day, part)`
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::{part_runtimes, DayBenchmark};
+/// let benchmark = |algorithm: &str, part, real_input_ns| DayBenchmark {
+///     day: 1,
+///     group: "naive_vs_hashmap".to_string(),
+///     algorithm: algorithm.to_string(),
+///     part,
+///     real_input_ns,
+/// };
+/// let runtimes = part_runtimes(&[
+///     benchmark("hashmap", Some(2), 150.0),
+///     benchmark("naive", Some(2), 9000.0),
+///     benchmark("parse", None, 50.0),
+/// ]);
+/// assert_eq!(runtimes.into_iter().collect::<Vec<_>>(), [((1, 2), 150.0)]);
+/// ```
+pub fn part_runtimes(benchmarks: &[DayBenchmark]) -> BTreeMap<(u8, u8), f64> {
+    let mut runtimes = BTreeMap::new();
+    for benchmark in benchmarks {
+        let Some(part) = benchmark.part else {
+            continue;
+        };
+        runtimes
+            .entry((benchmark.day, part))
+            .and_modify(|time: &mut f64| *time = time.min(benchmark.real_input_ns))
+            .or_insert(benchmark.real_input_ns);
+    }
+    runtimes
+}
+
+/// Builds the combined JSON dataset of aggregated benchmarks.
+///
+/// # Parameters
+/// * `benchmarks` - Entries gathered by [`aggregate_benchmarks`]
+///
+/// # Returns
+/// JSON array with one `{day, group, algorithm, part, real_input_ns}`
+/// object per entry
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::{aggregate_document, DayBenchmark};
+/// let benchmarks = [DayBenchmark {
+///     day: 5,
+///     group: "sequence_length_scaling".to_string(),
+///     algorithm: "optimized".to_string(),
+///     part: Some(1),
+///     real_input_ns: 1500.0,
+/// }];
+/// assert_eq!(aggregate_document(&benchmarks)[0]["day"], 5);
+/// ```
+pub fn aggregate_document(benchmarks: &[DayBenchmark]) -> Value {
+    benchmarks
+        .iter()
+        .map(|benchmark| {
+            serde_json::json!({
+                "day": benchmark.day,
+                "group": benchmark.group,
+                "algorithm": benchmark.algorithm,
+                "part": benchmark.part,
+                "real_input_ns": benchmark.real_input_ns,
+            })
+        })
+        .collect()
+}
+
+/// Writes the combined JSON dataset of aggregated benchmarks.
+///
+/// # Parameters
+/// * `path` - File the dataset is written to
+/// * `benchmarks` - Entries gathered by [`aggregate_benchmarks`]
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+pub fn write_aggregate_json(path: &Path, benchmarks: &[DayBenchmark]) -> Result<()> {
+    let document = aggregate_document(benchmarks);
+    std::fs::write(path, serde_json::to_string_pretty(&document)?)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
 
-    Ok(mean_ns)
+/// Returns the sorted names of all subdirectories except `report`.
+fn subdirectory_names(path: &str) -> Result<Vec<String>> {
+    let mut names = Vec::new();
+    for entry in std::fs::read_dir(path).with_context(|| format!("Failed to read {path}"))? {
+        let entry = entry?;
+        if entry.file_type()?.is_dir() {
+            let name = entry.file_name().to_string_lossy().into_owned();
+            if name != "report" {
+                names.push(name);
+            }
+        }
+    }
+    names.sort();
+    Ok(names)
 }
 
 /// Prints benchmark results summary to stdout.
 ///
-/// Displays formatted performance comparison with execution times in microseconds
-/// and speedup ratios for each input size.
+/// Displays each algorithm's execution time in microseconds for every input
+/// size, with the slowdown relative to the first (baseline) algorithm and
+/// its uncertainty. Slowdowns whose confidence intervals overlap the
+/// baseline's are marked `~noise`. When heap usage was measured, each entry
+/// also shows the peak memory in KiB and the number of allocations.
 ///
 /// # Parameters
+/// * `algorithm_names` - Names of the algorithms, in the order of each
+///   result's `times`
 /// * `results` - Benchmark results to print
-pub fn print_benchmark_summary(results: &BenchmarkResults) {
-    for (size, algorithm1_time, algorithm2_time, speedup) in results {
-        println!(
-            "Size {}: Algorithm1 {:.2}μs, Algorithm2 {:.2}μs, Speedup {:.1}x",
-            size,
-            algorithm1_time / 1000.0,
-            algorithm2_time / 1000.0,
-            speedup
-        );
+pub fn print_benchmark_summary(algorithm_names: &[&str], results: &BenchmarkResults) {
+    for result in results {
+        let times: Vec<String> = algorithm_names
+            .iter()
+            .zip(&result.times)
+            .enumerate()
+            .map(|(index, (name, time))| {
+                let micros = time / 1000.0;
+                let mut entry = match result.speedup(index).filter(|_| index > 0) {
+                    Some(speedup) => match result.speedup_error(index) {
+                        Some(error) => format!("{name} {micros:.2}μs ({speedup:.2}x ± {error:.2})"),
+                        None => format!("{name} {micros:.2}μs ({speedup:.1}x)"),
+                    },
+                    None => format!("{name} {micros:.2}μs"),
+                };
+                if index > 0 && result.is_significant(index) == Some(false) {
+                    entry.push_str(" ~noise");
+                }
+                if let Some(memory) = result.memory.get(index) {
+                    let _ = write!(
+                        entry,
+                        " [peak {:.1} KiB, {} allocs]",
+                        memory.peak_bytes as f64 / 1024.0,
+                        memory.allocations
+                    );
+                }
+                entry
+            })
+            .collect();
+        println!("Size {}: {}", result.size, times.join(", "));
+    }
+}
+
+/// Prints the best-fitting complexity class of every algorithm.
+///
+/// Fits each algorithm's mean times across all sizes with
+/// [`best_fit`], so the growth claimed in a plot title can be checked
+/// against the measurements.
+///
+/// # Parameters
+/// * `algorithm_names` - Names of the algorithms, in the order of each
+///   result's `times`
+/// * `results` - Benchmark results to analyze
+pub fn print_complexity_fits(algorithm_names: &[&str], results: &BenchmarkResults) {
+    for (index, name) in algorithm_names.iter().enumerate() {
+        let points: Vec<(usize, f64)> = results
+            .iter()
+            .filter_map(|result| Some((result.size, *result.times.get(index)?)))
+            .collect();
+        match best_fit(&points) {
+            Some(fit) => println!(
+                "📐 {name}: best fit {} (R² {:.4})",
+                fit.class, fit.r_squared
+            ),
+            None => println!("📐 {name}: not enough data to fit a complexity class"),
+        }
+    }
+}
+
+/// Prints the sample distribution of every algorithm and size.
+///
+/// Shows the median with the 5th–95th percentile range and outlier counts,
+/// which reveal skewed or bimodal timings that the mean hides. Prints
+/// nothing for results without distributions.
+///
+/// # Parameters
+/// * `algorithm_names` - Names of the algorithms, in the order of each
+///   result's `distributions`
+/// * `results` - Benchmark results to print
+pub fn print_distribution_summary(algorithm_names: &[&str], results: &BenchmarkResults) {
+    for result in results
+        .iter()
+        .filter(|result| !result.distributions.is_empty())
+    {
+        let entries: Vec<String> = algorithm_names
+            .iter()
+            .zip(&result.distributions)
+            .map(|(name, stats)| {
+                format!(
+                    "{name} median {:.2}μs (p5–p95 {:.2}–{:.2}μs, {} outliers)",
+                    stats.median / 1000.0,
+                    stats.p5 / 1000.0,
+                    stats.p95 / 1000.0,
+                    stats.mild_outliers + stats.severe_outliers
+                )
+            })
+            .collect();
+        println!("Size {} distribution: {}", result.size, entries.join(", "));
     }
 }
@@ -0,0 +1,188 @@
+//! Compact boolean grid with one bit per cell.
+//!
+//! Tracking visited cells with `HashSet<(usize, usize)>` costs a hash and
+//! tens of bytes per cell; [`BitGrid`] stores a bit instead, so a 1000x1000
+//! visited set fits in 125 KB and lookups are a shift and a mask.
+
+use crate::point::Point;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Fixed-size grid of bits stored row by row in `u64` words.
+///
+/// Each row starts on a fresh word so rows can be read as word slices.
+///
+/// # Attributes
+/// * `words` - All rows, `words_per_row` words each
+/// * `width` - Number of columns
+/// * `height` - Number of rows
+/// * `words_per_row` - Words needed to hold one row
+///
+/// # Examples
+///
+/// ```
+/// # use shared::bit_grid::BitGrid;
+/// let mut visited = BitGrid::new(130, 130);
+/// assert!(visited.set(5, 7)); // newly visited
+/// assert!(!visited.set(5, 7)); // already visited
+/// assert!(visited.get(5, 7));
+/// assert_eq!(visited.count_ones(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+}
+
+impl BitGrid {
+    /// Creates a grid with every bit unset.
+    pub fn new(width: usize, height: usize) -> Self {
+        let words_per_row = width.div_ceil(WORD_BITS);
+        BitGrid {
+            words: vec![0; words_per_row * height],
+            width,
+            height,
+            words_per_row,
+        }
+    }
+
+    /// Returns the number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the bit at a position; out-of-bounds positions read as unset.
+    pub fn get(&self, row: usize, col: usize) -> bool {
+        self.locate(row, col)
+            .is_some_and(|(word, mask)| self.words[word] & mask != 0)
+    }
+
+    /// Sets the bit at a position.
+    ///
+    /// # Returns
+    /// `true` if the bit was previously unset; `false` if it was already set
+    /// or the position is out of bounds
+    pub fn set(&mut self, row: usize, col: usize) -> bool {
+        let Some((word, mask)) = self.locate(row, col) else {
+            return false;
+        };
+        let was_unset = self.words[word] & mask == 0;
+        self.words[word] |= mask;
+        was_unset
+    }
+
+    /// Unsets the bit at a position.
+    ///
+    /// # Returns
+    /// `true` if the bit was previously set
+    pub fn unset(&mut self, row: usize, col: usize) -> bool {
+        let Some((word, mask)) = self.locate(row, col) else {
+            return false;
+        };
+        let was_set = self.words[word] & mask != 0;
+        self.words[word] &= !mask;
+        was_set
+    }
+
+    /// Returns the bit at a signed point; out-of-bounds points read as unset.
+    pub fn get_point(&self, point: Point) -> bool {
+        match (usize::try_from(point.0), usize::try_from(point.1)) {
+            (Ok(row), Ok(col)) => self.get(row, col),
+            _ => false,
+        }
+    }
+
+    /// Sets the bit at a signed point.
+    ///
+    /// # Returns
+    /// `true` if the bit was previously unset and the point is in bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::bit_grid::BitGrid;
+    /// # use shared::point::Point;
+    /// let mut visited = BitGrid::new(3, 3);
+    /// assert!(visited.set_point(Point(2, 1)));
+    /// assert!(!visited.set_point(Point(-1, 0)));
+    /// ```
+    pub fn set_point(&mut self, point: Point) -> bool {
+        match (usize::try_from(point.0), usize::try_from(point.1)) {
+            (Ok(row), Ok(col)) => self.set(row, col),
+            _ => false,
+        }
+    }
+
+    /// Returns the number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Unsets every bit.
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Returns the words backing a row, or `None` if it is out of bounds.
+    ///
+    /// Column `c` is bit `c % 64` of word `c / 64`; bits past the width are
+    /// always zero. Useful for combining rows with bitwise operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::bit_grid::BitGrid;
+    /// let mut grid = BitGrid::new(70, 2);
+    /// grid.set(1, 0);
+    /// grid.set(1, 65);
+    /// assert_eq!(grid.row_words(1), Some(&[1, 2][..]));
+    /// ```
+    pub fn row_words(&self, row: usize) -> Option<&[u64]> {
+        if row >= self.height {
+            return None;
+        }
+        let start = row * self.words_per_row;
+        self.words.get(start..start + self.words_per_row)
+    }
+
+    /// Returns the words backing a row for in-place bitwise updates.
+    ///
+    /// Callers must keep bits past the width zero, otherwise
+    /// [`count_ones`](Self::count_ones) counts them.
+    pub fn row_words_mut(&mut self, row: usize) -> Option<&mut [u64]> {
+        if row >= self.height {
+            return None;
+        }
+        let start = row * self.words_per_row;
+        self.words.get_mut(start..start + self.words_per_row)
+    }
+
+    /// Iterates over the positions of set bits in row-major order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height).flat_map(move |row| {
+            (0..self.width)
+                .filter(move |&col| self.get(row, col))
+                .map(move |col| (row, col))
+        })
+    }
+
+    /// Maps a position to its word index and bit mask.
+    fn locate(&self, row: usize, col: usize) -> Option<(usize, u64)> {
+        (row < self.height && col < self.width).then(|| {
+            (
+                row * self.words_per_row + col / WORD_BITS,
+                1 << (col % WORD_BITS),
+            )
+        })
+    }
+}
@@ -0,0 +1,180 @@
+//! Integrity tracking for downloaded puzzle inputs.
+//!
+//! Fetched inputs are stored in `dayXX/input.txt` together with a
+//! `dayXX/input.meta` file recording a checksum and the download time. A
+//! cached input that no longer matches its checksum, or a download that is
+//! really an HTML error page (e.g. after the session cookie expired), is
+//! reported before it can cause confusing parse errors downstream.
+
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::io;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Metadata recorded when an input is fetched.
+///
+/// # Attributes
+/// * `checksum` - FNV-1a hash of the input bytes
+/// * `fetched_at` - Download time in seconds since the Unix epoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputMetadata {
+    pub checksum: u64,
+    pub fetched_at: u64,
+}
+
+/// Computes the 64-bit FNV-1a hash of some bytes.
+///
+/// Stable across platforms and Rust versions, unlike `DefaultHasher`, so it
+/// can be persisted.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::cache::checksum;
+/// assert_eq!(checksum(b""), 0xcbf2_9ce4_8422_2325);
+/// assert_ne!(checksum(b"3   4\n"), checksum(b"3   4"));
+/// ```
+pub fn checksum(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+/// Detects downloads that are not a real puzzle input.
+///
+/// # Parameters
+/// * `body` - Downloaded or cached input text
+///
+/// # Returns
+/// A description of the problem, or `None` if the text looks like an input
+///
+/// # Examples
+///
+/// ```
+/// # use shared::cache::detect_corruption;
+/// assert!(detect_corruption("3   4\n4   3\n").is_none());
+/// assert!(detect_corruption("<!DOCTYPE html><html>...").is_some());
+/// let logged_out = "Puzzle inputs differ by user.  Please log in to get \
+///                   your puzzle input.\n";
+/// assert!(detect_corruption(logged_out).is_some());
+/// ```
+pub fn detect_corruption(body: &str) -> Option<String> {
+    let trimmed = body.trim_start();
+    let lowercase_start = trimmed
+        .get(..trimmed.len().min(64))
+        .unwrap_or(trimmed)
+        .to_ascii_lowercase();
+
+    if trimmed.is_empty() {
+        Some("Input is empty".to_string())
+    } else if lowercase_start.starts_with("<!doctype") || lowercase_start.starts_with("<html") {
+        Some("Input is an HTML page, not a puzzle input".to_string())
+    } else if body.contains("Please log in to get your puzzle input") {
+        Some("Input is a login prompt; AOC_SESSION is missing or expired".to_string())
+    } else if body.contains("Please don't repeatedly request this endpoint before it unlocks") {
+        Some("Input is not unlocked yet".to_string())
+    } else if body.starts_with("404 Not Found") || body.contains("500 Internal Server Error") {
+        Some("Input is a server error message".to_string())
+    } else {
+        None
+    }
+}
+
+/// Stores a freshly fetched input together with its metadata.
+///
+/// # Parameters
+/// * `day` - The day number (1-25)
+/// * `body` - The downloaded input
+///
+/// # Returns
+/// The metadata that was written
+///
+/// # Errors
+///
+/// Returns an error if the input looks corrupt or truncated (nothing is
+/// written then) or if either file cannot be written.
+pub fn store_input(day: u8, body: &str) -> Result<InputMetadata> {
+    if let Some(problem) = detect_corruption(body) {
+        bail!("Refusing to cache day {day} input: {problem}");
+    }
+    // The site always terminates inputs with a newline
+    if !body.ends_with('\n') {
+        bail!("Refusing to cache day {day} input: missing final newline, the download may be truncated");
+    }
+
+    let metadata = InputMetadata {
+        checksum: checksum(body.as_bytes()),
+        fetched_at: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs()),
+    };
+    let input_path = format!("day{day:02}/input.txt");
+    fs::write(&input_path, body).with_context(|| format!("Failed to write {input_path}"))?;
+    let meta_path = metadata_path(day);
+    fs::write(
+        &meta_path,
+        format!("{:016x}\t{}\n", metadata.checksum, metadata.fetched_at),
+    )
+    .with_context(|| format!("Failed to write {meta_path}"))?;
+    Ok(metadata)
+}
+
+/// Reads the metadata recorded for a day's cached input.
+///
+/// # Parameters
+/// * `day` - The day number (1-25)
+///
+/// # Returns
+/// The metadata, or `None` if the input was not fetched by the runner
+///
+/// # Errors
+///
+/// Returns an error if the metadata file exists but is unreadable or
+/// malformed.
+pub fn read_metadata(day: u8) -> Result<Option<InputMetadata>> {
+    let path = metadata_path(day);
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(error) => return Err(error).with_context(|| format!("Failed to read {path}")),
+    };
+
+    let Some((checksum, fetched_at)) = contents.trim().split_once('\t') else {
+        bail!("Malformed metadata in {path}: '{}'", contents.trim());
+    };
+    Ok(Some(InputMetadata {
+        checksum: u64::from_str_radix(checksum, 16)
+            .with_context(|| format!("Invalid checksum in {path}"))?,
+        fetched_at: fetched_at
+            .parse()
+            .with_context(|| format!("Invalid timestamp in {path}"))?,
+    }))
+}
+
+/// Checks a cached input for corruption and checksum mismatches.
+///
+/// Inputs without metadata (added by hand) are only checked for corruption.
+///
+/// # Parameters
+/// * `day` - The day number (1-25)
+/// * `input` - The cached input text
+///
+/// # Returns
+/// Every problem found; an empty vector means the cache looks intact
+pub fn verify_cached_input(day: u8, input: &str) -> Vec<String> {
+    let mut problems: Vec<String> = detect_corruption(input).into_iter().collect();
+    match read_metadata(day) {
+        Ok(Some(metadata)) if metadata.checksum != checksum(input.as_bytes()) => problems.push(
+            "Input changed since it was fetched (checksum mismatch); run `aoc fetch --force`"
+                .to_string(),
+        ),
+        Ok(_) => {}
+        Err(error) => problems.push(format!("{error:#}")),
+    }
+    problems
+}
+
+/// Returns the path of the metadata file for a day's input.
+fn metadata_path(day: u8) -> String {
+    format!("day{day:02}/input.meta")
+}
@@ -0,0 +1,214 @@
+//! Small parser-combinator layer for common puzzle input shapes.
+//!
+//! A parser is any function `for<'a> Fn(&'a str) -> Option<(T, &'a str)>`
+//! that consumes a prefix of the input and returns the parsed value with the
+//! unconsumed rest. Combinators build larger parsers out of smaller ones, so
+//! a line like `p=0,4 v=3,-3` can be described declaratively instead of with
+//! split/trim chains. [`parse_all`] runs a parser over a whole line and
+//! reports failures as [`ParseError`]s.
+//!
+//! # Examples
+//!
+//! ```
+//! # use shared::combinators::*;
+//! let vector = || pair(signed, preceded(tag(","), signed));
+//! let robot = pair(
+//!     labeled("p", vector()),
+//!     preceded(spaces, labeled("v", vector())),
+//! );
+//! let parsed = parse_all(&robot, "p=0,4 v=3,-3", "robot 'p=X,Y v=DX,DY'").unwrap();
+//! assert_eq!(parsed, ((0, 4), (3, -3)));
+//! ```
+
+use crate::input::fast::Cursor;
+use crate::input::ParseError;
+use anyhow::Result;
+
+/// Parser interface implemented by every suitable function and closure.
+pub trait Parser<T> {
+    /// Parses a prefix of `input`.
+    ///
+    /// # Returns
+    /// The parsed value and the unconsumed rest, or `None` if the input does
+    /// not start with a match
+    fn parse<'a>(&self, input: &'a str) -> Option<(T, &'a str)>;
+}
+
+impl<T, F> Parser<T> for F
+where
+    F: for<'a> Fn(&'a str) -> Option<(T, &'a str)>,
+{
+    fn parse<'a>(&self, input: &'a str) -> Option<(T, &'a str)> {
+        self(input)
+    }
+}
+
+/// Pins a closure to the parser signature so its lifetimes infer correctly.
+fn parser<T, F>(f: F) -> F
+where
+    F: for<'a> Fn(&'a str) -> Option<(T, &'a str)>,
+{
+    f
+}
+
+/// Parses an unsigned decimal integer.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::combinators::unsigned;
+/// assert_eq!(unsigned("42,7"), Some((42, ",7")));
+/// assert_eq!(unsigned("-1"), None);
+/// ```
+pub fn unsigned(input: &str) -> Option<(u64, &str)> {
+    let mut cursor = Cursor::new(input.as_bytes());
+    let value = cursor.read_unsigned()?;
+    // Only ASCII digits were consumed, so the position is a char boundary
+    Some((value, input.get(cursor.position()..)?))
+}
+
+/// Parses a signed decimal integer with an optional `-` or `+` sign.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::combinators::signed;
+/// assert_eq!(signed("-3 rest"), Some((-3, " rest")));
+/// ```
+pub fn signed(input: &str) -> Option<(i64, &str)> {
+    let mut cursor = Cursor::new(input.as_bytes());
+    let value = cursor.read_signed()?;
+    Some((value, input.get(cursor.position()..)?))
+}
+
+/// Consumes zero or more whitespace characters. Never fails.
+pub fn spaces(input: &str) -> Option<((), &str)> {
+    Some(((), input.trim_start()))
+}
+
+/// Matches a literal string.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::combinators::{tag, Parser};
+/// assert_eq!(tag("mul(").parse("mul(2,4)"), Some(((), "2,4)")));
+/// ```
+pub fn tag(literal: &str) -> impl Parser<()> + '_ {
+    parser(move |input| Some(((), input.strip_prefix(literal)?)))
+}
+
+/// Transforms the output of a parser.
+pub fn map<A, B>(inner: impl Parser<A>, f: impl Fn(A) -> B) -> impl Parser<B> {
+    parser(move |input| {
+        let (value, rest) = inner.parse(input)?;
+        Some((f(value), rest))
+    })
+}
+
+/// Runs two parsers in sequence and returns both results.
+pub fn pair<A, B>(first: impl Parser<A>, second: impl Parser<B>) -> impl Parser<(A, B)> {
+    parser(move |input| {
+        let (a, rest) = first.parse(input)?;
+        let (b, rest) = second.parse(rest)?;
+        Some(((a, b), rest))
+    })
+}
+
+/// Runs `prefix` then `inner`, keeping only the result of `inner`.
+pub fn preceded<P, T>(prefix: impl Parser<P>, inner: impl Parser<T>) -> impl Parser<T> {
+    parser(move |input| {
+        let (_, rest) = prefix.parse(input)?;
+        inner.parse(rest)
+    })
+}
+
+/// Runs `inner` then `suffix`, keeping only the result of `inner`.
+pub fn terminated<T, S>(inner: impl Parser<T>, suffix: impl Parser<S>) -> impl Parser<T> {
+    parser(move |input| {
+        let (value, rest) = inner.parse(input)?;
+        let (_, rest) = suffix.parse(rest)?;
+        Some((value, rest))
+    })
+}
+
+/// Runs `open`, `inner` and `close`, keeping only the result of `inner`.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::combinators::*;
+/// let mul = delimited(tag("mul("), pair(unsigned, preceded(tag(","), unsigned)), tag(")"));
+/// assert_eq!(mul.parse("mul(2,4)x"), Some(((2, 4), "x")));
+/// ```
+pub fn delimited<O, T, C>(
+    open: impl Parser<O>,
+    inner: impl Parser<T>,
+    close: impl Parser<C>,
+) -> impl Parser<T> {
+    preceded(open, terminated(inner, close))
+}
+
+/// Tries `first`, falling back to `second` if it fails.
+pub fn alt<T>(first: impl Parser<T>, second: impl Parser<T>) -> impl Parser<T> {
+    parser(move |input| first.parse(input).or_else(|| second.parse(input)))
+}
+
+/// Parses one or more items separated by `separator`.
+///
+/// Stops before a separator that is not followed by another item.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::combinators::*;
+/// let pages = separated(unsigned, tag(","));
+/// assert_eq!(pages.parse("75,47,61"), Some((vec![75, 47, 61], "")));
+/// ```
+pub fn separated<T, S>(item: impl Parser<T>, separator: impl Parser<S>) -> impl Parser<Vec<T>> {
+    parser(move |input| {
+        let (first, mut rest) = item.parse(input)?;
+        let mut items = vec![first];
+        while let Some((value, next)) = separator
+            .parse(rest)
+            .and_then(|(_, after)| item.parse(after))
+        {
+            items.push(value);
+            rest = next;
+        }
+        Some((items, rest))
+    })
+}
+
+/// Parses a `label=value` field, e.g. `p=0,4`.
+pub fn labeled<'l, T: 'l>(label: &'l str, value: impl Parser<T> + 'l) -> impl Parser<T> + 'l {
+    preceded(pair(tag(label), tag("=")), value)
+}
+
+/// Runs a parser over a whole string, ignoring surrounding whitespace.
+///
+/// # Parameters
+/// * `parser` - Parser describing the complete string
+/// * `input` - Text to parse, typically one line
+/// * `expected` - Description of the format used in error messages
+///
+/// # Returns
+/// The parsed value
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the parser fails (located at the start of
+/// the text) or leaves unparsed text behind (located at the leftover text).
+/// Used inside [`parse_lines_with`](crate::input::parse_lines_with), the
+/// error's line number is filled in automatically.
+pub fn parse_all<T>(parser: &impl Parser<T>, input: &str, expected: &str) -> Result<T> {
+    let text = input.trim();
+    let (value, rest) = parser
+        .parse(text)
+        .ok_or_else(|| ParseError::at_token(input, text, expected))?;
+    let rest = rest.trim_end();
+    if !rest.is_empty() {
+        return Err(ParseError::at_token(input, rest, "end of input").into());
+    }
+    Ok(value)
+}
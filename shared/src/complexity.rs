@@ -0,0 +1,210 @@
+//! Empirical complexity-class fitting.
+//!
+//! Fits measured times against common growth models with least squares so a
+//! benchmark can check that an algorithm really scales the way its plot
+//! title claims.
+
+use std::fmt;
+
+/// Growth model a benchmark's times can be fitted against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ComplexityClass {
+    Linear,
+    Linearithmic,
+    Quadratic,
+    Cubic,
+}
+
+/// Every class, in order of increasing growth.
+pub const COMPLEXITY_CLASSES: [ComplexityClass; 4] = [
+    ComplexityClass::Linear,
+    ComplexityClass::Linearithmic,
+    ComplexityClass::Quadratic,
+    ComplexityClass::Cubic,
+];
+
+impl ComplexityClass {
+    /// Evaluates the growth function at an input size.
+    pub fn apply(self, n: f64) -> f64 {
+        match self {
+            ComplexityClass::Linear => n,
+            ComplexityClass::Linearithmic => n * n.max(1.0).log2(),
+            ComplexityClass::Quadratic => n * n,
+            ComplexityClass::Cubic => n * n * n,
+        }
+    }
+}
+
+impl fmt::Display for ComplexityClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let notation = match self {
+            ComplexityClass::Linear => "O(n)",
+            ComplexityClass::Linearithmic => "O(n log n)",
+            ComplexityClass::Quadratic => "O(n²)",
+            ComplexityClass::Cubic => "O(n³)",
+        };
+        f.write_str(notation)
+    }
+}
+
+/// Least-squares fit of `time = coefficient * class(n) + intercept`.
+///
+/// # Attributes
+/// * `class` - Growth model that was fitted
+/// * `coefficient` - Time per unit of `class(n)`
+/// * `intercept` - Constant overhead independent of the size
+/// * `r_squared` - Coefficient of determination; 1.0 is a perfect fit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComplexityFit {
+    pub class: ComplexityClass,
+    pub coefficient: f64,
+    pub intercept: f64,
+    pub r_squared: f64,
+}
+
+/// Fits measurements against one complexity class.
+///
+/// # Parameters
+/// * `class` - Growth model to fit
+/// * `points` - `(size, time)` measurements
+///
+/// # Returns
+/// The fit, or `None` with fewer than two distinct sizes or constant times
+///
+/// # Examples
+///
+/// ```
+/// # use shared::complexity::{fit_class, ComplexityClass};
+/// let points = [(10, 205.0), (20, 805.0), (40, 3205.0)];
+/// let fit = fit_class(ComplexityClass::Quadratic, &points).unwrap();
+/// assert!((fit.coefficient - 2.0).abs() < 1e-9);
+/// assert!((fit.r_squared - 1.0).abs() < 1e-9);
+/// ```
+pub fn fit_class(class: ComplexityClass, points: &[(usize, f64)]) -> Option<ComplexityFit> {
+    let xs: Vec<f64> = points
+        .iter()
+        .map(|&(size, _)| class.apply(size as f64))
+        .collect();
+    let ys: Vec<f64> = points.iter().map(|&(_, time)| time).collect();
+    let (coefficient, intercept, r_squared) = linear_regression(&xs, &ys)?;
+
+    Some(ComplexityFit {
+        class,
+        coefficient,
+        intercept,
+        r_squared,
+    })
+}
+
+/// Least-squares fit of `time = coefficient * n^exponent`.
+///
+/// # Attributes
+/// * `exponent` - Fitted power of the size; 1.0 is linear, 2.0 quadratic
+/// * `coefficient` - Time at size 1
+/// * `r_squared` - Coefficient of determination in log-log space
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PowerLawFit {
+    pub exponent: f64,
+    pub coefficient: f64,
+    pub r_squared: f64,
+}
+
+impl PowerLawFit {
+    /// Evaluates the fitted curve at an input size.
+    pub fn apply(self, n: f64) -> f64 {
+        self.coefficient * n.powf(self.exponent)
+    }
+}
+
+/// Fits measurements against a power law by regressing in log-log space.
+///
+/// Unlike [`best_fit`], the exponent is not restricted to a known class, so
+/// it shows how close an algorithm comes to its claimed growth.
+///
+/// # Parameters
+/// * `points` - `(size, time)` measurements; non-positive ones are ignored
+///
+/// # Returns
+/// The fit, or `None` with fewer than two distinct sizes or constant times
+///
+/// # Examples
+///
+/// ```
+/// # use shared::complexity::fit_power_law;
+/// let points = [(10, 300.0), (100, 30_000.0), (1000, 3_000_000.0)];
+/// let fit = fit_power_law(&points).unwrap();
+/// assert!((fit.exponent - 2.0).abs() < 1e-9);
+/// assert!((fit.apply(20.0) - 1_200.0).abs() < 1e-6);
+/// ```
+pub fn fit_power_law(points: &[(usize, f64)]) -> Option<PowerLawFit> {
+    let (xs, ys): (Vec<f64>, Vec<f64>) = points
+        .iter()
+        .filter(|&&(size, time)| size > 0 && time > 0.0)
+        .map(|&(size, time)| ((size as f64).ln(), time.ln()))
+        .unzip();
+    let (exponent, intercept, r_squared) = linear_regression(&xs, &ys)?;
+
+    Some(PowerLawFit {
+        exponent,
+        coefficient: intercept.exp(),
+        r_squared,
+    })
+}
+
+/// Ordinary least squares of `y = slope * x + intercept`.
+///
+/// # Returns
+/// `(slope, intercept, r_squared)`, or `None` if either variable is constant
+fn linear_regression(xs: &[f64], ys: &[f64]) -> Option<(f64, f64, f64)> {
+    let count = xs.len() as f64;
+    let mean_x = xs.iter().sum::<f64>() / count;
+    let mean_y = ys.iter().sum::<f64>() / count;
+    let (mut covariance, mut variance_x, mut variance_y) = (0.0, 0.0, 0.0);
+    for (x, y) in xs.iter().zip(ys) {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance_x += (x - mean_x).powi(2);
+        variance_y += (y - mean_y).powi(2);
+    }
+    if variance_x == 0.0 || variance_y == 0.0 {
+        return None;
+    }
+
+    let slope = covariance / variance_x;
+    let intercept = mean_y - slope * mean_x;
+    let residual: f64 = xs
+        .iter()
+        .zip(ys)
+        .map(|(x, y)| (y - (slope * x + intercept)).powi(2))
+        .sum();
+
+    Some((slope, intercept, 1.0 - residual / variance_y))
+}
+
+/// Finds the complexity class that best explains the measurements.
+///
+/// Fits with a negative coefficient (time shrinking as size grows) are
+/// rejected, and ties keep the slower-growing class.
+///
+/// # Parameters
+/// * `points` - `(size, time)` measurements
+///
+/// # Returns
+/// The fit with the highest R², or `None` if no class can be fitted
+///
+/// # Examples
+///
+/// ```
+/// # use shared::complexity::{best_fit, ComplexityClass};
+/// let points = [(100, 1_010.0), (200, 2_005.0), (400, 4_020.0), (800, 7_990.0)];
+/// assert_eq!(best_fit(&points).unwrap().class, ComplexityClass::Linear);
+/// ```
+pub fn best_fit(points: &[(usize, f64)]) -> Option<ComplexityFit> {
+    COMPLEXITY_CLASSES
+        .iter()
+        .filter_map(|&class| fit_class(class, points))
+        .filter(|fit| fit.coefficient > 0.0)
+        .fold(None, |best: Option<ComplexityFit>, fit| match best {
+            Some(best) if best.r_squared >= fit.r_squared => Some(best),
+            _ => Some(fit),
+        })
+}
@@ -0,0 +1,157 @@
+//! Frequency counting.
+//!
+//! [`Counter`] is a multiset backed by `FxHashMap<K, u64>`, which hashes
+//! integer keys much faster than the standard hasher.
+
+use rustc_hash::FxHashMap;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Sub};
+
+/// Counts how often each key occurs.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::counter::Counter;
+/// let counts: Counter<i32> = [3, 4, 2, 1, 3, 3].into_iter().collect();
+/// assert_eq!(counts.get(&3), 3);
+/// assert_eq!(counts.get(&5), 0);
+/// assert_eq!(counts.total(), 6);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Counter<K: Eq + Hash> {
+    counts: FxHashMap<K, u64>,
+}
+
+impl<K: Eq + Hash> Default for Counter<K> {
+    fn default() -> Self {
+        Counter {
+            counts: FxHashMap::default(),
+        }
+    }
+}
+
+impl<K: Eq + Hash> Counter<K> {
+    /// Creates an empty counter.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Counts one occurrence of a key.
+    pub fn add(&mut self, key: K) {
+        self.add_n(key, 1);
+    }
+
+    /// Counts `n` occurrences of a key.
+    pub fn add_n(&mut self, key: K, n: u64) {
+        if n > 0 {
+            *self.counts.entry(key).or_insert(0) += n;
+        }
+    }
+
+    /// Returns how often a key occurred; `0` for unseen keys.
+    pub fn get(&self, key: &K) -> u64 {
+        self.counts.get(key).copied().unwrap_or(0)
+    }
+
+    /// Returns the number of distinct keys.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns `true` if nothing has been counted.
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Returns the sum of all counts.
+    pub fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    /// Iterates over every key and its count in arbitrary order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, u64)> {
+        self.counts.iter().map(|(key, &count)| (key, count))
+    }
+}
+
+impl<K: Eq + Hash + Ord> Counter<K> {
+    /// Returns the `n` most frequent keys with their counts.
+    ///
+    /// Ties are broken by ascending key so the result is deterministic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::counter::Counter;
+    /// let counts: Counter<char> = "abracadabra".chars().collect();
+    /// assert_eq!(counts.most_common(2), vec![(&'a', 5), (&'b', 2)]);
+    /// ```
+    pub fn most_common(&self, n: usize) -> Vec<(&K, u64)> {
+        let mut entries: Vec<(&K, u64)> = self.iter().collect();
+        entries.sort_unstable_by(|(a, a_count), (b, b_count)| {
+            b_count.cmp(a_count).then_with(|| a.cmp(b))
+        });
+        entries.truncate(n);
+        entries
+    }
+}
+
+impl<K: Eq + Hash> FromIterator<K> for Counter<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut counter = Counter::new();
+        counter.extend(iter);
+        counter
+    }
+}
+
+impl<K: Eq + Hash> Extend<K> for Counter<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.add(key);
+        }
+    }
+}
+
+impl<K: Eq + Hash> AddAssign for Counter<K> {
+    fn add_assign(&mut self, other: Counter<K>) {
+        for (key, count) in other.counts {
+            self.add_n(key, count);
+        }
+    }
+}
+
+impl<K: Eq + Hash> Add for Counter<K> {
+    type Output = Counter<K>;
+
+    /// Sums the counts of both counters.
+    fn add(mut self, other: Counter<K>) -> Counter<K> {
+        self += other;
+        self
+    }
+}
+
+impl<K: Eq + Hash> Sub for Counter<K> {
+    type Output = Counter<K>;
+
+    /// Subtracts counts, dropping keys whose count reaches zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::counter::Counter;
+    /// let a: Counter<u8> = [1, 1, 2].into_iter().collect();
+    /// let b: Counter<u8> = [1, 2, 2, 3].into_iter().collect();
+    /// let difference = a - b;
+    /// assert_eq!((difference.get(&1), difference.len()), (1, 1));
+    /// ```
+    fn sub(mut self, other: Counter<K>) -> Counter<K> {
+        for (key, count) in other.counts {
+            if let Some(existing) = self.counts.get_mut(&key) {
+                *existing = existing.saturating_sub(count);
+            }
+        }
+        self.counts.retain(|_, count| *count > 0);
+        self
+    }
+}
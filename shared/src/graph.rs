@@ -0,0 +1,310 @@
+//! Directed graphs with ordering and reachability queries.
+//!
+//! [`Graph`] stores nodes in insertion order with an adjacency list per node,
+//! so results such as a topological order are deterministic. Nodes are any
+//! small `Copy` key, typically a number or a grid position.
+
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::collections::VecDeque;
+use std::hash::Hash;
+
+/// Directed graph built from edge pairs.
+///
+/// # Attributes
+/// * `nodes` - Every node, in order of first appearance
+/// * `indices` - Position of each node in `nodes`
+/// * `adjacency` - Outgoing edges per node, as indices into `nodes`
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    indices: FxHashMap<N, usize>,
+    adjacency: Vec<Vec<usize>>,
+}
+
+impl<N: Copy + Eq + Hash> Default for Graph<N> {
+    fn default() -> Self {
+        Graph {
+            nodes: Vec::new(),
+            indices: FxHashMap::default(),
+            adjacency: Vec::new(),
+        }
+    }
+}
+
+impl<N: Copy + Eq + Hash> Graph<N> {
+    /// Creates an empty graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds a graph from `(from, to)` edge pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::graph::Graph;
+    /// let graph = Graph::from_edges([(47, 53), (97, 13), (97, 47)]);
+    /// assert_eq!(graph.node_count(), 4);
+    /// assert_eq!(graph.edge_count(), 3);
+    /// ```
+    pub fn from_edges(edges: impl IntoIterator<Item = (N, N)>) -> Self {
+        let mut graph = Self::new();
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    /// Adds a node if it is not already present.
+    ///
+    /// # Returns
+    /// The node's index
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&index) = self.indices.get(&node) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.nodes.push(node);
+        self.indices.insert(node, index);
+        self.adjacency.push(Vec::new());
+        index
+    }
+
+    /// Adds a directed edge, adding either node if needed.
+    pub fn add_edge(&mut self, from: N, to: N) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        if let Some(edges) = self.adjacency.get_mut(from) {
+            edges.push(to);
+        }
+    }
+
+    /// Returns `true` if the node is in the graph.
+    pub fn contains(&self, node: N) -> bool {
+        self.indices.contains_key(&node)
+    }
+
+    /// Returns the number of nodes.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of edges.
+    pub fn edge_count(&self) -> usize {
+        self.adjacency.iter().map(Vec::len).sum()
+    }
+
+    /// Iterates over the nodes in order of first appearance.
+    pub fn nodes(&self) -> impl Iterator<Item = N> + '_ {
+        self.nodes.iter().copied()
+    }
+
+    /// Iterates over the direct successors of a node.
+    ///
+    /// Yields nothing for a node that is not in the graph.
+    pub fn neighbors(&self, node: N) -> impl Iterator<Item = N> + '_ {
+        self.indices
+            .get(&node)
+            .and_then(|&index| self.adjacency.get(index))
+            .into_iter()
+            .flatten()
+            .filter_map(|&index| self.nodes.get(index).copied())
+    }
+
+    /// Returns the subgraph induced by the nodes that satisfy `keep`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::graph::Graph;
+    /// let graph = Graph::from_edges([(1, 2), (2, 3), (1, 3)]);
+    /// let without_two = graph.subgraph(|&node| node != 2);
+    /// assert_eq!(without_two.edge_count(), 1);
+    /// ```
+    pub fn subgraph(&self, keep: impl Fn(&N) -> bool) -> Graph<N> {
+        let mut subgraph = Graph::new();
+        for (from, edges) in self.nodes.iter().zip(&self.adjacency) {
+            if !keep(from) {
+                continue;
+            }
+            subgraph.add_node(*from);
+            for to in edges.iter().filter_map(|&index| self.nodes.get(index)) {
+                if keep(to) {
+                    subgraph.add_edge(*from, *to);
+                }
+            }
+        }
+        subgraph
+    }
+
+    /// Orders the nodes so every edge points forwards (Kahn's algorithm).
+    ///
+    /// Ties are broken by insertion order, so the result is deterministic.
+    ///
+    /// # Returns
+    /// The topological order, or `None` if the graph contains a cycle (see
+    /// [`find_cycle`](Self::find_cycle))
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::graph::Graph;
+    /// let graph = Graph::from_edges([(75, 47), (47, 61), (75, 61)]);
+    /// assert_eq!(graph.topological_sort(), Some(vec![75, 47, 61]));
+    /// assert_eq!(Graph::from_edges([(1, 2), (2, 1)]).topological_sort(), None);
+    /// ```
+    pub fn topological_sort(&self) -> Option<Vec<N>> {
+        let mut in_degree = vec![0usize; self.nodes.len()];
+        for &to in self.adjacency.iter().flatten() {
+            in_degree[to] += 1;
+        }
+
+        let mut queue: VecDeque<usize> = (0..self.nodes.len())
+            .filter(|&index| in_degree[index] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(index) = queue.pop_front() {
+            order.push(self.nodes[index]);
+            for &to in &self.adjacency[index] {
+                in_degree[to] -= 1;
+                if in_degree[to] == 0 {
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        // Nodes on a cycle never reach in-degree zero
+        (order.len() == self.nodes.len()).then_some(order)
+    }
+
+    /// Finds a cycle using depth-first search.
+    ///
+    /// # Returns
+    /// The nodes of one cycle in edge order (the last node links back to the
+    /// first), or `None` if the graph is acyclic
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::graph::Graph;
+    /// let graph = Graph::from_edges([(1, 2), (2, 3), (3, 2)]);
+    /// assert_eq!(graph.find_cycle(), Some(vec![2, 3]));
+    /// assert_eq!(Graph::from_edges([(1, 2)]).find_cycle(), None);
+    /// ```
+    pub fn find_cycle(&self) -> Option<Vec<N>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            Unvisited,
+            OnStack,
+            Done,
+        }
+
+        let mut state = vec![State::Unvisited; self.nodes.len()];
+        for root in 0..self.nodes.len() {
+            if state[root] != State::Unvisited {
+                continue;
+            }
+            // Iterative DFS: the path plus, per path entry, the next edge to
+            // try
+            let mut path = vec![root];
+            let mut next_edge = vec![0];
+            state[root] = State::OnStack;
+            while let (Some(&node), Some(edge)) = (path.last(), next_edge.last_mut()) {
+                let Some(&to) = self.adjacency[node].get(*edge) else {
+                    state[node] = State::Done;
+                    path.pop();
+                    next_edge.pop();
+                    continue;
+                };
+                *edge += 1;
+                match state[to] {
+                    State::Unvisited => {
+                        state[to] = State::OnStack;
+                        path.push(to);
+                        next_edge.push(0);
+                    }
+                    State::OnStack => {
+                        let start = path.iter().position(|&index| index == to)?;
+                        return Some(path[start..].iter().map(|&i| self.nodes[i]).collect());
+                    }
+                    State::Done => {}
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns `true` if the graph contains a cycle.
+    pub fn has_cycle(&self) -> bool {
+        self.find_cycle().is_some()
+    }
+
+    /// Collects every node reachable from `start`, including `start`.
+    ///
+    /// # Returns
+    /// The reachable nodes; empty if `start` is not in the graph
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::graph::Graph;
+    /// let graph = Graph::from_edges([(1, 2), (2, 3), (4, 1)]);
+    /// let reachable = graph.reachable_from(1);
+    /// assert!(reachable.contains(&3) && !reachable.contains(&4));
+    /// ```
+    pub fn reachable_from(&self, start: N) -> FxHashSet<N> {
+        let mut seen = FxHashSet::default();
+        let Some(&start) = self.indices.get(&start) else {
+            return seen;
+        };
+
+        let mut visited = vec![false; self.nodes.len()];
+        let mut stack = vec![start];
+        visited[start] = true;
+        while let Some(index) = stack.pop() {
+            seen.insert(self.nodes[index]);
+            for &to in &self.adjacency[index] {
+                if !visited[to] {
+                    visited[to] = true;
+                    stack.push(to);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Returns `true` if there is a path from `from` to `to`.
+    pub fn is_reachable(&self, from: N, to: N) -> bool {
+        self.reachable_from(from).contains(&to)
+    }
+
+    /// Checks that a sequence respects every edge between nodes it contains.
+    ///
+    /// For each edge whose endpoints both appear, every occurrence of the
+    /// source must precede every occurrence of the target. Nodes missing from
+    /// the sequence impose no constraint. Runs in O(N + E) time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::graph::Graph;
+    /// let graph = Graph::from_edges([(47, 53), (53, 29)]);
+    /// assert!(graph.is_consistent_order(&[47, 53, 29]));
+    /// assert!(graph.is_consistent_order(&[29, 47])); // no direct edge
+    /// assert!(!graph.is_consistent_order(&[53, 47]));
+    /// ```
+    pub fn is_consistent_order(&self, sequence: &[N]) -> bool {
+        let mut first = vec![usize::MAX; self.nodes.len()];
+        let mut last = vec![None; self.nodes.len()];
+        for (position, node) in sequence.iter().enumerate() {
+            if let Some(&index) = self.indices.get(node) {
+                first[index] = first[index].min(position);
+                last[index] = Some(position);
+            }
+        }
+
+        self.adjacency.iter().enumerate().all(|(from, edges)| {
+            last[from].is_none_or(|last_from| edges.iter().all(|&to| last_from < first[to]))
+        })
+    }
+}
@@ -0,0 +1,522 @@
+//! Rectangular grid type and character grid parsing.
+//!
+//! Many puzzles are played on a 2D map of characters. [`Grid`] stores the
+//! cells row-major in one vector and records its dimensions, so bounds checks
+//! are cheap and ragged input is caught at parse time. [`TorusGrid`] wraps a
+//! grid so that lookups wrap around its edges. Rotations, flips and diagonal
+//! iterators let a search over every orientation reuse one line scanner.
+
+use crate::input::ParseError;
+use crate::point::{Direction, Point};
+use anyhow::{bail, Result};
+
+/// Rectangular 2D grid stored in row-major order.
+///
+/// # Attributes
+/// * `cells` - All cells, row by row
+/// * `width` - Number of columns
+/// * `height` - Number of rows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from rows of equal length.
+    ///
+    /// # Parameters
+    /// * `rows` - The grid rows, top to bottom
+    ///
+    /// # Returns
+    /// The grid; no rows produce an empty 0x0 grid
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first row whose length differs from the
+    /// first row's.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::grid::Grid;
+    /// let grid = Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+    /// assert_eq!((grid.width(), grid.height()), (2, 2));
+    /// assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+        if let Some((index, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != width) {
+            bail!(
+                "Ragged grid: row {} has {} columns, expected {width}",
+                index + 1,
+                row.len()
+            );
+        }
+
+        Ok(Grid {
+            cells: rows.into_iter().flatten().collect(),
+            width,
+            height,
+        })
+    }
+
+    /// Returns the number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cell at a position, or `None` if it is out of bounds.
+    ///
+    /// # Parameters
+    /// * `row` - Row index (0-indexed)
+    /// * `col` - Column index (0-indexed)
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.height && col < self.width {
+            self.cells.get(row * self.width + col)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cell at a signed position, or `None` if it is out of
+    /// bounds.
+    ///
+    /// Convenient when walking from a cell by a direction offset, where the
+    /// walk may step past the top or left edge.
+    ///
+    /// # Parameters
+    /// * `row` - Row index, may be negative
+    /// * `col` - Column index, may be negative
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::grid::parse_char_grid;
+    /// let grid = parse_char_grid("AB\nCD").unwrap();
+    /// assert_eq!(grid.get_signed(1, 0), Some(&'C'));
+    /// assert_eq!(grid.get_signed(-1, 0), None);
+    /// ```
+    pub fn get_signed(&self, row: isize, col: isize) -> Option<&T> {
+        self.get(usize::try_from(row).ok()?, usize::try_from(col).ok()?)
+    }
+
+    /// Returns the cell at a [`Point`], or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::grid::parse_char_grid;
+    /// # use shared::point::{Direction, Point};
+    /// let grid = parse_char_grid("AB\nCD").unwrap();
+    /// assert_eq!(grid.get_point(Point(0, 0) + Direction::SouthEast), Some(&'D'));
+    /// assert_eq!(grid.get_point(Point(0, 0) + Direction::North), None);
+    /// ```
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(
+            usize::try_from(point.0).ok()?,
+            usize::try_from(point.1).ok()?,
+        )
+    }
+
+    /// Maps a point onto the grid by taking its indices modulo the height
+    /// and width.
+    ///
+    /// # Returns
+    /// The wrapped point, or `None` if the grid is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::grid::Grid;
+    /// # use shared::point::Point;
+    /// let grid = Grid::from_rows(vec![vec![0; 5]; 3]).unwrap();
+    /// assert_eq!(grid.wrap(Point(-1, 7)), Some(Point(2, 2)));
+    /// ```
+    pub fn wrap(&self, point: Point) -> Option<Point> {
+        let height = i64::try_from(self.height).ok().filter(|&h| h > 0)?;
+        let width = i64::try_from(self.width).ok().filter(|&w| w > 0)?;
+        Some(Point(point.0.rem_euclid(height), point.1.rem_euclid(width)))
+    }
+
+    /// Returns the cell at a point, wrapping around the edges.
+    ///
+    /// # Returns
+    /// The cell, or `None` only if the grid is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::grid::parse_char_grid;
+    /// # use shared::point::Point;
+    /// let grid = parse_char_grid("AB\nCD").unwrap();
+    /// assert_eq!(grid.get_wrapped(Point(-1, 2)), Some(&'C'));
+    /// ```
+    pub fn get_wrapped(&self, point: Point) -> Option<&T> {
+        self.get_point(self.wrap(point)?)
+    }
+
+    /// Returns a row as a slice, or `None` if it is out of bounds.
+    ///
+    /// # Parameters
+    /// * `row` - Row index (0-indexed)
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row >= self.height {
+            return None;
+        }
+        let start = row * self.width;
+        self.cells.get(start..start + self.width)
+    }
+
+    /// Iterates over the rows from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).filter_map(|row| self.row(row))
+    }
+
+    /// Iterates over every `(row, col)` position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| (row, col)))
+    }
+
+    /// Iterates over every `K`x`K` sub-window that fits inside the grid.
+    ///
+    /// Windows are visited in row-major order of their top-left corner. Each
+    /// is yielded with the position of its center, `(top + K / 2, left +
+    /// K / 2)`, so odd `K` gives the true center cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::grid::parse_char_grid;
+    /// let grid = parse_char_grid("#.#\n.#.\n#.#\n...").unwrap();
+    /// let crosses: Vec<(usize, usize)> = grid
+    ///     .windows::<3>()
+    ///     .filter(|(window, _)| matches!(window, [[&'#', _, &'#'], [_, &'#', _], [&'#', _, &'#']]))
+    ///     .map(|(_, center)| center)
+    ///     .collect();
+    /// assert_eq!(crosses, vec![(1, 1)]);
+    /// ```
+    pub fn windows<const K: usize>(
+        &self,
+    ) -> impl Iterator<Item = ([[&T; K]; K], (usize, usize))> + '_ {
+        let fits = |length: usize| {
+            if K == 0 {
+                0
+            } else {
+                (length + 1).saturating_sub(K)
+            }
+        };
+        let (rows, cols) = (fits(self.height), fits(self.width));
+        (0..rows)
+            .flat_map(move |top| (0..cols).map(move |left| (top, left)))
+            .map(move |(top, left)| {
+                let window = std::array::from_fn(|row| {
+                    std::array::from_fn(|col| &self.cells[(top + row) * self.width + left + col])
+                });
+                (window, (top + K / 2, left + K / 2))
+            })
+    }
+
+    /// Iterates over the diagonals running down and to the right.
+    ///
+    /// Starts at the bottom-left corner and ends at the top-right corner, so
+    /// a grid yields `width + height - 1` diagonals.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::grid::parse_char_grid;
+    /// let grid = parse_char_grid("AB\nCD").unwrap();
+    /// let diagonals: Vec<String> = grid.diagonals().map(|d| d.into_iter().collect()).collect();
+    /// assert_eq!(diagonals, vec!["C", "AD", "B"]);
+    /// ```
+    pub fn diagonals(&self) -> impl Iterator<Item = Vec<&T>> {
+        let (width, height) = (self.width, self.height);
+        (0..(width + height).saturating_sub(1)).map(move |index| {
+            let start = if index < height {
+                Point((height - 1 - index) as i64, 0)
+            } else {
+                Point(0, (index + 1 - height) as i64)
+            };
+            self.walk(start, Direction::SouthEast)
+        })
+    }
+
+    /// Iterates over the anti-diagonals running down and to the left.
+    ///
+    /// Starts at the top-left corner and ends at the bottom-right corner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::grid::parse_char_grid;
+    /// let grid = parse_char_grid("AB\nCD").unwrap();
+    /// let anti: Vec<String> = grid.anti_diagonals().map(|d| d.into_iter().collect()).collect();
+    /// assert_eq!(anti, vec!["A", "BC", "D"]);
+    /// ```
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = Vec<&T>> {
+        let (width, height) = (self.width, self.height);
+        (0..(width + height).saturating_sub(1)).map(move |index| {
+            let start = if index < width {
+                Point(0, index as i64)
+            } else {
+                Point((index + 1 - width) as i64, width as i64 - 1)
+            };
+            self.walk(start, Direction::SouthWest)
+        })
+    }
+
+    /// Collects cells from `start` in `direction` until leaving the grid.
+    fn walk(&self, start: Point, direction: Direction) -> Vec<&T> {
+        let mut cells = Vec::new();
+        let mut position = start;
+        while let Some(cell) = self.get_point(position) {
+            cells.push(cell);
+            position += direction;
+        }
+        cells
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Returns the grid mirrored along its main diagonal, so rows become
+    /// columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::grid::parse_char_grid;
+    /// let grid = parse_char_grid("ABC\nDEF").unwrap();
+    /// assert_eq!(grid.transpose(), parse_char_grid("AD\nBE\nCF").unwrap());
+    /// ```
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.height, self.width, |row, col| (col, row))
+    }
+
+    /// Returns the grid rotated a quarter turn clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::grid::parse_char_grid;
+    /// let grid = parse_char_grid("ABC\nDEF").unwrap();
+    /// assert_eq!(grid.rotate90(), parse_char_grid("DA\nEB\nFC").unwrap());
+    /// ```
+    pub fn rotate90(&self) -> Grid<T> {
+        let height = self.height;
+        self.remap(height, self.width, |row, col| (height - 1 - col, row))
+    }
+
+    /// Returns the grid mirrored left to right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::grid::parse_char_grid;
+    /// let grid = parse_char_grid("ABC\nDEF").unwrap();
+    /// assert_eq!(grid.flip_horizontal(), parse_char_grid("CBA\nFED").unwrap());
+    /// ```
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let width = self.width;
+        self.remap(width, self.height, |row, col| (row, width - 1 - col))
+    }
+
+    /// Returns the grid mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let height = self.height;
+        self.remap(self.width, height, |row, col| (height - 1 - row, col))
+    }
+
+    /// Builds a new `width` x `height` grid whose cell `(row, col)` is
+    /// copied from `source(row, col)` in this grid.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Grid<T> {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .filter_map(|(row, col)| {
+                let (source_row, source_col) = source(row, col);
+                self.get(source_row, source_col).cloned()
+            })
+            .collect();
+        Grid {
+            cells,
+            width,
+            height,
+        }
+    }
+}
+
+/// Grid whose edges wrap around, so every lookup lands on a cell.
+///
+/// Suits puzzles where things teleport to the opposite edge or the map
+/// repeats infinitely. Use [`Grid::get_wrapped`] instead when only a few
+/// queries need wrapping.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::grid::{parse_char_grid, TorusGrid};
+/// # use shared::point::{Direction, Point};
+/// let torus = TorusGrid::new(parse_char_grid("AB\nCD").unwrap());
+/// let mut position = Point(0, 0);
+/// position += Direction::North * 3;
+/// assert_eq!(torus.wrap(position), Point(1, 0));
+/// assert_eq!(torus.get(position), Some(&'C'));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TorusGrid<T> {
+    grid: Grid<T>,
+}
+
+impl<T> TorusGrid<T> {
+    /// Wraps a grid.
+    pub fn new(grid: Grid<T>) -> Self {
+        TorusGrid { grid }
+    }
+
+    /// Returns the underlying bounded grid.
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    /// Unwraps the underlying bounded grid.
+    pub fn into_inner(self) -> Grid<T> {
+        self.grid
+    }
+
+    /// Returns the number of columns in one tile.
+    pub fn width(&self) -> usize {
+        self.grid.width
+    }
+
+    /// Returns the number of rows in one tile.
+    pub fn height(&self) -> usize {
+        self.grid.height
+    }
+
+    /// Maps any point to its position within the tile.
+    ///
+    /// An empty grid leaves the point unchanged.
+    pub fn wrap(&self, point: Point) -> Point {
+        self.grid.wrap(point).unwrap_or(point)
+    }
+
+    /// Returns the cell at any point.
+    ///
+    /// # Returns
+    /// The cell, or `None` only if the grid is empty
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.grid.get_wrapped(point)
+    }
+}
+
+/// Parses a rectangular grid of characters, one row per line.
+///
+/// Surrounding whitespace (including `\r`) is stripped from each line and blank
+/// lines are skipped.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing the character grid
+///
+/// # Returns
+/// The parsed grid
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] locating the first line whose length differs
+/// from the first line's, counting blank lines like
+/// [`check_rectangular`](crate::validate::check_rectangular) does. Use
+/// [`parse_char_grid_padded`] for input with ragged rows.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::grid::parse_char_grid;
+/// # use shared::input::ParseError;
+/// let grid = parse_char_grid("ABC\nDEF").unwrap();
+/// assert_eq!(grid.row(1), Some(&['D', 'E', 'F'][..]));
+/// let error = parse_char_grid("ABC\n\nDE").unwrap_err();
+/// let error = error.downcast_ref::<ParseError>().unwrap();
+/// assert_eq!((error.line, error.text.as_str()), (3, "DE"));
+/// ```
+pub fn parse_char_grid(input: &str) -> Result<Grid<char>> {
+    let mut width = None;
+    let rows = grid_lines(input)
+        .map(|(index, raw, line)| {
+            let columns = line.chars().count();
+            let width = *width.get_or_insert(columns);
+            if columns != width {
+                return Err(ParseError {
+                    line: index + 1,
+                    ..ParseError::at_token(raw, line, format!("{width} columns"))
+                }
+                .into());
+            }
+            Ok(line.chars().collect())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Grid::from_rows(rows)
+}
+
+/// Parses a grid of characters, padding short rows to the widest row.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing the character grid
+/// * `fill` - Character used to pad rows shorter than the widest row
+///
+/// # Returns
+/// The parsed grid
+///
+/// # Examples
+///
+/// ```
+/// # use shared::grid::parse_char_grid_padded;
+/// let grid = parse_char_grid_padded("ABC\nD", '.');
+/// assert_eq!(grid.row(1), Some(&['D', '.', '.'][..]));
+/// ```
+pub fn parse_char_grid_padded(input: &str, fill: char) -> Grid<char> {
+    let mut rows: Vec<Vec<char>> = char_rows(input).collect();
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    for row in &mut rows {
+        row.resize(width, fill);
+    }
+
+    Grid {
+        height: rows.len(),
+        cells: rows.into_iter().flatten().collect(),
+        width,
+    }
+}
+
+/// Splits input into non-blank lines of characters.
+fn char_rows(input: &str) -> impl Iterator<Item = Vec<char>> + '_ {
+    grid_lines(input).map(|(_, _, line)| line.chars().collect())
+}
+
+/// Yields the non-blank lines of a grid with surrounding whitespace trimmed.
+///
+/// The one trimming rule shared by the grid parsers and validators. Each
+/// item is the 0-based line index, the raw line, and its trimmed text (a
+/// subslice of the raw line, so [`ParseError::at_token`] can locate
+/// characters in it).
+///
+/// [`ParseError::at_token`]: crate::input::ParseError::at_token
+pub(crate) fn grid_lines(input: &str) -> impl Iterator<Item = (usize, &str, &str)> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index, line, line.trim()))
+        .filter(|(_, _, trimmed)| !trimmed.is_empty())
+}
@@ -3,8 +3,129 @@
 //! This module provides common functionality for reading puzzle input files
 //! and parsing them into commonly used formats across different days.
 
-use anyhow::Result;
+pub mod fast;
+
+use crate::grid::grid_lines;
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+use std::fmt;
 use std::fs;
+use std::io::{self, BufRead};
+use std::str::FromStr;
+
+/// Error returned when input does not contain the expected number of
+/// blank-line separated sections.
+///
+/// # Attributes
+/// * `expected` - Number of sections the caller required
+/// * `found` - Number of non-empty sections present in the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SectionCountError {
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl fmt::Display for SectionCountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Input must have exactly {} sections, found {}",
+            self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for SectionCountError {}
+
+/// Error describing malformed input at a specific location.
+///
+/// Converts into `anyhow::Error`, so parsers can return it with `?`. Tests
+/// can recover it with `error.downcast_ref::<ParseError>()` and assert on the
+/// fields instead of on message substrings.
+///
+/// # Attributes
+/// * `line` - 1-based line number of the offending text
+/// * `column` - 1-based column where the offending text starts
+/// * `text` - The offending text
+/// * `expected` - Description of the format that was expected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub expected: String,
+}
+
+impl ParseError {
+    /// Creates an error for a token within a line.
+    ///
+    /// The line number is left at 0 for [`parse_lines_with`] (or
+    /// [`stream_lines_with`]) to fill in, so per-line parsers only need to
+    /// know the line they were given.
+    ///
+    /// # Parameters
+    /// * `line` - The line being parsed
+    /// * `token` - The offending text; must be a subslice of `line` for the
+    ///   column to be accurate, otherwise column 1 is reported
+    /// * `expected` - Description of the expected format
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::input::{parse_lines_with, ParseError};
+    /// let error = parse_lines_with("1 2\n3 x", |line| {
+    ///     let token = line.split(' ').nth(1).unwrap_or(line);
+    ///     token
+    ///         .parse::<u32>()
+    ///         .map_err(|_| ParseError::at_token(line, token, "number").into())
+    /// })
+    /// .unwrap_err();
+    /// let error = error.downcast_ref::<ParseError>().unwrap();
+    /// assert_eq!((error.line, error.column), (2, 3));
+    /// assert_eq!(error.text, "x");
+    /// ```
+    pub fn at_token(line: &str, token: &str, expected: impl Into<String>) -> Self {
+        let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        ParseError {
+            line: 0,
+            column: if offset <= line.len() { offset + 1 } else { 1 },
+            text: token.to_string(),
+            expected: expected.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Line {}, column {}: expected {}, found '{}'",
+            self.line, self.column, self.expected, self.text
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Attaches a line number to an error produced by a per-line parser.
+///
+/// [`ParseError`]s get their `line` set and their column shifted by the
+/// trimmed indentation; other errors are prefixed with `Line N: `.
+pub(crate) fn locate_error(
+    error: anyhow::Error,
+    line_number: usize,
+    indent: usize,
+) -> anyhow::Error {
+    match error.downcast::<ParseError>() {
+        Ok(error) => ParseError {
+            line: line_number,
+            column: error.column + indent,
+            ..error
+        }
+        .into(),
+        Err(error) => anyhow!("Line {line_number}: {error:#}"),
+    }
+}
 
 /// Reads the puzzle input file for a specific Advent of Code day.
 ///
@@ -45,3 +166,675 @@ pub fn read_input(day: u8) -> Result<String> {
     let filename = format!("day{day:02}/input.txt");
     Ok(fs::read_to_string(filename)?)
 }
+
+/// Which input [`read_input_or_example`] returned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputSource {
+    /// The real puzzle input from `dayXX/input.txt`
+    Real,
+    /// The example input, because no `input.txt` exists
+    Example,
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            InputSource::Real => "real input",
+            InputSource::Example => "example input (no input.txt)",
+        })
+    }
+}
+
+/// Reads the real puzzle input, falling back to the example if it is
+/// missing.
+///
+/// # Parameters
+/// * `day` - The day number (1-25)
+/// * `example` - The day's example input
+///
+/// # Returns
+/// The input text and which source it came from
+///
+/// # Errors
+///
+/// Returns an error if `input.txt` exists but cannot be read. A missing file
+/// is not an error.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::{read_input_or_example, InputSource};
+/// let (input, source) = read_input_or_example(25, "example")?;
+/// assert_eq!((input.as_str(), source), ("example", InputSource::Example));
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn read_input_or_example(day: u8, example: &str) -> Result<(String, InputSource)> {
+    match read_input(day) {
+        Ok(input) => Ok((input, InputSource::Real)),
+        Err(error)
+            if error
+                .downcast_ref::<io::Error>()
+                .is_some_and(|error| error.kind() == io::ErrorKind::NotFound) =>
+        {
+            Ok((example.to_string(), InputSource::Example))
+        }
+        Err(error) => Err(error),
+    }
+}
+
+/// Summary statistics of a puzzle input.
+///
+/// # Attributes
+/// * `lines` - Number of lines
+/// * `bytes` - Size in bytes
+/// * `max_line_length` - Length of the longest line in characters
+/// * `histogram` - Occurrences of each character, excluding line breaks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputStats {
+    pub lines: usize,
+    pub bytes: usize,
+    pub max_line_length: usize,
+    pub histogram: BTreeMap<char, usize>,
+}
+
+impl fmt::Display for InputStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Lines: {}, bytes: {}, longest line: {}",
+            self.lines, self.bytes, self.max_line_length
+        )?;
+        write!(f, "Characters:")?;
+        for (c, count) in &self.histogram {
+            write!(f, " {c:?}×{count}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Computes summary statistics of a puzzle input.
+///
+/// Useful to sanity-check a downloaded input and to size benchmark input
+/// generators realistically.
+///
+/// # Parameters
+/// * `input` - Raw puzzle input
+///
+/// # Returns
+/// Line count, byte count, longest line length and character histogram
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::stats;
+/// let stats = stats("3   4\n12  3\n");
+/// assert_eq!((stats.lines, stats.bytes, stats.max_line_length), (2, 12, 5));
+/// assert_eq!(stats.histogram[&' '], 5);
+/// assert_eq!(stats.histogram[&'3'], 2);
+/// ```
+pub fn stats(input: &str) -> InputStats {
+    let mut histogram = BTreeMap::new();
+    for c in input.chars().filter(|&c| c != '\n' && c != '\r') {
+        *histogram.entry(c).or_insert(0) += 1;
+    }
+
+    InputStats {
+        lines: input.lines().count(),
+        bytes: input.len(),
+        max_line_length: input
+            .lines()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0),
+        histogram,
+    }
+}
+
+/// Splits input into sections separated by blank lines.
+///
+/// A line containing only whitespace ends the current section, so both
+/// `\n\n` and `\r\n\r\n` separators work. Each section is trimmed and
+/// empty sections (e.g. from repeated blank lines) are dropped.
+///
+/// # Parameters
+/// * `input` - Raw puzzle input
+///
+/// # Returns
+/// Trimmed, non-empty sections in input order, borrowed from `input`
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::split_sections;
+/// let sections = split_sections("a\nb\n\n\n\nc\n");
+/// assert_eq!(sections, vec!["a\nb", "c"]);
+/// ```
+pub fn split_sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = 0;
+    let mut offset = 0;
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            sections.push(&input[start..offset]);
+            start = offset + line.len();
+        }
+        offset += line.len();
+    }
+    sections.push(&input[start..]);
+
+    sections
+        .into_iter()
+        .map(str::trim)
+        .filter(|section| !section.is_empty())
+        .collect()
+}
+
+/// Splits input into exactly two blank-line separated sections.
+///
+/// # Parameters
+/// * `input` - Raw puzzle input
+///
+/// # Returns
+/// The two trimmed sections, as returned by [`split_sections`]
+///
+/// # Errors
+///
+/// Returns a [`SectionCountError`] if the input does not contain exactly two
+/// non-empty sections.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::split_two_sections;
+/// let (rules, updates) = split_two_sections("47|53\n\n75,47").unwrap();
+/// assert_eq!((rules, updates), ("47|53", "75,47"));
+/// assert!(split_two_sections("47|53").is_err());
+/// ```
+pub fn split_two_sections(input: &str) -> Result<(&str, &str), SectionCountError> {
+    match split_sections(input)[..] {
+        [first, second] => Ok((first, second)),
+        ref sections => Err(SectionCountError {
+            expected: 2,
+            found: sections.len(),
+        }),
+    }
+}
+
+/// Parses each non-blank line with a custom parser.
+///
+/// Errors from `parse` are located on the 1-based line number, so callers
+/// only need to describe what is wrong with the line itself: a
+/// [`ParseError`] gets its `line` filled in, any other error is prefixed with
+/// `Line N: `.
+///
+/// # Parameters
+/// * `input` - Multi-line string with one record per line
+/// * `parse` - Parser applied to each trimmed, non-blank line
+///
+/// # Returns
+/// One parsed record per non-blank line
+///
+/// # Errors
+///
+/// Returns the first parser error, located as described above.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::parse_lines_with;
+/// let lengths = parse_lines_with("ab\n\ncde", |line| Ok(line.len())).unwrap();
+/// assert_eq!(lengths, vec![2, 3]);
+/// ```
+pub fn parse_lines_with<T>(input: &str, parse: impl Fn(&str) -> Result<T>) -> Result<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            parse(line.trim()).map_err(|e| locate_error(e, index + 1, indentation(line)))
+        })
+        .collect()
+}
+
+/// Calls a visitor on each non-blank line without collecting results.
+///
+/// The allocation-free counterpart of [`parse_lines_with`] for parsers that
+/// write into buffers of their own. Lines are trimmed, and errors carry
+/// their line number as in [`parse_lines_with`].
+///
+/// # Parameters
+/// * `input` - Multi-line string to visit
+/// * `visit` - Called with each trimmed, non-blank line
+///
+/// # Errors
+///
+/// Returns the first error of `visit`, located at its line; later lines are
+/// not visited.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::try_for_each_line;
+/// let mut total = 0;
+/// try_for_each_line("1\n\n2", |line| {
+///     total += line.parse::<u32>()?;
+///     Ok(())
+/// })
+/// .unwrap();
+/// assert_eq!(total, 3);
+/// ```
+pub fn try_for_each_line(input: &str, mut visit: impl FnMut(&str) -> Result<()>) -> Result<()> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .try_for_each(|(index, line)| {
+            visit(line.trim()).map_err(|e| locate_error(e, index + 1, indentation(line)))
+        })
+}
+
+/// Returns the number of bytes of leading whitespace in a line.
+pub(crate) fn indentation(line: &str) -> usize {
+    line.len() - line.trim_start().len()
+}
+
+/// Parses each non-blank line into a value of type `T`.
+///
+/// Lines are trimmed before parsing. For lines holding several
+/// whitespace-separated values use [`parse_number_rows`].
+///
+/// # Parameters
+/// * `input` - Multi-line string with one value per line
+///
+/// # Returns
+/// One parsed value per non-blank line
+///
+/// # Errors
+///
+/// Returns an error naming the line number and text of the first line that
+/// cannot be parsed.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::parse_lines_as;
+/// let values: Vec<u64> = parse_lines_as("10\n\n20\n").unwrap();
+/// assert_eq!(values, vec![10, 20]);
+/// let error = parse_lines_as::<u64>("10\nabc").unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "Line 2: invalid value 'abc': invalid digit found in string"
+/// );
+/// ```
+pub fn parse_lines_as<T>(input: &str) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    parse_lines_with(input, |line| {
+        line.parse()
+            .map_err(|e| anyhow!("invalid value '{line}': {e}"))
+    })
+}
+
+/// Separator between the values of a delimited tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delimiter {
+    /// Any run of whitespace, e.g. `3   4`
+    Whitespace,
+    /// A single character with optional whitespace around it, e.g. `47 | 53`
+    Char(char),
+}
+
+impl From<char> for Delimiter {
+    fn from(c: char) -> Self {
+        Delimiter::Char(c)
+    }
+}
+
+impl fmt::Display for Delimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Delimiter::Whitespace => f.write_str("whitespace"),
+            Delimiter::Char(c) => write!(f, "'{c}'"),
+        }
+    }
+}
+
+/// Parses one token of a line into a value of type `T`.
+///
+/// Surrounding whitespace is ignored.
+///
+/// # Parameters
+/// * `line` - The line containing the token (used for the error column)
+/// * `token` - Subslice of `line` to parse
+/// * `expected` - What the token should be, in the puzzle's terms (e.g.
+///   "a page number")
+///
+/// # Returns
+/// The parsed value
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] carrying `expected` if the token is not a `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::{parse_token, ParseError};
+/// let line = "75,x";
+/// let error = parse_token::<u32>(line, &line[3..], "a page").unwrap_err();
+/// let error = error.downcast_ref::<ParseError>().unwrap();
+/// assert_eq!((error.column, error.expected.as_str()), (4, "a page"));
+/// ```
+pub fn parse_token<T: FromStr>(line: &str, token: &str, expected: &str) -> Result<T> {
+    let token = token.trim();
+    token
+        .parse()
+        .map_err(|_| ParseError::at_token(line, token, expected).into())
+}
+
+/// Parses a line of exactly `N` delimited values.
+///
+/// # Parameters
+/// * `line` - The line to parse
+/// * `delimiter` - Separator between values (a `char` converts implicitly)
+/// * `expected` - What each value should be, in the puzzle's terms
+///
+/// # Returns
+/// The `N` parsed values in order
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the line does not contain exactly `N` values
+/// or any value cannot be parsed as `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::parse_tuple_n;
+/// let [x, y, z] = parse_tuple_n::<i32, 3>("1, -2, 3", ',', "a coordinate")?;
+/// assert_eq!((x, y, z), (1, -2, 3));
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn parse_tuple_n<T: FromStr, const N: usize>(
+    line: &str,
+    delimiter: impl Into<Delimiter>,
+    expected: &str,
+) -> Result<[T; N]> {
+    let delimiter = delimiter.into();
+    let tokens: Vec<&str> = match delimiter {
+        Delimiter::Whitespace => line.split_whitespace().collect(),
+        Delimiter::Char(c) => line.split(c).collect(),
+    };
+    let wrong_count = || {
+        ParseError::at_token(
+            line,
+            line.trim(),
+            format!("{N} values separated by {delimiter}"),
+        )
+    };
+    if tokens.len() != N {
+        return Err(wrong_count().into());
+    }
+
+    let values = tokens
+        .into_iter()
+        .map(|token| parse_token(line, token, expected))
+        .collect::<Result<Vec<T>>>()?;
+    values.try_into().map_err(|_| wrong_count().into())
+}
+
+/// Parses a line of exactly two delimited values.
+///
+/// # Parameters
+/// * `line` - The line to parse
+/// * `delimiter` - Separator between the values (a `char` converts
+///   implicitly)
+/// * `expected` - What each value should be, in the puzzle's terms
+///
+/// # Returns
+/// The two parsed values
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the line does not contain exactly two values
+/// or either value cannot be parsed as `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::{parse_pair, Delimiter};
+/// let ids = parse_pair::<i32>("3   4", Delimiter::Whitespace, "an ID")?;
+/// assert_eq!(ids, (3, 4));
+/// assert_eq!(parse_pair::<u32>("47 | 53", '|', "a page")?, (47, 53));
+/// assert!(parse_pair::<u32>("47|53|61", '|', "a page").is_err());
+/// # Ok::<(), anyhow::Error>(())
+/// ```
+pub fn parse_pair<T: FromStr>(
+    line: &str,
+    delimiter: impl Into<Delimiter>,
+    expected: &str,
+) -> Result<(T, T)> {
+    // Split by hand rather than through `parse_tuple_n`, so valid lines are
+    // parsed without allocating
+    let delimiter = delimiter.into();
+    let tokens = match delimiter {
+        Delimiter::Whitespace => {
+            let mut tokens = line.split_whitespace();
+            (tokens.next(), tokens.next(), tokens.next())
+        }
+        Delimiter::Char(c) => {
+            let mut tokens = line.split(c);
+            (tokens.next(), tokens.next(), tokens.next())
+        }
+    };
+    match tokens {
+        (Some(first), Some(second), None) => Ok((
+            parse_token(line, first, expected)?,
+            parse_token(line, second, expected)?,
+        )),
+        _ => Err(ParseError::at_token(
+            line,
+            line.trim(),
+            format!("2 values separated by {delimiter}"),
+        )
+        .into()),
+    }
+}
+
+/// Lazy iterator over parsed records from a [`BufRead`] source.
+///
+/// Created by [`stream_lines_with`] and [`stream_lines_as`]. Reuses one line
+/// buffer, so memory stays flat regardless of input size.
+pub struct LineRecords<R, F> {
+    reader: R,
+    parse: F,
+    buffer: String,
+    line_number: usize,
+}
+
+impl<R, F, T> Iterator for LineRecords<R, F>
+where
+    R: BufRead,
+    F: FnMut(&str) -> Result<T>,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.buffer.clear();
+            self.line_number += 1;
+            match self.reader.read_line(&mut self.buffer) {
+                Ok(0) => return None,
+                Ok(_) => {}
+                Err(e) => return Some(Err(anyhow!("Line {}: {e}", self.line_number))),
+            }
+
+            let line = self.buffer.trim();
+            if !line.is_empty() {
+                let (line_number, indent) = (self.line_number, indentation(&self.buffer));
+                return Some((self.parse)(line).map_err(|e| locate_error(e, line_number, indent)));
+            }
+        }
+    }
+}
+
+/// Lazily parses each non-blank line of a reader with a custom parser.
+///
+/// The streaming counterpart of [`parse_lines_with`]: records are produced
+/// one at a time, so input can come from stdin or a pipe and very large
+/// inputs never need to fit in memory.
+///
+/// # Parameters
+/// * `reader` - Buffered source of the input (file, stdin, byte slice, ...)
+/// * `parse` - Parser applied to each trimmed, non-blank line
+///
+/// # Returns
+/// An iterator yielding one result per non-blank line; errors carry their
+/// line number as in [`parse_lines_with`]
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::stream_lines_with;
+/// let reader = "3   4\n4   3\n".as_bytes();
+/// let sum: i32 = stream_lines_with(reader, |line| Ok(line.len() as i32))
+///     .sum::<anyhow::Result<i32>>()
+///     .unwrap();
+/// assert_eq!(sum, 10);
+/// ```
+pub fn stream_lines_with<R, F, T>(reader: R, parse: F) -> LineRecords<R, F>
+where
+    R: BufRead,
+    F: FnMut(&str) -> Result<T>,
+{
+    LineRecords {
+        reader,
+        parse,
+        buffer: String::new(),
+        line_number: 0,
+    }
+}
+
+/// Lazily parses each non-blank line of a reader into a value of type `T`.
+///
+/// The streaming counterpart of [`parse_lines_as`].
+///
+/// # Parameters
+/// * `reader` - Buffered source of the input (file, stdin, byte slice, ...)
+///
+/// # Returns
+/// An iterator yielding one parsed value per non-blank line
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::stream_lines_as;
+/// let values: Vec<u32> = stream_lines_as("1\n\n2\n".as_bytes())
+///     .collect::<anyhow::Result<_>>()
+///     .unwrap();
+/// assert_eq!(values, vec![1, 2]);
+/// ```
+pub fn stream_lines_as<R, T>(reader: R) -> impl Iterator<Item = Result<T>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    stream_lines_with(reader, |line: &str| {
+        line.parse()
+            .map_err(|e| anyhow!("invalid value '{line}': {e}"))
+    })
+}
+
+/// Parses a grid of single decimal digits, one row per line.
+///
+/// Lines are trimmed like [`parse_char_grid`](crate::grid::parse_char_grid)
+/// trims them and blank lines are skipped. Rows may have different lengths.
+///
+/// # Parameters
+/// * `input` - Multi-line string of digit characters (e.g. "0123\n4567")
+///
+/// # Returns
+/// One vector of digit values per non-blank line
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] locating the first character that is not a
+/// decimal digit.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::{parse_digit_grid, ParseError};
+/// let grid = parse_digit_grid("012\n  345").unwrap();
+/// assert_eq!(grid, vec![vec![0, 1, 2], vec![3, 4, 5]]);
+/// let error = parse_digit_grid("012\n3x5").unwrap_err();
+/// let error = error.downcast_ref::<ParseError>().unwrap();
+/// assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "x"));
+/// ```
+pub fn parse_digit_grid(input: &str) -> Result<Vec<Vec<u8>>> {
+    grid_lines(input)
+        .map(|(index, raw, line)| {
+            line.char_indices()
+                .map(|(offset, c)| {
+                    c.to_digit(10)
+                        .and_then(|digit| u8::try_from(digit).ok())
+                        .ok_or_else(|| {
+                            let token = &line[offset..offset + c.len_utf8()];
+                            ParseError {
+                                line: index + 1,
+                                ..ParseError::at_token(raw, token, "a decimal digit")
+                            }
+                            .into()
+                        })
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Parses whitespace-separated values, one row per line.
+///
+/// Blank lines are skipped. Rows may have different lengths.
+///
+/// # Parameters
+/// * `input` - Multi-line string of whitespace-separated tokens
+/// * `expected` - What each value should be, in the puzzle's terms
+///
+/// # Returns
+/// One vector of parsed values per non-blank line
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] locating the first token that cannot be parsed
+/// as `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::{parse_number_rows, ParseError};
+/// let rows: Vec<Vec<i32>> = parse_number_rows("1 2 3\n4 5", "a number").unwrap();
+/// assert_eq!(rows, vec![vec![1, 2, 3], vec![4, 5]]);
+/// let error = parse_number_rows::<i32>("1 2\n3 x", "a number").unwrap_err();
+/// assert_eq!(
+///     error.to_string(),
+///     "Line 2, column 3: expected a number, found 'x'"
+/// );
+/// ```
+pub fn parse_number_rows<T: FromStr>(input: &str, expected: &str) -> Result<Vec<Vec<T>>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            line.split_whitespace()
+                .map(|token| {
+                    parse_token(line, token, expected)
+                        .map_err(|error| locate_error(error, index + 1, 0))
+                })
+                .collect()
+        })
+        .collect()
+}
@@ -0,0 +1,312 @@
+//! Byte-slice parsing for hot loops.
+//!
+//! Works directly on `&[u8]`, skipping UTF-8 validation and `str::parse`.
+//! AoC input is ASCII, so solvers can call `input.as_bytes()` and scan with a
+//! [`Cursor`]. Readers return `None` instead of an error to stay cheap;
+//! callers decide how to report malformed input. The `next_*` scanners
+//! report an overflowing number as [`Overflow`], so it is not mistaken for
+//! the end of the input.
+
+use std::fmt;
+
+/// Forward-only scanner over a byte slice.
+///
+/// # Attributes
+/// * `bytes` - The bytes being scanned
+/// * `pos` - Index of the next unread byte
+#[derive(Debug, Clone)]
+pub struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    /// Creates a cursor at the start of `bytes`.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Cursor { bytes, pos: 0 }
+    }
+
+    /// Returns the index of the next unread byte.
+    pub fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns `true` once every byte has been consumed.
+    pub fn is_at_end(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+
+    /// Returns the unread bytes.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.bytes.get(self.pos..).unwrap_or_default()
+    }
+
+    /// Returns the next byte without consuming it.
+    pub fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    /// Consumes and returns the next byte.
+    pub fn next_byte(&mut self) -> Option<u8> {
+        let byte = self.peek()?;
+        self.pos += 1;
+        Some(byte)
+    }
+
+    /// Consumes `byte` if it is next.
+    ///
+    /// # Returns
+    /// `true` if the byte was present and consumed
+    pub fn skip_byte(&mut self, byte: u8) -> bool {
+        let matched = self.peek() == Some(byte);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    /// Consumes `prefix` if the unread bytes start with it.
+    ///
+    /// # Returns
+    /// `true` if the prefix was present and consumed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::input::fast::Cursor;
+    /// let mut cursor = Cursor::new(b"mul(2,4)");
+    /// assert!(cursor.skip_prefix(b"mul("));
+    /// assert_eq!(cursor.read_unsigned(), Some(2));
+    /// ```
+    pub fn skip_prefix(&mut self, prefix: &[u8]) -> bool {
+        let matched = self.remaining().starts_with(prefix);
+        if matched {
+            self.pos += prefix.len();
+        }
+        matched
+    }
+
+    /// Consumes bytes while `predicate` holds.
+    pub fn skip_while(&mut self, predicate: impl Fn(u8) -> bool) {
+        while self.peek().is_some_and(&predicate) {
+            self.pos += 1;
+        }
+    }
+
+    /// Consumes ASCII whitespace, including newlines.
+    pub fn skip_whitespace(&mut self) {
+        self.skip_while(|byte| byte.is_ascii_whitespace());
+    }
+
+    /// Consumes bytes up to and including the next `delimiter`.
+    ///
+    /// Consumes everything if the delimiter does not occur.
+    ///
+    /// # Returns
+    /// The bytes before the delimiter
+    pub fn skip_past(&mut self, delimiter: u8) -> &'a [u8] {
+        let rest = self.remaining();
+        let length = rest
+            .iter()
+            .position(|&byte| byte == delimiter)
+            .unwrap_or(rest.len());
+        self.pos += (length + 1).min(rest.len());
+        &rest[..length]
+    }
+
+    /// Reads an unsigned decimal integer at the cursor.
+    ///
+    /// # Returns
+    /// The value, or `None` (consuming nothing) if no digit is next or the
+    /// value overflows `u64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::input::fast::Cursor;
+    /// let mut cursor = Cursor::new(b"123 45");
+    /// assert_eq!(cursor.read_unsigned(), Some(123));
+    /// assert_eq!(cursor.read_unsigned(), None); // space is next
+    /// ```
+    pub fn read_unsigned(&mut self) -> Option<u64> {
+        let (value, length) = parse_unsigned_prefix(self.remaining())?;
+        self.pos += length;
+        Some(value)
+    }
+
+    /// Reads a signed decimal integer with an optional `-` or `+` sign.
+    ///
+    /// # Returns
+    /// The value, or `None` (consuming nothing) if no number is next or the
+    /// value overflows `i64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::input::fast::Cursor;
+    /// let mut cursor = Cursor::new(b"-12,+7");
+    /// assert_eq!(cursor.read_signed(), Some(-12));
+    /// assert!(cursor.skip_byte(b','));
+    /// assert_eq!(cursor.read_signed(), Some(7));
+    /// ```
+    pub fn read_signed(&mut self) -> Option<i64> {
+        let rest = self.remaining();
+        let (negative, sign_length) = match rest.first() {
+            Some(b'-') => (true, 1),
+            Some(b'+') => (false, 1),
+            _ => (false, 0),
+        };
+        let (magnitude, length) = parse_unsigned_prefix(rest.get(sign_length..)?)?;
+        let magnitude = i128::from(magnitude);
+        let value = i64::try_from(if negative { -magnitude } else { magnitude }).ok()?;
+        self.pos += sign_length + length;
+        Some(value)
+    }
+
+    /// Skips to the next digit and reads the unsigned integer there.
+    ///
+    /// Handy for extracting every number from loosely formatted lines.
+    ///
+    /// # Returns
+    /// The next number, `Some(Err(Overflow))` if it does not fit in `u64`, or
+    /// `None` once no digits remain. An overflowing number is consumed, so
+    /// the next call resumes after it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::input::fast::{Cursor, Overflow};
+    /// let mut cursor = Cursor::new(b"Button A: X+94, Y+34");
+    /// assert_eq!(cursor.next_unsigned(), Some(Ok(94)));
+    /// assert_eq!(cursor.next_unsigned(), Some(Ok(34)));
+    /// assert_eq!(cursor.next_unsigned(), None);
+    ///
+    /// let mut cursor = Cursor::new(b"99999999999999999999 7");
+    /// assert_eq!(cursor.next_unsigned(), Some(Err(Overflow)));
+    /// assert_eq!(cursor.next_unsigned(), Some(Ok(7)));
+    /// ```
+    pub fn next_unsigned(&mut self) -> Option<Result<u64, Overflow>> {
+        self.skip_while(|byte| !byte.is_ascii_digit());
+        self.peek()?;
+        Some(self.read_unsigned().ok_or_else(|| {
+            self.skip_while(|byte| byte.is_ascii_digit());
+            Overflow
+        }))
+    }
+
+    /// Skips to the next digit or `-` and reads the signed integer there.
+    ///
+    /// A `-` directly before a digit is treated as a sign.
+    ///
+    /// # Returns
+    /// The next number, `Some(Err(Overflow))` if it does not fit in `i64`, or
+    /// `None` once no digits remain. An overflowing number is consumed, so
+    /// the next call resumes after it rather than partway through its
+    /// digits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::input::fast::{Cursor, Overflow};
+    /// let mut cursor = Cursor::new(b"p=0,4 v=3,-3");
+    /// let numbers: Result<Vec<i64>, Overflow> =
+    ///     std::iter::from_fn(|| cursor.next_signed()).collect();
+    /// assert_eq!(numbers, Ok(vec![0, 4, 3, -3]));
+    ///
+    /// let mut cursor = Cursor::new(b"-99999999999999999999,5");
+    /// assert_eq!(cursor.next_signed(), Some(Err(Overflow)));
+    /// assert_eq!(cursor.next_signed(), Some(Ok(5)));
+    /// ```
+    pub fn next_signed(&mut self) -> Option<Result<i64, Overflow>> {
+        loop {
+            self.skip_while(|byte| !byte.is_ascii_digit() && byte != b'-');
+            self.peek()?;
+            if let Some(value) = self.read_signed() {
+                return Some(Ok(value));
+            }
+            if self.skip_byte(b'-') && !self.peek().is_some_and(|byte| byte.is_ascii_digit()) {
+                // A lone '-' that does not start a number
+                continue;
+            }
+            // The number overflows; skip the rest of its digits
+            self.skip_while(|byte| byte.is_ascii_digit());
+            return Some(Err(Overflow));
+        }
+    }
+}
+
+/// Error for a number that does not fit in the requested integer type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Number overflows the integer type")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Parses an entire byte slice as an unsigned decimal integer.
+///
+/// # Parameters
+/// * `bytes` - ASCII digits with no sign or surrounding whitespace
+///
+/// # Returns
+/// The value, or `None` if the slice is empty, contains a non-digit, or
+/// overflows `u64`
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::fast::parse_unsigned;
+/// assert_eq!(parse_unsigned(b"1603498"), Some(1_603_498));
+/// assert_eq!(parse_unsigned(b"12a"), None);
+/// ```
+pub fn parse_unsigned(bytes: &[u8]) -> Option<u64> {
+    match parse_unsigned_prefix(bytes)? {
+        (value, length) if length == bytes.len() => Some(value),
+        _ => None,
+    }
+}
+
+/// Parses an entire byte slice as a signed decimal integer.
+///
+/// # Parameters
+/// * `bytes` - ASCII digits with an optional leading `-` or `+`
+///
+/// # Returns
+/// The value, or `None` if the slice is not a complete number or overflows
+/// `i64`
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::fast::parse_signed;
+/// assert_eq!(parse_signed(b"-42"), Some(-42));
+/// assert_eq!(parse_signed(b"-"), None);
+/// ```
+pub fn parse_signed(bytes: &[u8]) -> Option<i64> {
+    let mut cursor = Cursor::new(bytes);
+    cursor.read_signed().filter(|_| cursor.is_at_end())
+}
+
+/// Parses the leading run of ASCII digits.
+///
+/// # Returns
+/// The value and the number of digits consumed, or `None` if there are no
+/// leading digits or the value overflows `u64`
+fn parse_unsigned_prefix(bytes: &[u8]) -> Option<(u64, usize)> {
+    let length = bytes
+        .iter()
+        .position(|byte| !byte.is_ascii_digit())
+        .unwrap_or(bytes.len());
+    if length == 0 {
+        return None;
+    }
+
+    let value = bytes[..length].iter().try_fold(0u64, |value, &digit| {
+        value.checked_mul(10)?.checked_add(u64::from(digit - b'0'))
+    })?;
+    Some((value, length))
+}
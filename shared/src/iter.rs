@@ -0,0 +1,186 @@
+//! Iterator helpers for recurring puzzle patterns.
+//!
+//! Import [`AocIterExt`] to add the methods to every iterator. They cover
+//! patterns that are otherwise rebuilt inline with slightly different
+//! itertools chains in each day crate.
+
+use anyhow::{bail, Result};
+
+/// Integer types that support overflow-checked addition.
+pub trait CheckedAdd: Sized {
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// Adds two values, returning `None` on overflow.
+    fn checked_add(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_add {
+    ($($t:ty),*) => {
+        $(impl CheckedAdd for $t {
+            const ZERO: Self = 0;
+
+            fn checked_add(self, other: Self) -> Option<Self> {
+                <$t>::checked_add(self, other)
+            }
+        })*
+    };
+}
+
+impl_checked_add!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Extension methods available on every iterator.
+pub trait AocIterExt: Iterator + Sized {
+    /// Sums the items, detecting overflow.
+    ///
+    /// # Returns
+    /// The sum, or `None` if it overflows the item type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::iter::AocIterExt;
+    /// assert_eq!([1u8, 2, 3].into_iter().sum_checked(), Some(6));
+    /// assert_eq!([200u8, 100].into_iter().sum_checked(), None);
+    /// ```
+    fn sum_checked(mut self) -> Option<Self::Item>
+    where
+        Self::Item: CheckedAdd,
+    {
+        self.try_fold(Self::Item::ZERO, CheckedAdd::checked_add)
+    }
+
+    /// Returns the only item.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the iterator yields no items or more than one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::iter::AocIterExt;
+    /// assert_eq!([7].into_iter().exactly_one_ok().unwrap(), 7);
+    /// assert!([1, 2].into_iter().exactly_one_ok().is_err());
+    /// ```
+    fn exactly_one_ok(mut self) -> Result<Self::Item> {
+        let Some(item) = self.next() else {
+            bail!("Expected exactly one item, found none");
+        };
+        if self.next().is_some() {
+            bail!("Expected exactly one item, found {}", 2 + self.count());
+        }
+        Ok(item)
+    }
+
+    /// Groups lines into blocks separated by blank (or whitespace-only)
+    /// lines.
+    ///
+    /// Runs of blank lines count as one separator and empty blocks are
+    /// skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::iter::AocIterExt;
+    /// let blocks: Vec<Vec<&str>> = "a\nb\n\n\nc".lines().chunk_by_blank_lines().collect();
+    /// assert_eq!(blocks, vec![vec!["a", "b"], vec!["c"]]);
+    /// ```
+    fn chunk_by_blank_lines(self) -> BlankLineChunks<Self>
+    where
+        Self::Item: AsRef<str>,
+    {
+        BlankLineChunks { lines: self }
+    }
+
+    /// Iterates over overlapping windows of `N` consecutive items as arrays.
+    ///
+    /// Yields nothing if there are fewer than `N` items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::iter::AocIterExt;
+    /// let rising = [1, 3, 2, 5]
+    ///     .into_iter()
+    ///     .tuple_windows_array::<2>()
+    ///     .filter(|[a, b]| b > a)
+    ///     .count();
+    /// assert_eq!(rising, 2);
+    ///
+    /// let triples: Vec<_> = (1..=4).tuple_windows_array::<3>().collect();
+    /// assert_eq!(triples, [[1, 2, 3], [2, 3, 4]]);
+    /// ```
+    fn tuple_windows_array<const N: usize>(self) -> ArrayWindows<Self, N>
+    where
+        Self::Item: Clone,
+    {
+        ArrayWindows {
+            items: self,
+            window: Vec::with_capacity(N),
+            start: 0,
+        }
+    }
+}
+
+impl<I: Iterator> AocIterExt for I {}
+
+/// Iterator returned by [`AocIterExt::chunk_by_blank_lines`].
+#[derive(Debug, Clone)]
+pub struct BlankLineChunks<I> {
+    lines: I,
+}
+
+impl<I> Iterator for BlankLineChunks<I>
+where
+    I: Iterator,
+    I::Item: AsRef<str>,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chunk = Vec::new();
+        for line in self.lines.by_ref() {
+            if !line.as_ref().trim().is_empty() {
+                chunk.push(line);
+            } else if !chunk.is_empty() {
+                return Some(chunk);
+            }
+        }
+        (!chunk.is_empty()).then_some(chunk)
+    }
+}
+
+/// Iterator returned by [`AocIterExt::tuple_windows_array`].
+#[derive(Debug, Clone)]
+pub struct ArrayWindows<I: Iterator, const N: usize> {
+    items: I,
+    /// Ring buffer of the last `N` items; the oldest sits at `start`.
+    window: Vec<I::Item>,
+    start: usize,
+}
+
+impl<I, const N: usize> Iterator for ArrayWindows<I, N>
+where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if N == 0 {
+            return None;
+        }
+        if self.window.len() == N {
+            self.window[self.start] = self.items.next()?;
+            self.start = (self.start + 1) % N;
+        } else {
+            while self.window.len() < N {
+                self.window.push(self.items.next()?);
+            }
+        }
+        Some(std::array::from_fn(|i| {
+            self.window[(self.start + i) % N].clone()
+        }))
+    }
+}
@@ -1,3 +1,25 @@
+pub mod alloc_tracking;
 pub mod benchmarking;
+pub mod bit_grid;
+pub mod cache;
+pub mod combinators;
+pub mod complexity;
+pub mod counter;
+pub mod graph;
+pub mod grid;
 pub mod input;
+pub mod iter;
+pub mod math;
+pub mod matrix;
+pub mod memo;
+pub mod output;
+pub mod perf_counters;
 pub mod plotting;
+pub mod point;
+pub mod priority_queue;
+pub mod range_set;
+pub mod runner;
+pub mod search;
+pub mod submit;
+pub mod union_find;
+pub mod validate;
@@ -0,0 +1,201 @@
+//! Number theory helpers for cycle-alignment puzzles.
+//!
+//! Everything works on `i64`/`u64` with `i128` intermediates, so products of
+//! two in-range values never overflow. Functions with no answer (no inverse,
+//! inconsistent congruences) return `None` rather than panicking.
+
+/// Greatest common divisor; `gcd(0, 0) == 0`.
+///
+/// Returned as `u64` because `gcd(i64::MIN, 0)` is `2^63`, which does not
+/// fit in `i64`.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::math::gcd;
+/// assert_eq!(gcd(12, 18), 6);
+/// assert_eq!(gcd(-4, 6), 2);
+/// assert_eq!(gcd(i64::MIN, 0), 1 << 63);
+/// ```
+pub fn gcd(a: i64, b: i64) -> u64 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Least common multiple; `None` on overflow, `0` if either input is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::math::lcm;
+/// assert_eq!(lcm(4, 6), Some(12));
+/// assert_eq!(lcm(i64::MAX, 2), None);
+/// assert_eq!(lcm(1, i64::MIN), None);
+/// ```
+pub fn lcm(a: i64, b: i64) -> Option<i64> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    let g = i128::from(gcd(a, b));
+    let lcm = (i128::from(a) / g * i128::from(b)).abs();
+    i64::try_from(lcm).ok()
+}
+
+/// Least common multiple of many values, e.g. the period of several cycles.
+///
+/// # Returns
+/// The LCM (`1` for no values), or `None` on overflow
+///
+/// # Examples
+///
+/// ```
+/// # use shared::math::lcm_all;
+/// assert_eq!(lcm_all([2, 3, 4]), Some(12));
+/// ```
+pub fn lcm_all(values: impl IntoIterator<Item = i64>) -> Option<i64> {
+    values.into_iter().try_fold(1, lcm)
+}
+
+/// Extended Euclidean algorithm.
+///
+/// # Returns
+/// `(g, x, y)` with `g = gcd(a, b)` and `a * x + b * y == g`, or `None` if
+/// `g` or a coefficient does not fit in `i64` (e.g. `gcd(i64::MIN, 0)`)
+///
+/// # Examples
+///
+/// ```
+/// # use shared::math::extended_gcd;
+/// let (g, x, y) = extended_gcd(240, 46).unwrap();
+/// assert_eq!(g, 2);
+/// assert_eq!(240 * x + 46 * y, 2);
+/// assert_eq!(extended_gcd(i64::MIN, -1), Some((1, 0, -1)));
+/// assert_eq!(extended_gcd(i64::MIN, 0), None);
+/// ```
+pub fn extended_gcd(a: i64, b: i64) -> Option<(i64, i64, i64)> {
+    let (mut old_r, mut r) = (i128::from(a), i128::from(b));
+    let (mut old_x, mut x) = (1i128, 0i128);
+    let (mut old_y, mut y) = (0i128, 1i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (old_r, old_x, old_y) = (-old_r, -old_x, -old_y);
+    }
+    Some((
+        i64::try_from(old_r).ok()?,
+        i64::try_from(old_x).ok()?,
+        i64::try_from(old_y).ok()?,
+    ))
+}
+
+/// Modular multiplicative inverse.
+///
+/// # Returns
+/// `x` in `0..modulus` with `a * x ≡ 1 (mod modulus)`, or `None` if `a` and
+/// `modulus` are not coprime or `modulus < 1`
+///
+/// # Examples
+///
+/// ```
+/// # use shared::math::mod_inverse;
+/// assert_eq!(mod_inverse(3, 11), Some(4));
+/// assert_eq!(mod_inverse(4, 8), None);
+/// ```
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus < 1 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus)?;
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Modular exponentiation by squaring.
+///
+/// # Returns
+/// `base^exponent mod modulus`, or `None` if `modulus` is zero
+///
+/// # Examples
+///
+/// ```
+/// # use shared::math::mod_pow;
+/// assert_eq!(mod_pow(4, 13, 497), Some(445));
+/// assert_eq!(mod_pow(7, 0, 1), Some(0));
+/// ```
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+    let modulus = u128::from(modulus);
+    let mut base = u128::from(base) % modulus;
+    let mut result = 1 % modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+    u64::try_from(result).ok()
+}
+
+/// Solves a system of congruences `x ≡ residue (mod modulus)` with the
+/// Chinese remainder theorem.
+///
+/// Moduli need not be pairwise coprime; the system is merged pairwise and
+/// checked for consistency.
+///
+/// # Parameters
+/// * `congruences` - `(residue, modulus)` pairs with positive moduli
+///
+/// # Returns
+/// `(x, m)` where `x` in `0..m` is the smallest non-negative solution and `m`
+/// is the combined modulus, or `None` if the system has no solution, a
+/// modulus is not positive, or the combined modulus overflows `i64`
+///
+/// # Examples
+///
+/// ```
+/// # use shared::math::crt;
+/// assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(crt(&[(1, 4), (3, 6)]), Some((9, 12))); // not coprime
+/// assert_eq!(crt(&[(1, 4), (2, 6)]), None); // inconsistent
+/// ```
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    congruences.iter().try_fold(
+        (0, 1),
+        |(residue, modulus), &(next_residue, next_modulus)| {
+            if next_modulus < 1 {
+                return None;
+            }
+            merge_congruences(
+                residue,
+                modulus,
+                next_residue.rem_euclid(next_modulus),
+                next_modulus,
+            )
+        },
+    )
+}
+
+/// Merges `x ≡ a (mod m)` and `x ≡ b (mod n)` into one congruence.
+fn merge_congruences(a: i64, m: i64, b: i64, n: i64) -> Option<(i64, i64)> {
+    let (g, p, _) = extended_gcd(m, n)?;
+    if (b - a) % g != 0 {
+        return None;
+    }
+
+    // x = a + m * t where m * t ≡ b - a (mod n), so
+    // t = (b - a) / g * p mod n / g
+    let lcm = i128::from(m / g) * i128::from(n);
+    let step = i128::from(n / g);
+    let t = (i128::from((b - a) / g) * i128::from(p)).rem_euclid(step);
+    let x = (i128::from(a) + i128::from(m) * t).rem_euclid(lcm);
+    Some((i64::try_from(x).ok()?, i64::try_from(lcm).ok()?))
+}
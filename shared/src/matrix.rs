@@ -0,0 +1,105 @@
+//! Compatibility matrix of registered solver variants.
+//!
+//! Runs the canonical solver and every registered variant of each day on the
+//! example and real input, and records whether each variant agrees with the
+//! canonical answer. This keeps naive reference implementations honest as the
+//! optimized ones evolve.
+
+use crate::input::read_input;
+use crate::runner::{run_solver, RunOutcome, Solution};
+use std::fmt::Write;
+use std::time::Duration;
+
+/// One cell of the compatibility matrix.
+///
+/// # Attributes
+/// * `day` - The day number (1-25)
+/// * `part` - The puzzle part (1 or 2)
+/// * `input` - Which input was solved ("example" or "input")
+/// * `variant` - Variant name, "canonical" for the registry's main solver
+/// * `outcome` - Result of running the variant
+/// * `agrees` - Whether the variant produced the canonical answer
+pub struct MatrixEntry {
+    pub day: u8,
+    pub part: u8,
+    pub input: &'static str,
+    pub variant: &'static str,
+    pub outcome: RunOutcome,
+    pub agrees: bool,
+}
+
+/// Runs every solver of every part of the given days.
+///
+/// Real input is only used when `dayXX/input.txt` exists. A canonical solver
+/// that fails never agrees, so broken days show up in the matrix too.
+///
+/// # Parameters
+/// * `solutions` - Registry entries to run
+/// * `timeout` - Per-solver time limit, or `None` to wait forever
+///
+/// # Returns
+/// One entry per day, part, input and variant, canonical first
+pub fn build_matrix(solutions: &[&Solution], timeout: Option<Duration>) -> Vec<MatrixEntry> {
+    let mut entries = Vec::new();
+    for solution in solutions {
+        let real_input = read_input(solution.day).ok();
+        for part in [1, 2] {
+            let inputs = [("example", Some(solution.example_input(part)))]
+                .into_iter()
+                .chain([("input", real_input.as_deref())]);
+            for (label, input) in inputs {
+                let Some(input) = input else {
+                    continue;
+                };
+                let mut canonical: Option<String> = None;
+                for (index, (variant, solver)) in solution.solvers(part).into_iter().enumerate() {
+                    let outcome = run_solver(solver, input, timeout);
+                    if index == 0 {
+                        canonical = outcome.answer().map(str::to_owned);
+                    }
+                    let agrees = canonical.is_some() && outcome.answer() == canonical.as_deref();
+                    entries.push(MatrixEntry {
+                        day: solution.day,
+                        part,
+                        input: label,
+                        variant,
+                        outcome,
+                        agrees,
+                    });
+                }
+            }
+        }
+    }
+    entries
+}
+
+/// Renders the matrix as a Markdown table.
+///
+/// # Parameters
+/// * `entries` - Matrix entries from [`build_matrix`]
+///
+/// # Returns
+/// Markdown with one row per entry
+pub fn render_matrix(entries: &[MatrixEntry]) -> String {
+    let mut table = String::from(
+        "| Day | Part | Input | Variant | Answer | Time | Status |\n\
+         |-----|------|-------|---------|--------|------|--------|\n",
+    );
+    for entry in entries {
+        let (answer, time) = match &entry.outcome {
+            RunOutcome::Solved { answer, elapsed } => (answer.clone(), format!("{elapsed:.2?}")),
+            RunOutcome::Failed { error, elapsed } => {
+                (format!("error: {error}"), format!("{elapsed:.2?}"))
+            }
+            RunOutcome::TimedOut { limit } => ("timed out".to_string(), format!(">{limit:?}")),
+        };
+        let status = if entry.agrees { "✅" } else { "❌" };
+        // Writing to a String cannot fail
+        let _ = writeln!(
+            table,
+            "| {:02} | {} | {} | {} | {answer} | {time} | {status} |",
+            entry.day, entry.part, entry.input, entry.variant
+        );
+    }
+    table
+}
@@ -0,0 +1,162 @@
+//! Memoization for recursive counting functions.
+//!
+//! [`Memo`] wraps a recursive closure and caches its results by key; the
+//! closure receives the memo itself so recursive calls go through the cache.
+//! For plain functions of the shape `fn(state...) -> u64`, the [`memoize!`]
+//! macro adds a per-thread cache without any boilerplate.
+//!
+//! [`memoize!`]: crate::memoize
+
+use rustc_hash::FxHashMap;
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// Recursive function to memoize; calls `memo.get` to recurse.
+type Recursive<'f, K, V> = Rc<dyn Fn(&mut Memo<'f, K, V>, K) -> V + 'f>;
+
+/// Cache around a recursive closure.
+///
+/// # Attributes
+/// * `cache` - Results computed so far
+/// * `capacity` - Maximum number of cached entries, if bounded
+/// * `function` - The wrapped closure
+pub struct Memo<'f, K, V> {
+    cache: FxHashMap<K, V>,
+    capacity: Option<usize>,
+    function: Recursive<'f, K, V>,
+}
+
+impl<'f, K: Clone + Eq + Hash, V: Clone> Memo<'f, K, V> {
+    /// Wraps a recursive closure with an unbounded cache.
+    ///
+    /// # Parameters
+    /// * `function` - Computes the value for a key, recursing via the memo
+    ///   passed as its first argument
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::memo::Memo;
+    /// // Stones after some blinks: 0 becomes 1, even-length numbers split,
+    /// // anything else is multiplied by 2024
+    /// let mut stones = Memo::new(|memo, (stone, blinks): (u64, u8)| {
+    ///     if blinks == 0 {
+    ///         return 1;
+    ///     }
+    ///     let digits = stone.checked_ilog10().unwrap_or(0) + 1;
+    ///     if stone == 0 {
+    ///         memo.get((1, blinks - 1))
+    ///     } else if digits % 2 == 0 {
+    ///         let half = 10u64.pow(digits / 2);
+    ///         memo.get((stone / half, blinks - 1)) + memo.get((stone % half, blinks - 1))
+    ///     } else {
+    ///         memo.get((stone * 2024, blinks - 1))
+    ///     }
+    /// });
+    /// let total: u64 = [125, 17].into_iter().map(|stone| stones.get((stone, 25))).sum();
+    /// assert_eq!(total, 55312);
+    /// ```
+    pub fn new(function: impl Fn(&mut Memo<'f, K, V>, K) -> V + 'f) -> Self {
+        Memo {
+            cache: FxHashMap::default(),
+            capacity: None,
+            function: Rc::new(function),
+        }
+    }
+
+    /// Wraps a recursive closure with a cache of at most `capacity` entries.
+    ///
+    /// When the cache is full it is cleared before the next insertion, which
+    /// bounds memory at the cost of recomputing older entries.
+    pub fn with_capacity(
+        capacity: usize,
+        function: impl Fn(&mut Memo<'f, K, V>, K) -> V + 'f,
+    ) -> Self {
+        Memo {
+            capacity: Some(capacity),
+            ..Self::new(function)
+        }
+    }
+
+    /// Returns the value for a key, computing and caching it if needed.
+    pub fn get(&mut self, key: K) -> V {
+        if let Some(value) = self.cache.get(&key) {
+            return value.clone();
+        }
+
+        let function = Rc::clone(&self.function);
+        let value = function(self, key.clone());
+        if self
+            .capacity
+            .is_some_and(|capacity| self.cache.len() >= capacity)
+        {
+            self.cache.clear();
+        }
+        if self.capacity != Some(0) {
+            self.cache.insert(key, value.clone());
+        }
+        value
+    }
+
+    /// Returns the number of cached entries.
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Returns `true` if nothing is cached.
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Drops every cached entry.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+    }
+}
+
+/// Defines a function whose results are cached per thread, keyed by all of
+/// its arguments.
+///
+/// Arguments and the return type must be `Clone`, arguments also
+/// `Eq + Hash`. The function must be pure: the cache lives for the rest of
+/// the thread and is shared by every call.
+///
+/// # Examples
+///
+/// ```
+/// shared::memoize! {
+///     /// Counts the ways to climb `steps` stairs taking 1 or 2 at a time.
+///     fn ways(steps: u64) -> u64 {
+///         if steps <= 1 {
+///             return 1;
+///         }
+///         ways(steps - 1) + ways(steps - 2)
+///     }
+/// }
+/// assert_eq!(ways(90), 4_660_046_610_375_530_309);
+/// ```
+#[macro_export]
+macro_rules! memoize {
+    (
+        $(#[$meta:meta])*
+        $vis:vis fn $name:ident($($arg:ident: $ty:ty),+ $(,)?) -> $ret:ty $body:block
+    ) => {
+        $(#[$meta])*
+        $vis fn $name($($arg: $ty),+) -> $ret {
+            ::std::thread_local! {
+                static CACHE: ::std::cell::RefCell<
+                    ::std::collections::HashMap<($($ty,)+), $ret>,
+                > = ::std::cell::RefCell::new(::std::collections::HashMap::new());
+            }
+
+            let key = ($(::std::clone::Clone::clone(&$arg),)+);
+            if let Some(value) = CACHE.with(|cache| cache.borrow().get(&key).cloned()) {
+                return value;
+            }
+            // The body runs in a closure so its early returns are cached too
+            let value: $ret = (move || $body)();
+            CACHE.with(|cache| cache.borrow_mut().insert(key, ::std::clone::Clone::clone(&value)));
+            value
+        }
+    };
+}
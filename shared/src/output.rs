@@ -0,0 +1,113 @@
+//! Terminal output helpers shared by the runners.
+//!
+//! Formats answer mismatches, against an accepted submission or the previous
+//! run, as a colored "expected vs got" diff. Colors are
+//! only emitted when stdout is a terminal and `NO_COLOR` is unset.
+
+use std::io::{self, IsTerminal};
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const RESET: &str = "\x1b[0m";
+
+/// Returns whether colored output should be written to stdout.
+pub fn use_color() -> bool {
+    std::env::var_os("NO_COLOR").is_none() && io::stdout().is_terminal()
+}
+
+/// Formats the difference between an expected and a computed answer.
+///
+/// Numeric answers also show the signed delta `got - expected`, unless it
+/// does not fit in an `i128`.
+///
+/// # Parameters
+/// * `expected` - The known correct answer
+/// * `got` - The answer computed by the solver
+/// * `color` - Whether to wrap the values in ANSI color codes
+///
+/// # Returns
+/// A two-line diff, or `None` if the answers match (ignoring surrounding
+/// whitespace)
+///
+/// # Examples
+///
+/// A wrong numeric answer shows both values and the delta:
+///
+/// ```
+/// # use shared::output::format_mismatch;
+/// let diff = format_mismatch("11", "14", false).unwrap();
+/// assert_eq!(diff, "  expected: 11\n  got:      14 (+3)");
+///
+/// // A delta that overflows i128 is left out
+/// let min = i128::MIN.to_string();
+/// let diff = format_mismatch(&min, "1", false).unwrap();
+/// assert_eq!(diff, format!("  expected: {min}\n  got:      1"));
+/// ```
+///
+/// A matching answer needs no diff:
+///
+/// ```
+/// # use shared::output::format_mismatch;
+/// assert_eq!(format_mismatch("11", "11", false), None);
+/// ```
+pub fn format_mismatch(expected: &str, got: &str, color: bool) -> Option<String> {
+    let (expected, got) = (expected.trim(), got.trim());
+    if expected == got {
+        return None;
+    }
+
+    let (green, red, reset) = if color {
+        (GREEN, RED, RESET)
+    } else {
+        ("", "", "")
+    };
+    let delta = match (expected.parse::<i128>(), got.parse::<i128>()) {
+        (Ok(expected), Ok(got)) => got
+            .checked_sub(expected)
+            .map_or_else(String::new, |delta| format!(" ({delta:+})")),
+        _ => String::new(),
+    };
+    Some(format!(
+        "  expected: {green}{expected}{reset}\n  got:      {red}{got}{reset}{delta}"
+    ))
+}
+
+/// Prints a labelled mismatch diff if the answers differ.
+///
+/// # Parameters
+/// * `label` - Describes the answer, e.g. "Part 1 result"
+/// * `expected` - The known correct answer
+/// * `got` - The answer computed by the solver
+///
+/// # Returns
+/// `true` if the answers match, `false` if a diff was printed
+pub fn report_mismatch(label: &str, expected: &str, got: &str) -> bool {
+    match format_mismatch(expected, got, use_color()) {
+        Some(diff) => {
+            println!("❌ {label} does not match the expected answer:\n{diff}");
+            false
+        }
+        None => true,
+    }
+}
+
+/// Prints a labelled diff if an answer changed since the previous run.
+///
+/// A change is only a warning: the previous run may have been wrong.
+///
+/// # Parameters
+/// * `label` - Describes the answer, e.g. "Part 1 result"
+/// * `previous` - The answer of the previous run
+/// * `got` - The answer computed by the solver
+///
+/// # Returns
+/// `true` if the answer is unchanged, `false` if a diff was printed
+pub fn report_change(label: &str, previous: &str, got: &str) -> bool {
+    match format_mismatch(previous, got, use_color()) {
+        Some(diff) => {
+            println!("⚠️  {label} changed since the previous run:\n{diff}");
+            false
+        }
+        None => true,
+    }
+}
@@ -0,0 +1,198 @@
+//! Hardware performance counters for benchmarks.
+//!
+//! Wall-clock times of closely matched algorithms drown in noise on busy
+//! machines; instruction and cycle counts are far more stable. Counters are
+//! read through Linux `perf_event_open` and need the `perf-counters` feature.
+//! On other platforms, without the feature, or when the kernel refuses access
+//! (see `/proc/sys/kernel/perf_event_paranoid`), [`measure_counters`] reports
+//! nothing.
+
+/// Hardware events counted in user space during one invocation.
+///
+/// # Attributes
+/// * `instructions` - Retired instructions
+/// * `cycles` - CPU cycles
+/// * `branch_misses` - Mispredicted branches
+/// * `cache_misses` - Last-level cache misses
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HardwareCounters {
+    pub instructions: u64,
+    pub cycles: u64,
+    pub branch_misses: u64,
+    pub cache_misses: u64,
+}
+
+/// Runs a closure and counts the hardware events it causes.
+///
+/// Only the calling thread is counted.
+///
+/// # Returns
+/// The closure's result and its counters, or `None` for the counters if
+/// they are unavailable
+///
+/// # Examples
+///
+/// ```
+/// # use shared::perf_counters::measure_counters;
+/// let (sum, counters) = measure_counters(|| (1..=10).sum::<u32>());
+/// assert_eq!(sum, 55);
+/// if let Some(counters) = counters {
+///     assert!(counters.instructions > 0);
+/// }
+/// ```
+pub fn measure_counters<R>(f: impl FnOnce() -> R) -> (R, Option<HardwareCounters>) {
+    #[cfg(all(target_os = "linux", feature = "perf-counters"))]
+    {
+        if let Some(group) = linux::CounterGroup::open() {
+            return group.measure(f);
+        }
+    }
+    (f(), None)
+}
+
+#[cfg(all(target_os = "linux", feature = "perf-counters"))]
+mod linux {
+    use super::HardwareCounters;
+    use std::fs::File;
+    use std::io::Read;
+    use std::os::fd::{AsRawFd, FromRawFd};
+    use std::os::raw::{c_int, c_long, c_ulong};
+
+    #[cfg(target_arch = "x86_64")]
+    const SYS_PERF_EVENT_OPEN: c_long = 298;
+    #[cfg(target_arch = "aarch64")]
+    const SYS_PERF_EVENT_OPEN: c_long = 241;
+    #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+    const SYS_PERF_EVENT_OPEN: c_long = -1;
+
+    const PERF_TYPE_HARDWARE: u32 = 0;
+    const PERF_COUNT_HW_CPU_CYCLES: u64 = 0;
+    const PERF_COUNT_HW_INSTRUCTIONS: u64 = 1;
+    const PERF_COUNT_HW_CACHE_MISSES: u64 = 3;
+    const PERF_COUNT_HW_BRANCH_MISSES: u64 = 5;
+
+    /// `disabled`, `exclude_kernel` and `exclude_hv` attribute bits.
+    const ATTR_FLAGS: u64 = 1 | 1 << 5 | 1 << 6;
+    const PERF_FLAG_FD_CLOEXEC: c_ulong = 1 << 3;
+    const PERF_EVENT_IOC_ENABLE: c_ulong = 0x2400;
+    const PERF_EVENT_IOC_DISABLE: c_ulong = 0x2401;
+    const PERF_EVENT_IOC_RESET: c_ulong = 0x2403;
+
+    extern "C" {
+        fn syscall(number: c_long, ...) -> c_long;
+        fn ioctl(fd: c_int, request: c_ulong, ...) -> c_int;
+    }
+
+    /// First version of `struct perf_event_attr`, which every kernel accepts.
+    #[repr(C)]
+    struct PerfEventAttr {
+        kind: u32,
+        size: u32,
+        config: u64,
+        sample_period: u64,
+        sample_type: u64,
+        read_format: u64,
+        flags: u64,
+        wakeup_events: u32,
+        bp_type: u32,
+        config1: u64,
+    }
+
+    /// One open counter per measured event.
+    pub(super) struct CounterGroup {
+        instructions: File,
+        cycles: File,
+        branch_misses: File,
+        cache_misses: File,
+    }
+
+    impl CounterGroup {
+        /// Opens every counter, or returns `None` if any is unavailable.
+        pub(super) fn open() -> Option<Self> {
+            Some(CounterGroup {
+                instructions: open_counter(PERF_COUNT_HW_INSTRUCTIONS)?,
+                cycles: open_counter(PERF_COUNT_HW_CPU_CYCLES)?,
+                branch_misses: open_counter(PERF_COUNT_HW_BRANCH_MISSES)?,
+                cache_misses: open_counter(PERF_COUNT_HW_CACHE_MISSES)?,
+            })
+        }
+
+        /// Counts the events of a closure with every counter enabled.
+        pub(super) fn measure<R>(mut self, f: impl FnOnce() -> R) -> (R, Option<HardwareCounters>) {
+            let files = [
+                &self.instructions,
+                &self.cycles,
+                &self.branch_misses,
+                &self.cache_misses,
+            ];
+            for file in files {
+                control(file, PERF_EVENT_IOC_RESET);
+                control(file, PERF_EVENT_IOC_ENABLE);
+            }
+            let result = f();
+            for file in files {
+                control(file, PERF_EVENT_IOC_DISABLE);
+            }
+
+            let counters = (|| {
+                Some(HardwareCounters {
+                    instructions: read_count(&mut self.instructions)?,
+                    cycles: read_count(&mut self.cycles)?,
+                    branch_misses: read_count(&mut self.branch_misses)?,
+                    cache_misses: read_count(&mut self.cache_misses)?,
+                })
+            })();
+            (result, counters)
+        }
+    }
+
+    /// Opens a disabled user-space counter for the calling thread.
+    fn open_counter(config: u64) -> Option<File> {
+        if SYS_PERF_EVENT_OPEN < 0 {
+            return None;
+        }
+        let attr = PerfEventAttr {
+            kind: PERF_TYPE_HARDWARE,
+            size: std::mem::size_of::<PerfEventAttr>() as u32,
+            config,
+            sample_period: 0,
+            sample_type: 0,
+            read_format: 0,
+            flags: ATTR_FLAGS,
+            wakeup_events: 0,
+            bp_type: 0,
+            config1: 0,
+        };
+        let (pid, cpu, group_fd): (c_int, c_int, c_int) = (0, -1, -1);
+        // SAFETY: `attr` is a valid perf_event_attr that outlives the call
+        let fd = unsafe {
+            syscall(
+                SYS_PERF_EVENT_OPEN,
+                &attr as *const PerfEventAttr,
+                pid,
+                cpu,
+                group_fd,
+                PERF_FLAG_FD_CLOEXEC,
+            )
+        };
+        let fd = c_int::try_from(fd).ok().filter(|&fd| fd >= 0)?;
+        // SAFETY: the kernel just returned this descriptor and nothing else
+        // owns it
+        Some(unsafe { File::from_raw_fd(fd) })
+    }
+
+    /// Sends an argument-less control request to a counter.
+    fn control(file: &File, request: c_ulong) {
+        // SAFETY: the descriptor is an open perf event owned by `file`
+        unsafe {
+            ioctl(file.as_raw_fd(), request, 0);
+        }
+    }
+
+    /// Reads the current value of a counter.
+    fn read_count(file: &mut File) -> Option<u64> {
+        let mut bytes = [0; 8];
+        file.read_exact(&mut bytes).ok()?;
+        Some(u64::from_ne_bytes(bytes))
+    }
+}
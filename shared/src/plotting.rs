@@ -2,32 +2,664 @@
 //!
 //! This module provides simple plotting functionality for creating
 //! performance comparison charts across different days of Advent of Code.
+//! Charts are written as SVG, or as PNG when the filename ends in `.png`
+//! and the `png-plots` feature is enabled.
 
+use crate::benchmarking::{
+    part_runtimes, AxisScale, BenchmarkResult, DayBenchmark, PlotMetric, PointAnnotation,
+    ThroughputUnit,
+};
+use crate::complexity::{fit_power_law, PowerLawFit};
 use anyhow::{bail, Context, Result};
-use itertools::Itertools;
 use plotters::coord::types::RangedCoordf64;
+use plotters::coord::Shift;
+use plotters::element::DashedPathElement;
 use plotters::prelude::*;
+use serde_json::Value;
+use std::collections::BTreeSet;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
 
-const CIRCLE_RADIUS: i32 = 4;
+/// Width in pixels of the caps on confidence interval error bars.
+const ERROR_BAR_WIDTH: u32 = 8;
 const LEGEND_LINE_LENGTH: i32 = 10;
-const CHART_WIDTH: u32 = 800;
-const CHART_HEIGHT: u32 = 600;
+/// Pixels lifting point annotations above their data point.
+const LABEL_LIFT: i32 = 18;
+/// Share of a day's column covered by its group of bars.
+const BAR_GROUP_WIDTH: f64 = 0.8;
+/// Number of segments a fitted curve is drawn with.
+const FIT_SEGMENTS: u32 = 50;
+/// Length in pixels of the dashes and gaps of fitted curves.
+const FIT_DASH: u32 = 6;
 
-type PlotChart<'a> = ChartContext<'a, SVGBackend<'a>, Cartesian2d<RangedCoordf64, RangedCoordf64>>;
+/// Legend positions by the names stored in plot metadata.
+const LEGEND_POSITIONS: [(&str, SeriesLabelPosition); 9] = [
+    ("upper_left", SeriesLabelPosition::UpperLeft),
+    ("middle_left", SeriesLabelPosition::MiddleLeft),
+    ("lower_left", SeriesLabelPosition::LowerLeft),
+    ("upper_middle", SeriesLabelPosition::UpperMiddle),
+    ("middle_middle", SeriesLabelPosition::MiddleMiddle),
+    ("lower_middle", SeriesLabelPosition::LowerMiddle),
+    ("upper_right", SeriesLabelPosition::UpperRight),
+    ("middle_right", SeriesLabelPosition::MiddleRight),
+    ("lower_right", SeriesLabelPosition::LowerRight),
+];
 
-/// Creates a dual-algorithm performance comparison plot.
+/// Visual styling shared by every chart.
 ///
-/// Generates an SVG chart comparing two algorithms with fixed styling:
-/// - 800x600 dimensions
-/// - Blue line for first algorithm, red line for second
-/// - Logarithmic y-axis showing execution times
-/// - Speedup factor labels
-/// - Standard "Input Size (n)" / "Time (microseconds)" axes
+/// # Attributes
+/// * `width` - Image width in pixels
+/// * `height` - Image height in pixels
+/// * `series_colors` - Line colors assigned to algorithms in order, cycling
+///   when exhausted
+/// * `font_family` - Font of every text element
+/// * `title_font_size` - Font size of the chart title
+/// * `label_font_size` - Font size of axis, legend and speedup labels
+/// * `marker_radius` - Radius in pixels of the data point markers
+/// * `legend_position` - Where the legend box is placed
+/// * `background` - Fill of the canvas and the legend box
+/// * `foreground` - Color of text, axes and the legend border
+/// * `grid_color` - Color of the gridlines, drawn translucent
+///
+/// # Examples
+///
+/// ```
+/// # use shared::plotting::PlotTheme;
+/// let theme = PlotTheme { width: 1024, ..PlotTheme::default() };
+/// assert_eq!(theme.height, 600);
+/// assert_eq!(PlotTheme::from_json(&theme.to_json()).unwrap(), theme);
+///
+/// let dark = PlotTheme::dark();
+/// assert_eq!(PlotTheme::from_json(&dark.to_json()).unwrap(), dark);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlotTheme {
+    pub width: u32,
+    pub height: u32,
+    pub series_colors: Vec<RGBColor>,
+    pub font_family: String,
+    pub title_font_size: u32,
+    pub label_font_size: u32,
+    pub marker_radius: i32,
+    pub legend_position: SeriesLabelPosition,
+    pub background: RGBColor,
+    pub foreground: RGBColor,
+    pub grid_color: RGBColor,
+}
+
+impl Default for PlotTheme {
+    fn default() -> Self {
+        PlotTheme {
+            width: 800,
+            height: 600,
+            series_colors: vec![BLUE, RED, GREEN, MAGENTA, CYAN, RGBColor(255, 140, 0)],
+            font_family: "sans-serif".to_string(),
+            title_font_size: 24,
+            label_font_size: 12,
+            marker_radius: 4,
+            legend_position: SeriesLabelPosition::MiddleRight,
+            background: WHITE,
+            foreground: BLACK,
+            grid_color: BLACK,
+        }
+    }
+}
+
+impl PlotTheme {
+    /// Returns a theme with twice the canvas, text and markers of the
+    /// default, for slides.
+    pub fn presentation() -> Self {
+        PlotTheme {
+            width: 1600,
+            height: 1200,
+            title_font_size: 48,
+            label_font_size: 24,
+            marker_radius: 8,
+            ..PlotTheme::default()
+        }
+    }
+
+    /// Returns a theme for pages rendered in dark mode, with light text and
+    /// gridlines on a near-black canvas.
+    pub fn dark() -> Self {
+        PlotTheme {
+            // Okabe-Ito hues stay distinguishable on dark backgrounds
+            series_colors: vec![
+                RGBColor(86, 180, 233),
+                RGBColor(230, 159, 0),
+                RGBColor(0, 158, 115),
+                RGBColor(240, 228, 66),
+                RGBColor(204, 121, 167),
+                RGBColor(213, 94, 0),
+            ],
+            background: RGBColor(13, 17, 23),
+            foreground: RGBColor(230, 237, 243),
+            grid_color: WHITE,
+            ..PlotTheme::default()
+        }
+    }
+
+    /// Returns the line color of the algorithm at `index`.
+    fn series_color(&self, index: usize) -> RGBColor {
+        match self.series_colors.len() {
+            0 => BLACK,
+            count => self.series_colors[index % count],
+        }
+    }
+
+    /// Returns the style of the chart title.
+    fn title_font(&self) -> TextStyle<'_> {
+        TextStyle::from((self.font_family.as_str(), self.title_font_size)).color(&self.foreground)
+    }
+
+    /// Returns the style of axis, legend and speedup labels.
+    fn label_font(&self) -> TextStyle<'_> {
+        TextStyle::from((self.font_family.as_str(), self.label_font_size)).color(&self.foreground)
+    }
+
+    /// Serializes the theme for plot metadata.
+    ///
+    /// Legends placed at explicit coordinates are stored as `[x, y]`.
+    pub fn to_json(&self) -> Value {
+        let legend_position = match &self.legend_position {
+            SeriesLabelPosition::Coordinate(x, y) => serde_json::json!([x, y]),
+            position => LEGEND_POSITIONS
+                .iter()
+                .find(|(_, known)| known == position)
+                .map_or(Value::Null, |(key, _)| Value::from(*key)),
+        };
+        serde_json::json!({
+            "width": self.width,
+            "height": self.height,
+            "series_colors": self
+                .series_colors
+                .iter()
+                .map(color_to_json)
+                .collect::<Vec<_>>(),
+            "font_family": self.font_family,
+            "title_font_size": self.title_font_size,
+            "label_font_size": self.label_font_size,
+            "marker_radius": self.marker_radius,
+            "legend_position": legend_position,
+            "background": color_to_json(&self.background),
+            "foreground": color_to_json(&self.foreground),
+            "grid_color": color_to_json(&self.grid_color),
+        })
+    }
+
+    /// Reads a theme written by [`to_json`](Self::to_json).
+    ///
+    /// Missing fields keep their default values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a present field has the wrong type or an unknown
+    /// value.
+    pub fn from_json(json: &Value) -> Result<Self> {
+        let mut theme = PlotTheme::default();
+        let number = |key: &str| -> Result<Option<u64>> {
+            match &json[key] {
+                Value::Null => Ok(None),
+                value => value
+                    .as_u64()
+                    .map(Some)
+                    .with_context(|| format!("Theme field '{key}' must be a number")),
+            }
+        };
+        let narrow = |key: &str, value: u64| {
+            u32::try_from(value).with_context(|| format!("Theme field '{key}' is too large"))
+        };
+
+        if let Some(width) = number("width")? {
+            theme.width = narrow("width", width)?;
+        }
+        if let Some(height) = number("height")? {
+            theme.height = narrow("height", height)?;
+        }
+        if let Some(size) = number("title_font_size")? {
+            theme.title_font_size = narrow("title_font_size", size)?;
+        }
+        if let Some(size) = number("label_font_size")? {
+            theme.label_font_size = narrow("label_font_size", size)?;
+        }
+        if let Some(radius) = number("marker_radius")? {
+            theme.marker_radius =
+                i32::try_from(radius).context("Theme marker radius is too large")?;
+        }
+        if let Some(family) = json["font_family"].as_str() {
+            theme.font_family = family.to_string();
+        }
+        if let Some(colors) = json["series_colors"].as_array() {
+            theme.series_colors = colors.iter().map(color_from_json).collect::<Result<_>>()?;
+        }
+        for (key, color) in [
+            ("background", &mut theme.background),
+            ("foreground", &mut theme.foreground),
+            ("grid_color", &mut theme.grid_color),
+        ] {
+            if !json[key].is_null() {
+                *color = color_from_json(&json[key])
+                    .with_context(|| format!("Invalid theme field '{key}'"))?;
+            }
+        }
+        theme.legend_position = match &json["legend_position"] {
+            Value::Null => theme.legend_position,
+            Value::String(key) => LEGEND_POSITIONS
+                .iter()
+                .find(|(known, _)| known == key)
+                .map(|(_, position)| position.clone())
+                .with_context(|| format!("Unknown legend position '{key}'"))?,
+            value => {
+                let coordinate = |index: usize| {
+                    value[index]
+                        .as_i64()
+                        .and_then(|coordinate| i32::try_from(coordinate).ok())
+                        .with_context(|| format!("Invalid legend position {value}"))
+                };
+                SeriesLabelPosition::Coordinate(coordinate(0)?, coordinate(1)?)
+            }
+        };
+        Ok(theme)
+    }
+}
+
+/// Serializes a color as its `[red, green, blue]` channels.
+fn color_to_json(color: &RGBColor) -> Value {
+    serde_json::json!([color.0, color.1, color.2])
+}
+
+/// Reads a color written by [`color_to_json`].
+///
+/// # Errors
+///
+/// Returns an error if the value is not three channels between 0 and 255.
+fn color_from_json(color: &Value) -> Result<RGBColor> {
+    let channel = |index: usize| {
+        color[index]
+            .as_u64()
+            .and_then(|channel| u8::try_from(channel).ok())
+            .with_context(|| format!("Invalid color {color}"))
+    };
+    Ok(RGBColor(channel(0)?, channel(1)?, channel(2)?))
+}
+
+type PlotChart<'a, DB> = ChartContext<'a, DB, Cartesian2d<RangedCoordf64, RangedCoordf64>>;
+
+/// Image format a chart is written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlotFormat {
+    Svg,
+    Png,
+}
+
+impl PlotFormat {
+    /// Picks the format from a filename's extension.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the extension is neither `svg` nor `png`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::plotting::PlotFormat;
+    /// assert_eq!(PlotFormat::from_filename("chart.PNG").unwrap(), PlotFormat::Png);
+    /// assert!(PlotFormat::from_filename("chart.pdf").is_err());
+    /// ```
+    pub fn from_filename(filename: &str) -> Result<Self> {
+        let extension = Path::new(filename)
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("svg") => Ok(PlotFormat::Svg),
+            Some("png") => Ok(PlotFormat::Png),
+            _ => bail!("Unsupported plot format for '{filename}'; use .svg or .png"),
+        }
+    }
+}
+
+/// Draws a chart into `filename` in the format its extension selects.
+///
+/// `|root| draw` is expanded once per backend, so `draw` can call any of the
+/// generic `draw_*` functions with `root` as their drawing area. A macro
+/// rather than a function, since a closure cannot be generic over backends.
+macro_rules! render {
+    ($filename:expr, $dimensions:expr, |$root:ident| $draw:expr) => {
+        match PlotFormat::from_filename($filename)? {
+            PlotFormat::Svg => {
+                let area = SVGBackend::new($filename, $dimensions).into_drawing_area();
+                let $root = &area;
+                $draw?;
+                area.present()?;
+            }
+            #[cfg(feature = "png-plots")]
+            PlotFormat::Png => {
+                let area = BitMapBackend::new($filename, $dimensions).into_drawing_area();
+                let $root = &area;
+                $draw?;
+                area.present()?;
+            }
+            #[cfg(not(feature = "png-plots"))]
+            PlotFormat::Png => bail!("Writing '{}' needs the `png-plots` feature", $filename),
+        }
+    };
+}
+
+/// One algorithm comparison chart: what is plotted and how its axes look.
+///
+/// # Attributes
+/// * `title` - Chart title
+/// * `series_names` - Legend names, one per entry in each result's `times`
+/// * `x_axis_label` - Label for the x-axis (e.g., "Sequence Length (N)", "Rule Count (M)")
+/// * `metric` - Quantity plotted on the y-axis
+/// * `x_scale` - Scale of the x-axis; [`AxisScale::Log`] turns polynomial
+///   growth into straight lines
+/// * `y_scale` - Scale of the y-axis; [`AxisScale::Linear`] keeps small
+///   differences between near-equal algorithms visible
+/// * `x_range` - Explicit `(start, end)` sizes shown, instead of fitting
+///   every result
+/// * `y_range` - Explicit `(start, end)` values shown, in the metric's
+///   units
+/// * `fit_overlay` - Overlay each latency series with its fitted power law,
+///   dashed, and name the exponent in the legend
+/// * `annotation` - Labels drawn next to the data points
+/// * `results` - Benchmark data with one mean time in nanoseconds per
+///   algorithm
+#[derive(Debug, Clone, Copy)]
+pub struct AlgorithmChart<'a> {
+    pub title: &'a str,
+    pub series_names: &'a [&'a str],
+    pub x_axis_label: &'a str,
+    pub metric: PlotMetric,
+    pub x_scale: AxisScale,
+    pub y_scale: AxisScale,
+    pub x_range: Option<(f64, f64)>,
+    pub y_range: Option<(f64, f64)>,
+    pub fit_overlay: bool,
+    pub annotation: PointAnnotation,
+    pub results: &'a [BenchmarkResult],
+}
+
+/// Creates a performance comparison plot for any number of algorithms.
+///
+/// Generates a chart comparing the algorithms with:
+/// - One colored line per algorithm, in the theme's series colors
+/// - Linear or logarithmic axes, logarithmic y by default in the benchmarks
+/// - Error bars spanning each mean's confidence interval, when extracted
+/// - Point annotations chosen by [`PointAnnotation`], by default speedup
+///   factors relative to the first (baseline) algorithm
+/// - Custom x-axis label and a y-axis label matching the metric
+///
+/// # Parameters
+/// * `filename` - Output filename; its extension selects the [`PlotFormat`]
+/// * `theme` - Dimensions, colors and fonts of the chart
+/// * `plot` - Data and axes of the chart
+///
+/// # Errors
+///
+/// Returns an error if there is nothing to plot, an explicit range is empty
+/// or not positive on a logarithmic axis, the format is unsupported or not
+/// enabled, or chart creation fails.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::{AxisScale, BenchmarkResult, PlotMetric, PointAnnotation};
+/// # use shared::plotting::{create_algorithm_plot, AlgorithmChart, PlotTheme};
+/// # use std::fs;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let results = vec![
+///     // 1000 elements: 25μs optimized, 50μs naive, 10μs parallel
+///     BenchmarkResult::new(1000, vec![25000.0, 50000.0, 10000.0], vec![500.0, 900.0, 300.0]),
+///     BenchmarkResult::new(5000, vec![100000.0, 250000.0, 30000.0], vec![2000.0, 4100.0, 800.0]),
+/// ];
+///
+/// let plot = AlgorithmChart {
+///     title: "Algorithm Performance Comparison",
+///     series_names: &["Optimized Algorithm", "Naive Algorithm", "Parallel Algorithm"],
+///     x_axis_label: "Input Size (N)",
+///     metric: PlotMetric::Latency,
+///     x_scale: AxisScale::Log,
+///     y_scale: AxisScale::Log,
+///     x_range: None,
+///     y_range: None,
+///     fit_overlay: true,
+///     annotation: PointAnnotation::Speedup,
+///     results: &results,
+/// };
+/// create_algorithm_plot("benchmark_comparison.svg", &PlotTheme::default(), &plot)?;
+///
+/// // Zoom into the fast algorithms on a linear time axis, labelled in μs
+/// let zoomed = AlgorithmChart {
+///     y_scale: AxisScale::Linear,
+///     y_range: Some((0.0, 120.0)),
+///     annotation: PointAnnotation::Value,
+///     ..plot
+/// };
+/// create_algorithm_plot("benchmark_comparison.svg", &PlotTheme::default(), &zoomed)?;
+/// # fs::remove_file("benchmark_comparison.svg").ok();
+/// # fs::remove_file("benchmark_comparison.csv").ok();
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_algorithm_plot(
+    filename: &str,
+    theme: &PlotTheme,
+    plot: &AlgorithmChart,
+) -> Result<()> {
+    let dimensions = (theme.width, theme.height);
+    render!(filename, dimensions, |root| {
+        draw_algorithm_plot(root, theme, plot)
+    });
+
+    println!("✅ Performance plot saved as '{filename}'");
+    write_plot_data(
+        filename,
+        &series_header(plot.metric),
+        &series_rows(plot.series_names, plot.metric, plot.results),
+    )?;
+    Ok(())
+}
+
+/// Composes several algorithm comparison charts into one image.
+///
+/// Panels fill a grid row by row under a shared title, each drawn like
+/// [`create_algorithm_plot`] at the theme's dimensions, so related
+/// benchmarks share one file and one style.
+///
+/// # Parameters
+/// * `filename` - Output filename; its extension selects the [`PlotFormat`]
+/// * `title` - Title above all panels
+/// * `theme` - Dimensions of each panel, colors and fonts
+/// * `columns` - Number of panels per row
+/// * `panels` - Charts to draw, in reading order
+///
+/// # Errors
+///
+/// Returns an error if there are no panels, `columns` is zero, a panel has
+/// nothing to plot, the format is unsupported or not enabled, or chart
+/// creation fails.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::{AxisScale, BenchmarkResult, PlotMetric, PointAnnotation};
+/// # use shared::plotting::{create_panel_plot, AlgorithmChart, PlotTheme};
+/// # use std::fs;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let result = |size, times| BenchmarkResult::new(size, times, Vec::new());
+/// let by_length = [result(100, vec![10.0, 100.0]), result(1000, vec![100.0, 10000.0])];
+/// let by_rules = [result(10, vec![5.0, 50.0]), result(100, vec![50.0, 500.0])];
+/// let panel = |title, x_axis_label, results| AlgorithmChart {
+///     title,
+///     series_names: &["Optimized", "Naive"],
+///     x_axis_label,
+///     metric: PlotMetric::Latency,
+///     x_scale: AxisScale::Linear,
+///     y_scale: AxisScale::Log,
+///     x_range: None,
+///     y_range: None,
+///     fit_overlay: false,
+///     annotation: PointAnnotation::MinMax,
+///     results,
+/// };
+/// create_panel_plot(
+///     "scaling.svg",
+///     "Scaling Overview",
+///     &PlotTheme::default(),
+///     2,
+///     &[
+///         panel("By Length", "Sequence Length (N)", &by_length),
+///         panel("By Rules", "Rule Count (M)", &by_rules),
+///     ],
+/// )?;
+/// # fs::remove_file("scaling.svg").ok();
+/// # fs::remove_file("scaling.csv").ok();
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_panel_plot(
+    filename: &str,
+    title: &str,
+    theme: &PlotTheme,
+    columns: usize,
+    panels: &[AlgorithmChart],
+) -> Result<()> {
+    if panels.is_empty() || columns == 0 {
+        bail!("A panel plot needs at least one panel and one column");
+    }
+    let columns = columns.min(panels.len());
+    let rows = panels.len().div_ceil(columns);
+    let dimensions = (
+        theme.width * u32::try_from(columns)?,
+        theme.height * u32::try_from(rows)?,
+    );
+    render!(filename, dimensions, |root| {
+        draw_panel_plot(root, theme, title, (rows, columns), panels)
+    });
+
+    println!("✅ Panel plot saved as '{filename}'");
+    let mut header = vec!["panel"];
+    header.extend(series_header(panels[0].metric));
+    let rows: Vec<Vec<String>> = panels
+        .iter()
+        .flat_map(|panel| {
+            series_rows(panel.series_names, panel.metric, panel.results)
+                .into_iter()
+                .map(|row| [vec![panel.title.to_string()], row].concat())
+        })
+        .collect();
+    write_plot_data(filename, &header, &rows)?;
+    Ok(())
+}
+
+/// Draws a grid of panels onto any plotters backend.
+///
+/// # Errors
+///
+/// Returns an error if a panel has nothing to plot or drawing fails.
+fn draw_panel_plot<DB>(
+    root: &DrawingArea<DB, Shift>,
+    theme: &PlotTheme,
+    title: &str,
+    grid: (usize, usize),
+    panels: &[AlgorithmChart],
+) -> Result<()>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    root.fill(&theme.background)?;
+    let body = root.titled(title, theme.title_font())?;
+    for (area, panel) in body.split_evenly(grid).iter().zip(panels) {
+        draw_algorithm_plot(area, theme, panel)?;
+    }
+    Ok(())
+}
+
+/// Draws the algorithm comparison chart onto any plotters backend.
+///
+/// # Parameters
+/// * `root` - Drawing area covering the whole image
+/// * `theme` - Dimensions, colors and fonts of the chart
+/// * `plot` - Data and axes of the chart
+///
+/// # Errors
+///
+/// Returns an error if there is nothing to plot, an explicit range is
+/// invalid or drawing fails.
+fn draw_algorithm_plot<DB>(
+    root: &DrawingArea<DB, Shift>,
+    theme: &PlotTheme,
+    plot: &AlgorithmChart,
+) -> Result<()>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let mut chart = setup_performance_chart(root, theme, plot)?;
+
+    // Configure mesh for performance benchmark charts
+    let x_label = |x: &f64| format_size(plot.x_scale, *x);
+    let y_label = |y: &f64| format_axis_value(plot.y_scale.value(*y));
+    chart
+        .configure_mesh()
+        .x_desc(plot.x_axis_label)
+        .y_desc(plot.metric.axis_label())
+        .label_style(theme.label_font())
+        .axis_desc_style(theme.label_font())
+        .axis_style(theme.foreground)
+        .bold_line_style(theme.grid_color.mix(0.2))
+        .light_line_style(theme.grid_color.mix(0.1))
+        .x_label_formatter(&x_label)
+        .y_label_formatter(&y_label)
+        .draw()?;
+
+    for index in 0..plot.series_names.len() {
+        plot_performance_line(&mut chart, theme, plot, index)?;
+        // A power law fitted to throughput would not describe the time
+        // complexity the legend names
+        if plot.fit_overlay && plot.metric == PlotMetric::Latency {
+            plot_fit_overlay(&mut chart, theme, plot, index)?;
+        }
+        add_point_labels(&mut chart, theme, plot, index)?;
+    }
+
+    draw_legend(&mut chart, theme)
+}
+
+/// Draws the legend box of every labelled series.
+///
+/// # Errors
+///
+/// Returns an error if drawing fails.
+fn draw_legend<'a, DB>(chart: &mut PlotChart<'a, DB>, theme: &PlotTheme) -> Result<()>
+where
+    DB: DrawingBackend + 'a,
+    DB::ErrorType: 'static,
+{
+    chart
+        .configure_series_labels()
+        .position(theme.legend_position.clone())
+        .label_font(theme.label_font())
+        .background_style(theme.background.mix(0.8))
+        .border_style(theme.foreground)
+        .draw()?;
+    Ok(())
+}
+
+/// Creates a performance comparison plot of two algorithms.
+///
+/// Compatibility wrapper around [`create_algorithm_plot`] for callers that
+/// still pass `(input_size, time1_ns, time2_ns, speedup)` tuples. The
+/// speedup labels are recomputed from the times, so the fourth tuple element
+/// is ignored.
 ///
 /// # Parameters
 /// * `filename` - Output SVG filename
 /// * `title` - Chart title
-/// * `algo1_name` - Name of the first algorithm
+/// * `algo1_name` - Name of the first (baseline) algorithm
 /// * `algo2_name` - Name of the second algorithm
 /// * `x_axis_label` - Label for the x-axis (e.g., "Sequence Length (N)", "Rule Count (M)")
 /// * `results` - Benchmark data as (input_size, time1_ns, time2_ns, speedup)
@@ -35,7 +667,7 @@ type PlotChart<'a> = ChartContext<'a, SVGBackend<'a>, Cartesian2d<RangedCoordf64
 ///
 /// # Errors
 ///
-/// Returns an error if chart creation fails.
+/// Returns an error if there is nothing to plot or chart creation fails.
 ///
 /// # Examples
 ///
@@ -43,23 +675,18 @@ type PlotChart<'a> = ChartContext<'a, SVGBackend<'a>, Cartesian2d<RangedCoordf64
 /// # use shared::plotting::create_dual_algorithm_plot;
 /// # use std::fs;
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
-/// // Sample benchmark data: (input_size, time1_ns, time2_ns, speedup)
-/// let results = vec![
-///     (1000, 50000.0, 25000.0, 2.0), // 1000 elements: 50μs vs 25μs,
-///                                      // 2x speedup
-///     (5000, 250000.0, 100000.0, 2.5), // 5000 elements: 250μs vs 100μs,
-///                                       // 2.5x speedup
-/// ];
+/// let results = vec![(1000, 50000.0, 25000.0, 2.0), (5000, 250000.0, 100000.0, 2.5)];
 ///
 /// create_dual_algorithm_plot(
-///     "benchmark_comparison.svg",
+///     "dual_comparison.svg",
 ///     "Algorithm Performance Comparison",
 ///     "Naive Algorithm",
 ///     "Optimized Algorithm",
 ///     "Input Size (N)",
 ///     &results
 /// )?;
-/// # fs::remove_file("benchmark_comparison.svg").ok();
+/// # fs::remove_file("dual_comparison.svg").ok();
+/// # fs::remove_file("dual_comparison.csv").ok();
 /// # Ok(())
 /// # }
 /// ```
@@ -71,134 +698,1227 @@ pub fn create_dual_algorithm_plot(
     x_axis_label: &str,
     results: &[(usize, f64, f64, f64)],
 ) -> Result<()> {
-    let (root, mut chart) = setup_dual_performance_chart(filename, title, results)?;
+    let results: Vec<BenchmarkResult> = results
+        .iter()
+        .map(|&(size, time1, time2, _)| BenchmarkResult::new(size, vec![time1, time2], Vec::new()))
+        .collect();
 
-    // Configure mesh for performance benchmark charts
+    let plot = AlgorithmChart {
+        title,
+        series_names: &[algo1_name, algo2_name],
+        x_axis_label,
+        metric: PlotMetric::Latency,
+        x_scale: AxisScale::Linear,
+        y_scale: AxisScale::Log,
+        x_range: None,
+        y_range: None,
+        fit_overlay: false,
+        annotation: PointAnnotation::Speedup,
+        results: &results,
+    };
+    create_algorithm_plot(filename, &PlotTheme::default(), &plot)
+}
+
+/// Creates a chart of each algorithm's speedup ratio versus input size.
+///
+/// Plots `times[i] / times[0]` for every algorithm after the baseline on a
+/// logarithmic y-axis, with a reference line at 1.0 where an algorithm
+/// matches the baseline. Easier to compare across sizes than the speedup
+/// labels of [`create_algorithm_plot`].
+///
+/// # Parameters
+/// * `filename` - Output filename; its extension selects the [`PlotFormat`]
+/// * `title` - Chart title
+/// * `series_names` - Legend names, one per entry in each result's `times`;
+///   the first names the baseline
+/// * `x_axis_label` - Label for the x-axis
+/// * `x_scale` - Scale of the x-axis
+/// * `theme` - Dimensions, colors and fonts of the chart
+/// * `results` - Benchmark data with one mean time in nanoseconds per
+///   algorithm
+///
+/// # Errors
+///
+/// Returns an error if there are fewer than two algorithms or no results,
+/// the format is unsupported or not enabled, or chart creation fails.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::{AxisScale, BenchmarkResult};
+/// # use shared::plotting::{create_speedup_plot, PlotTheme};
+/// # use std::fs;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let result = |size, times| BenchmarkResult::new(size, times, Vec::new());
+/// let results = vec![result(100, vec![10.0, 40.0]), result(1000, vec![100.0, 4000.0])];
+///
+/// create_speedup_plot(
+///     "speedup.svg",
+///     "Naive vs Optimized",
+///     &["Optimized", "Naive"],
+///     "Input Size (N)",
+///     AxisScale::Linear,
+///     &PlotTheme::default(),
+///     &results,
+/// )?;
+/// # fs::remove_file("speedup.svg").ok();
+/// # fs::remove_file("speedup.csv").ok();
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_speedup_plot(
+    filename: &str,
+    title: &str,
+    series_names: &[&str],
+    x_axis_label: &str,
+    x_scale: AxisScale,
+    theme: &PlotTheme,
+    results: &[BenchmarkResult],
+) -> Result<()> {
+    if series_names.len() < 2 {
+        bail!("A speedup plot needs a baseline and at least one other algorithm");
+    }
+
+    let dimensions = (theme.width, theme.height);
+    render!(filename, dimensions, |root| {
+        draw_speedup_plot(
+            root,
+            theme,
+            title,
+            series_names,
+            x_axis_label,
+            x_scale,
+            results,
+        )
+    });
+
+    println!("✅ Speedup plot saved as '{filename}'");
+    let rows: Vec<Vec<String>> = results
+        .iter()
+        .flat_map(|result| {
+            series_names
+                .iter()
+                .enumerate()
+                .skip(1)
+                .filter_map(|(index, name)| {
+                    let speedup = result.speedup(index)?;
+                    Some(vec![
+                        name.to_string(),
+                        result.size.to_string(),
+                        speedup.to_string(),
+                    ])
+                })
+        })
+        .collect();
+    write_plot_data(
+        filename,
+        &["series", "size", "time_relative_to_baseline"],
+        &rows,
+    )?;
+    Ok(())
+}
+
+/// Draws the speedup chart onto any plotters backend.
+///
+/// # Parameters
+/// * `root` - Drawing area covering the whole image
+/// * `theme` - Dimensions, colors and fonts of the chart
+/// * `title` - Chart title
+/// * `series_names` - Legend names; the first names the baseline
+/// * `x_axis_label` - Label for the x-axis
+/// * `x_scale` - Scale of the x-axis
+/// * `results` - Benchmark data with one mean time in nanoseconds per
+///   algorithm
+///
+/// # Errors
+///
+/// Returns an error if there is nothing to plot or drawing fails.
+fn draw_speedup_plot<DB>(
+    root: &DrawingArea<DB, Shift>,
+    theme: &PlotTheme,
+    title: &str,
+    series_names: &[&str],
+    x_axis_label: &str,
+    x_scale: AxisScale,
+    results: &[BenchmarkResult],
+) -> Result<()>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let x_range = x_axis_range(x_scale, results)?;
+    // Keep the 1.0 reference line inside the plotted range
+    let (min_speedup, max_speedup) = results
+        .iter()
+        .flat_map(|result| (1..result.times.len()).filter_map(|index| result.speedup(index)))
+        .fold((1.0, 1.0), |(min, max): (f64, f64), speedup| {
+            (min.min(speedup), max.max(speedup))
+        });
+    let mut chart = build_chart(
+        root,
+        theme,
+        title,
+        x_range.clone(),
+        (min_speedup / 1.5).log10()..(max_speedup * 1.5).log10(),
+    )?;
+
+    let x_label = |x: &f64| format_size(x_scale, *x);
     chart
         .configure_mesh()
         .x_desc(x_axis_label)
-        .y_desc("Time (microseconds)")
-        .x_label_formatter(&|x| format!("{x:.0}"))
-        .y_label_formatter(&|y| format!("{:.0}", 10f64.powf(*y) / 1000.0))
+        .y_desc(format!("Time relative to {}", series_names[0]))
+        .label_style(theme.label_font())
+        .axis_desc_style(theme.label_font())
+        .axis_style(theme.foreground)
+        .bold_line_style(theme.grid_color.mix(0.2))
+        .light_line_style(theme.grid_color.mix(0.1))
+        .x_label_formatter(&x_label)
+        .y_label_formatter(&|y| format!("{}x", format_axis_value(10f64.powf(*y))))
         .draw()?;
 
-    // Plot both algorithms
-    plot_performance_line(&mut chart, results, 0, &BLUE, algo1_name)?;
-    plot_performance_line(&mut chart, results, 1, &RED, algo2_name)?;
+    chart.draw_series(LineSeries::new(
+        [(x_range.start, 0.0), (x_range.end, 0.0)],
+        theme.foreground.mix(0.5),
+    ))?;
+
+    for (index, name) in series_names.iter().enumerate().skip(1) {
+        let points: Vec<(f64, f64)> = results
+            .iter()
+            .filter_map(|result| {
+                let speedup = result.speedup(index)?;
+                Some((x_scale.coordinate(result.size as f64), speedup.log10()))
+            })
+            .collect();
+        draw_line_with_points(&mut chart, theme, &points, theme.series_color(index), name)?;
+    }
+
+    draw_legend(&mut chart, theme)
+}
+
+/// Creates a histogram of how values are distributed.
+///
+/// Splits the range of the values into equally wide bins and draws one bar
+/// per bin, e.g. for timing samples, answer distributions or input
+/// statistics.
+///
+/// # Parameters
+/// * `filename` - Output filename; its extension selects the [`PlotFormat`]
+/// * `title` - Chart title
+/// * `values` - Values to count
+/// * `bins` - Number of bins to split the range of the values into
+/// * `theme` - Dimensions, colors and fonts of the chart; bars use its first
+///   series color
+///
+/// # Errors
+///
+/// Returns an error if there are no values, a value is not finite, `bins`
+/// is zero, the format is unsupported or not enabled, or chart creation
+/// fails.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::plotting::{create_histogram, PlotTheme};
+/// # use std::fs;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let samples = [10.2, 10.4, 10.5, 10.5, 10.7, 11.9];
+/// create_histogram("samples.svg", "Sample Times (μs)", &samples, 4, &PlotTheme::default())?;
+/// # fs::remove_file("samples.svg").ok();
+/// # fs::remove_file("samples.csv").ok();
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_histogram(
+    filename: &str,
+    title: &str,
+    values: &[f64],
+    bins: usize,
+    theme: &PlotTheme,
+) -> Result<()> {
+    let dimensions = (theme.width, theme.height);
+    render!(filename, dimensions, |root| {
+        draw_histogram(root, theme, title, values, bins)
+    });
+
+    println!("✅ Histogram saved as '{filename}'");
+    let (start, bin_width, counts) = bin_values(values, bins)?;
+    let rows: Vec<Vec<String>> = counts
+        .iter()
+        .enumerate()
+        .map(|(bin, count)| {
+            let lower = start + bin_width * bin as f64;
+            vec![
+                lower.to_string(),
+                (lower + bin_width).to_string(),
+                count.to_string(),
+            ]
+        })
+        .collect();
+    write_plot_data(filename, &["bin_start", "bin_end", "count"], &rows)?;
+    Ok(())
+}
 
-    // Add speedup labels above the second algorithm line
-    add_speedup_labels(&mut chart, results)?;
+/// Draws the histogram onto any plotters backend.
+///
+/// # Errors
+///
+/// Returns an error if the values cannot be binned or drawing fails.
+fn draw_histogram<DB>(
+    root: &DrawingArea<DB, Shift>,
+    theme: &PlotTheme,
+    title: &str,
+    values: &[f64],
+    bins: usize,
+) -> Result<()>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let (start, bin_width, counts) = bin_values(values, bins)?;
+    let end = start + bin_width * counts.len() as f64;
+    let max_count = counts.iter().copied().max().unwrap_or(0) as f64;
+    let mut chart = build_chart(root, theme, title, start..end, 0.0..max_count * 1.1)?;
 
     chart
-        .configure_series_labels()
-        .background_style(WHITE.mix(0.8))
-        .border_style(BLACK)
+        .configure_mesh()
+        .x_desc("Value")
+        .y_desc("Count")
+        .label_style(theme.label_font())
+        .axis_desc_style(theme.label_font())
+        .axis_style(theme.foreground)
+        .bold_line_style(theme.grid_color.mix(0.2))
+        .light_line_style(theme.grid_color.mix(0.1))
+        .x_label_formatter(&|x| format_axis_value(*x))
+        .y_label_formatter(&|y| format!("{y:.0}"))
         .draw()?;
 
-    root.present()?;
-    println!("✅ Performance plot saved as '{filename}'");
+    let color = theme.series_color(0);
+    chart.draw_series(counts.iter().enumerate().map(|(bin, &count)| {
+        let left = start + bin_width * bin as f64;
+        Rectangle::new(
+            [(left, 0.0), (left + bin_width, count as f64)],
+            color.mix(0.7).filled(),
+        )
+    }))?;
     Ok(())
 }
 
-/// Sets up the chart layout and coordinate system for dual-algorithm
-/// performance benchmarks.
+/// Counts how many values fall into each of `bins` equally wide bins.
 ///
-/// Creates the SVG backend, determines appropriate axis ranges from timing
-/// data, and builds the chart with logarithmic y-axis scaling for performance
-/// visualization.
+/// Matches the bars of [`create_histogram`], for callers that want the
+/// counts without drawing. The largest value belongs to the last bin. When
+/// all values are equal the bins span one unit centred on them.
+///
+/// # Returns
+/// `(start, bin_width, counts)` with the lower edge of the first bin
+///
+/// # Errors
+///
+/// Returns an error if there are no values, a value is not finite, or
+/// `bins` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::plotting::bin_values;
+/// let (start, bin_width, counts) = bin_values(&[1.0, 2.0, 2.5, 5.0], 2).unwrap();
+/// assert_eq!((start, bin_width, counts), (1.0, 2.0, vec![3, 1]));
+/// ```
+pub fn bin_values(values: &[f64], bins: usize) -> Result<(f64, f64, Vec<usize>)> {
+    if bins == 0 {
+        bail!("A histogram needs at least one bin");
+    }
+    if values.iter().any(|value| !value.is_finite()) {
+        bail!("Histogram values must be finite");
+    }
+    let (Some(min), Some(max)) = (
+        values.iter().copied().reduce(f64::min),
+        values.iter().copied().reduce(f64::max),
+    ) else {
+        bail!("No values to plot");
+    };
+
+    let (start, span) = if max > min {
+        (min, max - min)
+    } else {
+        (min - 0.5, 1.0)
+    };
+    let bin_width = span / bins as f64;
+    let mut counts = vec![0; bins];
+    for value in values {
+        let bin = ((value - start) / bin_width) as usize;
+        counts[bin.min(bins - 1)] += 1;
+    }
+    Ok((start, bin_width, counts))
+}
+
+/// Label and scale of one chart axis.
+///
+/// # Attributes
+/// * `label` - Axis description
+/// * `scale` - Whether the axis is linear or logarithmic
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlotAxis<'a> {
+    pub label: &'a str,
+    pub scale: AxisScale,
+}
+
+/// One point of a scatter plot.
+///
+/// # Attributes
+/// * `x` - Horizontal value
+/// * `y` - Vertical value
+/// * `label` - Text drawn next to the point, if any
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScatterPoint<'a> {
+    pub x: f64,
+    pub y: f64,
+    pub label: Option<&'a str>,
+}
+
+/// Creates a scatter plot of arbitrary `(x, y)` points.
+///
+/// Useful for correlations that are not benchmark series, e.g. the left
+/// against the right value of day01 pairs, or benchmark variance against
+/// input size.
 ///
 /// # Parameters
-/// * `filename` - Output SVG filename
+/// * `filename` - Output filename; its extension selects the [`PlotFormat`]
 /// * `title` - Chart title
-/// * `results` - Benchmark data used to determine axis ranges
+/// * `x_axis` - Label and scale of the x-axis
+/// * `y_axis` - Label and scale of the y-axis
+/// * `theme` - Dimensions, colors and fonts of the chart; points use its
+///   first series color
+/// * `points` - Points to draw, each optionally labelled
 ///
-/// # Returns
-/// drawing_area and configured chart, ready for mesh configuration and data
-/// plotting
+/// # Errors
+///
+/// Returns an error if there are no points, a coordinate is not finite or
+/// not positive on a logarithmic axis, the format is unsupported or not
+/// enabled, or chart creation fails.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::AxisScale;
+/// # use shared::plotting::{create_scatter_plot, PlotAxis, PlotTheme, ScatterPoint};
+/// # use std::fs;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let points = [
+///     ScatterPoint { x: 3.0, y: 4.0, label: None },
+///     ScatterPoint { x: 1.0, y: 3.0, label: Some("outlier") },
+///     ScatterPoint { x: 2.0, y: 5.0, label: None },
+/// ];
+/// create_scatter_plot(
+///     "pairs.svg",
+///     "Left vs Right",
+///     PlotAxis { label: "Left", scale: AxisScale::Linear },
+///     PlotAxis { label: "Right", scale: AxisScale::Linear },
+///     &PlotTheme::default(),
+///     &points,
+/// )?;
+/// # fs::remove_file("pairs.svg").ok();
+/// # fs::remove_file("pairs.csv").ok();
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_scatter_plot(
+    filename: &str,
+    title: &str,
+    x_axis: PlotAxis,
+    y_axis: PlotAxis,
+    theme: &PlotTheme,
+    points: &[ScatterPoint],
+) -> Result<()> {
+    let dimensions = (theme.width, theme.height);
+    render!(filename, dimensions, |root| {
+        draw_scatter_plot(root, theme, title, x_axis, y_axis, points)
+    });
+
+    println!("✅ Scatter plot saved as '{filename}'");
+    let rows: Vec<Vec<String>> = points
+        .iter()
+        .map(|point| {
+            vec![
+                point.x.to_string(),
+                point.y.to_string(),
+                point.label.unwrap_or_default().to_string(),
+            ]
+        })
+        .collect();
+    write_plot_data(filename, &[x_axis.label, y_axis.label, "label"], &rows)?;
+    Ok(())
+}
+
+/// Draws the scatter plot onto any plotters backend.
 ///
 /// # Errors
 ///
-/// Returns an error if the chart setup fails (e.g., invalid ranges, SVG
-/// backend issues).
-fn setup_dual_performance_chart<'a>(
-    filename: &'a str,
-    title: &'a str,
-    results: &[(usize, f64, f64, f64)],
-) -> Result<(
-    DrawingArea<SVGBackend<'a>, plotters::coord::Shift>,
-    PlotChart<'a>,
-)> {
-    let root = SVGBackend::new(filename, (CHART_WIDTH, CHART_HEIGHT)).into_drawing_area();
-    root.fill(&WHITE)?;
-
-    let max_size = results
+/// Returns an error if an axis range cannot be determined or drawing fails.
+fn draw_scatter_plot<DB>(
+    root: &DrawingArea<DB, Shift>,
+    theme: &PlotTheme,
+    title: &str,
+    x_axis: PlotAxis,
+    y_axis: PlotAxis,
+    points: &[ScatterPoint],
+) -> Result<()>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let x_range = scatter_range(x_axis, points.iter().map(|point| point.x))?;
+    let y_range = scatter_range(y_axis, points.iter().map(|point| point.y))?;
+    let mut chart = build_chart(root, theme, title, x_range, y_range)?;
+
+    let x_label = |x: &f64| format_axis_value(x_axis.scale.value(*x));
+    let y_label = |y: &f64| format_axis_value(y_axis.scale.value(*y));
+    chart
+        .configure_mesh()
+        .x_desc(x_axis.label)
+        .y_desc(y_axis.label)
+        .label_style(theme.label_font())
+        .axis_desc_style(theme.label_font())
+        .axis_style(theme.foreground)
+        .bold_line_style(theme.grid_color.mix(0.2))
+        .light_line_style(theme.grid_color.mix(0.1))
+        .x_label_formatter(&x_label)
+        .y_label_formatter(&y_label)
+        .draw()?;
+
+    let color = theme.series_color(0);
+    let offset = theme.marker_radius + 2;
+    chart.draw_series(points.iter().map(|point| {
+        let position = (
+            x_axis.scale.coordinate(point.x),
+            y_axis.scale.coordinate(point.y),
+        );
+        EmptyElement::at(position)
+            + Circle::new((0, 0), theme.marker_radius, color.filled())
+            + Text::new(
+                point.label.unwrap_or_default().to_string(),
+                (offset, -offset),
+                theme.label_font(),
+            )
+    }))?;
+    Ok(())
+}
+
+/// Determines an axis range covering values with 5% padding on each side.
+///
+/// # Errors
+///
+/// Returns an error if there are no values, a value is not finite, or a
+/// value is not positive on a logarithmic axis.
+fn scatter_range(axis: PlotAxis, values: impl Iterator<Item = f64>) -> Result<Range<f64>> {
+    let mut coordinates = Vec::new();
+    for value in values {
+        if axis.scale == AxisScale::Log && value <= 0.0 {
+            bail!(
+                "A logarithmic '{}' axis needs values above zero",
+                axis.label
+            );
+        }
+        let coordinate = axis.scale.coordinate(value);
+        if !coordinate.is_finite() {
+            bail!("Scatter values on the '{}' axis must be finite", axis.label);
+        }
+        coordinates.push(coordinate);
+    }
+    let (Some(min), Some(max)) = (
+        coordinates.iter().copied().reduce(f64::min),
+        coordinates.iter().copied().reduce(f64::max),
+    ) else {
+        bail!("No points to plot");
+    };
+
+    // A single distinct value still gets an axis of unit width
+    let padding = if max > min { (max - min) * 0.05 } else { 0.5 };
+    Ok(min - padding..max + padding)
+}
+
+/// Creates a grouped bar chart of where the total runtime is spent.
+///
+/// Draws one group of bars per day with one bar per puzzle part, each the
+/// real-input time of the part's fastest algorithm as picked by
+/// [`part_runtimes`]. The time axis is logarithmic since days differ by
+/// orders of magnitude.
+///
+/// # Parameters
+/// * `filename` - Output filename; its extension selects the [`PlotFormat`]
+/// * `title` - Chart title
+/// * `theme` - Dimensions, colors and fonts of the chart; parts use its
+///   series colors in order
+/// * `benchmarks` - Entries gathered by
+///   [`aggregate_benchmarks`](crate::benchmarking::aggregate_benchmarks)
+///
+/// # Errors
+///
+/// Returns an error if no benchmark has a recorded part, the format is
+/// unsupported or not enabled, or chart creation fails.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::DayBenchmark;
+/// # use shared::plotting::{create_runtime_bar_chart, PlotTheme};
+/// # use std::fs;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let benchmark = |day, part, real_input_ns| DayBenchmark {
+///     day,
+///     group: "real".to_string(),
+///     algorithm: "optimized".to_string(),
+///     part: Some(part),
+///     real_input_ns,
+/// };
+/// let benchmarks = [
+///     benchmark(1, 1, 40_000.0),
+///     benchmark(1, 2, 55_000.0),
+///     benchmark(5, 1, 1_200_000.0),
+/// ];
+/// create_runtime_bar_chart("runtime.svg", "Runtime by Day", &PlotTheme::default(), &benchmarks)?;
+/// # fs::remove_file("runtime.svg").ok();
+/// # fs::remove_file("runtime.csv").ok();
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_runtime_bar_chart(
+    filename: &str,
+    title: &str,
+    theme: &PlotTheme,
+    benchmarks: &[DayBenchmark],
+) -> Result<()> {
+    let dimensions = (theme.width, theme.height);
+    render!(filename, dimensions, |root| {
+        draw_runtime_bar_chart(root, theme, title, benchmarks)
+    });
+
+    println!("✅ Runtime chart saved as '{filename}'");
+    let rows: Vec<Vec<String>> = part_runtimes(benchmarks)
+        .into_iter()
+        .map(|((day, part), time)| {
+            vec![
+                day.to_string(),
+                part.to_string(),
+                (time / 1000.0).to_string(),
+            ]
+        })
+        .collect();
+    write_plot_data(
+        filename,
+        &["day", "part", PlotMetric::Latency.axis_label()],
+        &rows,
+    )?;
+    Ok(())
+}
+
+/// Draws the runtime bar chart onto any plotters backend.
+///
+/// # Errors
+///
+/// Returns an error if no benchmark has a recorded part or drawing fails.
+fn draw_runtime_bar_chart<DB>(
+    root: &DrawingArea<DB, Shift>,
+    theme: &PlotTheme,
+    title: &str,
+    benchmarks: &[DayBenchmark],
+) -> Result<()>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let runtimes = part_runtimes(benchmarks);
+    let days: BTreeSet<u8> = runtimes.keys().map(|&(day, _)| day).collect();
+    let parts: BTreeSet<u8> = runtimes.keys().map(|&(_, part)| part).collect();
+    let days: Vec<u8> = days.into_iter().collect();
+    let (Some(fastest), Some(slowest)) = (
+        runtimes.values().copied().reduce(f64::min),
+        runtimes.values().copied().reduce(f64::max),
+    ) else {
+        bail!("No benchmarks with a recorded part to chart");
+    };
+
+    // Bars rise from the bottom of the log axis, in microseconds
+    let y_range = (fastest / 2000.0).log10()..(slowest * 2.0 / 1000.0).log10();
+    let bottom = y_range.start;
+    let x_range = -0.5..days.len() as f64 - 0.5;
+    let mut chart = build_chart(root, theme, title, x_range, y_range)?;
+
+    let x_label = |x: &f64| {
+        let column = x.round();
+        match days.get(column as usize) {
+            Some(day) if (x - column).abs() < 1e-6 && column >= 0.0 => format!("Day {day}"),
+            _ => String::new(),
+        }
+    };
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_labels(days.len())
+        .y_desc(PlotMetric::Latency.axis_label())
+        .label_style(theme.label_font())
+        .axis_desc_style(theme.label_font())
+        .axis_style(theme.foreground)
+        .bold_line_style(theme.grid_color.mix(0.2))
+        .light_line_style(theme.grid_color.mix(0.1))
+        .x_label_formatter(&x_label)
+        .y_label_formatter(&|y| format_axis_value(10f64.powf(*y)))
+        .draw()?;
+
+    let bar_width = BAR_GROUP_WIDTH / parts.len() as f64;
+    for (slot, &part) in parts.iter().enumerate() {
+        let color = theme.series_color(slot);
+        let bars: Vec<_> = days
+            .iter()
+            .enumerate()
+            .filter_map(|(column, &day)| {
+                let time = runtimes.get(&(day, part))?;
+                let left = column as f64 - BAR_GROUP_WIDTH / 2.0 + bar_width * slot as f64;
+                Some(Rectangle::new(
+                    [(left, bottom), (left + bar_width, (time / 1000.0).log10())],
+                    color.filled(),
+                ))
+            })
+            .collect();
+        chart
+            .draw_series(bars)?
+            .label(format!("Part {part}"))
+            .legend(move |(x, y)| {
+                Rectangle::new(
+                    [(x, y - 4), (x + LEGEND_LINE_LENGTH, y + 4)],
+                    color.filled(),
+                )
+            });
+    }
+
+    draw_legend(&mut chart, theme)
+}
+
+/// Creates a box plot of each algorithm's sample distribution per size.
+///
+/// Draws one group of boxes per size with one box per algorithm, built from
+/// the [`SampleStats`](crate::benchmarking::SampleStats) of Criterion's raw
+/// samples. Boxes span the quartiles with a line at the median, whiskers
+/// span the 5th to 95th percentile, and the fastest and slowest samples are
+/// marked when they lie outside the whiskers. Complements the mean lines of
+/// [`create_algorithm_plot`] when diagnosing noisy benchmarks. The time axis
+/// is logarithmic.
+///
+/// # Parameters
+/// * `filename` - Output filename; its extension selects the [`PlotFormat`]
+/// * `title` - Chart title
+/// * `series_names` - Legend names, one per entry in each result's
+///   `distributions`
+/// * `x_axis_label` - Label for the x-axis
+/// * `theme` - Dimensions, colors and fonts of the chart
+/// * `results` - Benchmark results; those without distributions are skipped
+///
+/// # Errors
+///
+/// Returns an error if no result has distributions, the format is
+/// unsupported or not enabled, or chart creation fails.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::benchmarking::{BenchmarkResult, SampleStats};
+/// # use shared::plotting::{create_distribution_plot, PlotTheme};
+/// # use std::fs;
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let samples = |scale: f64| {
+///     let samples: Vec<f64> = (1..=20).map(|sample| scale * f64::from(sample)).collect();
+///     SampleStats::from_samples(&samples).unwrap()
+/// };
+/// let results = vec![BenchmarkResult {
+///     distributions: vec![samples(1000.0), samples(5000.0)],
+///     ..BenchmarkResult::new(1000, vec![10_500.0, 52_500.0], Vec::new())
+/// }];
+/// create_distribution_plot(
+///     "distribution.svg",
+///     "Sample Distribution",
+///     &["Optimized", "Naive"],
+///     "Input Size (N)",
+///     &PlotTheme::default(),
+///     &results,
+/// )?;
+/// # fs::remove_file("distribution.svg").ok();
+/// # fs::remove_file("distribution.csv").ok();
+/// # Ok(())
+/// # }
+/// ```
+pub fn create_distribution_plot(
+    filename: &str,
+    title: &str,
+    series_names: &[&str],
+    x_axis_label: &str,
+    theme: &PlotTheme,
+    results: &[BenchmarkResult],
+) -> Result<()> {
+    let results: Vec<&BenchmarkResult> = results
         .iter()
-        .map(|(size, _, _, _)| *size)
-        .max()
-        .context("No data points to plot")?;
-    let times: Vec<f64> = results
+        .filter(|result| !result.distributions.is_empty())
+        .collect();
+    if results.is_empty() {
+        bail!("No sample distributions to plot");
+    }
+
+    let dimensions = (theme.width, theme.height);
+    render!(filename, dimensions, |root| {
+        draw_distribution_plot(root, theme, title, series_names, x_axis_label, &results)
+    });
+
+    println!("✅ Distribution plot saved as '{filename}'");
+    let rows: Vec<Vec<String>> = results
         .iter()
-        .flat_map(|(_, t1, t2, _)| [*t1, *t2])
+        .flat_map(|result| {
+            series_names
+                .iter()
+                .zip(&result.distributions)
+                .map(|(name, stats)| {
+                    let mut row = vec![result.size.to_string(), name.to_string()];
+                    row.extend(
+                        [
+                            stats.min,
+                            stats.p5,
+                            stats.q1,
+                            stats.median,
+                            stats.q3,
+                            stats.p95,
+                            stats.max,
+                        ]
+                        .iter()
+                        .map(|nanos| (nanos / 1000.0).to_string()),
+                    );
+                    row
+                })
+        })
         .collect();
-    let (min_time, max_time) = (
-        times.iter().copied().fold(f64::INFINITY, f64::min),
-        times.iter().copied().fold(0.0, f64::max),
+    write_plot_data(
+        filename,
+        &[
+            x_axis_label,
+            "series",
+            "min_us",
+            "p5_us",
+            "q1_us",
+            "median_us",
+            "q3_us",
+            "p95_us",
+            "max_us",
+        ],
+        &rows,
+    )?;
+    Ok(())
+}
+
+/// Draws the distribution box plot onto any plotters backend.
+///
+/// # Errors
+///
+/// Returns an error if a sample is not positive or drawing fails.
+fn draw_distribution_plot<DB>(
+    root: &DrawingArea<DB, Shift>,
+    theme: &PlotTheme,
+    title: &str,
+    series_names: &[&str],
+    x_axis_label: &str,
+    results: &[&BenchmarkResult],
+) -> Result<()>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let stats = || results.iter().flat_map(|result| &result.distributions);
+    let (Some(fastest), Some(slowest)) = (
+        stats().map(|stats| stats.min).reduce(f64::min),
+        stats().map(|stats| stats.max).reduce(f64::max),
+    ) else {
+        bail!("No sample distributions to plot");
+    };
+    if fastest <= 0.0 {
+        bail!("Samples must be positive on a logarithmic time axis");
+    }
+
+    // Times in microseconds on a log axis, padded by a factor of 1.5
+    let y = |nanos: f64| (nanos / 1000.0).log10();
+    let y_range = y(fastest / 1.5)..y(slowest * 1.5);
+    let x_range = -0.5..results.len() as f64 - 0.5;
+    let mut chart = build_chart(root, theme, title, x_range, y_range)?;
+
+    let x_label = |x: &f64| {
+        let column = x.round();
+        match results.get(column as usize) {
+            Some(result) if (x - column).abs() < 1e-6 && column >= 0.0 => result.size.to_string(),
+            _ => String::new(),
+        }
+    };
+    chart
+        .configure_mesh()
+        .disable_x_mesh()
+        .x_labels(results.len())
+        .x_desc(x_axis_label)
+        .y_desc(PlotMetric::Latency.axis_label())
+        .label_style(theme.label_font())
+        .axis_desc_style(theme.label_font())
+        .axis_style(theme.foreground)
+        .bold_line_style(theme.grid_color.mix(0.2))
+        .light_line_style(theme.grid_color.mix(0.1))
+        .x_label_formatter(&x_label)
+        .y_label_formatter(&|y| format_axis_value(10f64.powf(*y)))
+        .draw()?;
+
+    let box_width = BAR_GROUP_WIDTH / series_names.len().max(1) as f64;
+    for (slot, name) in series_names.iter().enumerate() {
+        let color = theme.series_color(slot);
+        let mut elements: Vec<DynElement<DB, (f64, f64)>> = Vec::new();
+        for (column, result) in results.iter().enumerate() {
+            let Some(stats) = result.distributions.get(slot) else {
+                continue;
+            };
+            let left = column as f64 - BAR_GROUP_WIDTH / 2.0 + box_width * slot as f64;
+            let (inner_left, inner_right) = (left + box_width * 0.15, left + box_width * 0.85);
+            let center = left + box_width / 2.0;
+            let cap = |nanos: f64| {
+                PathElement::new(
+                    vec![
+                        (center - box_width * 0.2, y(nanos)),
+                        (center + box_width * 0.2, y(nanos)),
+                    ],
+                    color,
+                )
+            };
+
+            elements.push(
+                PathElement::new(vec![(center, y(stats.p5)), (center, y(stats.p95))], color)
+                    .into_dyn(),
+            );
+            elements.push(cap(stats.p5).into_dyn());
+            elements.push(cap(stats.p95).into_dyn());
+            elements.push(
+                Rectangle::new(
+                    [(inner_left, y(stats.q1)), (inner_right, y(stats.q3))],
+                    color.mix(0.4).filled(),
+                )
+                .into_dyn(),
+            );
+            elements.push(
+                Rectangle::new(
+                    [(inner_left, y(stats.q1)), (inner_right, y(stats.q3))],
+                    color,
+                )
+                .into_dyn(),
+            );
+            elements.push(
+                PathElement::new(
+                    vec![
+                        (inner_left, y(stats.median)),
+                        (inner_right, y(stats.median)),
+                    ],
+                    color.stroke_width(2),
+                )
+                .into_dyn(),
+            );
+            // Extremes beyond the whiskers hint at outliers
+            for extreme in [stats.min, stats.max] {
+                if extreme < stats.p5 || extreme > stats.p95 {
+                    elements.push(
+                        Circle::new((center, y(extreme)), theme.marker_radius, color).into_dyn(),
+                    );
+                }
+            }
+        }
+        chart
+            .draw_series(elements)?
+            .label(*name)
+            .legend(move |(x, y)| {
+                Rectangle::new(
+                    [(x, y - 4), (x + LEGEND_LINE_LENGTH, y + 4)],
+                    color.mix(0.4).filled(),
+                )
+            });
+    }
+
+    draw_legend(&mut chart, theme)
+}
+
+/// Sets up the chart layout and coordinate system for algorithm performance
+/// benchmarks.
+///
+/// Clears the drawing area, takes the explicit axis ranges or determines
+/// them from timing data, and builds the chart on the requested scales.
+///
+/// # Parameters
+/// * `root` - Drawing area covering the whole image
+/// * `theme` - Dimensions, colors and fonts of the chart
+/// * `plot` - Data and axes of the chart
+///
+/// # Returns
+/// Configured chart, ready for mesh configuration and data plotting
+///
+/// # Errors
+///
+/// Returns an error if the chart setup fails (e.g., invalid ranges, a zero
+/// size on a logarithmic x-axis, backend issues).
+fn setup_performance_chart<'a, DB>(
+    root: &'a DrawingArea<DB, Shift>,
+    theme: &PlotTheme,
+    plot: &AlgorithmChart,
+) -> Result<PlotChart<'a, DB>>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let x_range = match plot.x_range {
+        Some(range) => explicit_range(plot.x_scale, range, "x")?,
+        None => x_axis_range(plot.x_scale, plot.results)?,
+    };
+    let y_range = match plot.y_range {
+        Some(range) => explicit_range(plot.y_scale, range, "y")?,
+        None => {
+            let values: Vec<f64> = plot
+                .results
+                .iter()
+                .flat_map(|result| {
+                    (0..result.times.len()).filter_map(|index| plot.metric.value(result, index))
+                })
+                .collect();
+            let (min_value, max_value) = (
+                values.iter().copied().fold(f64::INFINITY, f64::min),
+                values.iter().copied().fold(0.0, f64::max),
+            );
+            match plot.y_scale {
+                AxisScale::Linear => 0.0..max_value * 1.1,
+                AxisScale::Log => (min_value * 0.5).log10()..(max_value * 2.0).log10(),
+            }
+        }
+    };
+
+    build_chart(root, theme, plot.title, x_range, y_range)
+}
+
+/// Maps a user-chosen `(start, end)` range onto an axis.
+///
+/// # Errors
+///
+/// Returns an error if the range is empty or reversed, or not positive on a
+/// logarithmic axis.
+fn explicit_range(scale: AxisScale, (start, end): (f64, f64), axis: &str) -> Result<Range<f64>> {
+    if !start.is_finite() || !end.is_finite() || start >= end {
+        bail!("The {axis} range must start below its end, got {start}..{end}");
+    }
+    if scale == AxisScale::Log && start <= 0.0 {
+        bail!("A logarithmic {axis} range must start above zero, got {start}");
+    }
+    Ok(scale.coordinate(start)..scale.coordinate(end))
+}
+
+/// Returns the x-axis range covering every benchmarked size.
+///
+/// # Errors
+///
+/// Returns an error if there are no results, or a size is zero on a
+/// logarithmic axis.
+fn x_axis_range(x_scale: AxisScale, results: &[BenchmarkResult]) -> Result<Range<f64>> {
+    let sizes = results.iter().map(|result| result.size);
+    let (min_size, max_size) = (
+        sizes.clone().min().context("No data points to plot")? as f64,
+        sizes.max().context("No data points to plot")? as f64,
     );
+    match x_scale {
+        AxisScale::Linear => Ok(0.0..max_size * 1.1),
+        AxisScale::Log if min_size > 0.0 => Ok((min_size * 0.8).log10()..(max_size * 1.25).log10()),
+        AxisScale::Log => bail!("A logarithmic x-axis needs sizes above zero"),
+    }
+}
 
-    let chart = ChartBuilder::on(&root)
-        .caption(title, ("sans-serif", 24))
+/// Clears the drawing area and builds a chart with the shared layout.
+///
+/// # Errors
+///
+/// Returns an error if drawing fails.
+fn build_chart<'a, DB>(
+    root: &'a DrawingArea<DB, Shift>,
+    theme: &PlotTheme,
+    title: &str,
+    x_range: Range<f64>,
+    y_range: Range<f64>,
+) -> Result<PlotChart<'a, DB>>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    root.fill(&theme.background)?;
+    let chart = ChartBuilder::on(root)
+        .caption(title, theme.title_font())
         .margin(50)
         .margin_top(50)
         .margin_bottom(40)
         .x_label_area_size(50)
         .y_label_area_size(50)
-        .build_cartesian_2d(
-            0f64..(max_size as f64 * 1.1),
-            (min_time * 0.5).log10()..(max_time * 2.0).log10(),
-        )?;
-
-    Ok((root, chart))
+        .build_cartesian_2d(x_range, y_range)?;
+    Ok(chart)
 }
 
 /// Plots a single algorithm's performance line.
 ///
-/// Extracts timing data for one algorithm, applies logarithmic transformation,
-/// and draws a line with points for that algorithm's performance.
+/// Extracts one algorithm's values of the plotted metric, maps them onto the
+/// y-axis scale, and draws a line with points for its performance. Points
+/// with a confidence interval get an error bar spanning it.
 ///
 /// # Parameters
 /// * `chart` - Mutable reference to the chart context for drawing operations
-/// * `results` - Benchmark data as tuples of (input_size, time1_ns, time2_ns,
-///   speedup)
-/// * `time_index` - Which time column to use (0 for first algorithm, 1 for
-///   second)
-/// * `color` - Color for the line and markers
-/// * `label` - Label for the legend entry
-/// # Errors
+/// * `theme` - Supplies the line color and marker size
+/// * `plot` - Data and axes of the chart
+/// * `time_index` - Which algorithm's times to plot, also selecting its color
+///   and legend name
 ///
-/// Returns an error if chart drawing operations fail (SVG backend errors,
-/// invalid coordinates).
+/// # Errors
 ///
-fn plot_performance_line<'a>(
-    chart: &mut PlotChart<'a>,
-    results: &[(usize, f64, f64, f64)],
+/// Returns an error if a result has no time at `time_index` or chart
+/// drawing operations fail (backend errors, invalid coordinates).
+fn plot_performance_line<'a, DB>(
+    chart: &mut PlotChart<'a, DB>,
+    theme: &PlotTheme,
+    plot: &AlgorithmChart,
     time_index: usize,
-    color: &'a RGBColor,
-    label: &str,
-) -> Result<()> {
-    let points: Vec<(f64, f64)> = results
+) -> Result<()>
+where
+    DB: DrawingBackend + 'a,
+    DB::ErrorType: 'static,
+{
+    let (metric, x_scale, y_scale) = (plot.metric, plot.x_scale, plot.y_scale);
+    let color = theme.series_color(time_index);
+    let points: Vec<(f64, f64)> = plot
+        .results
         .iter()
-        .map(|(size, time1, time2, time3)| {
-            let time = match time_index {
-                0 => *time1,
-                1 => *time2,
-                2 => *time3,
-                _ => bail!("Invalid time_index: {time_index}. Must be 0, 1, or 2"),
-            };
-            Ok((*size as f64, time.log10()))
+        .map(|result| {
+            let value = metric.value(result, time_index).with_context(|| {
+                format!("No time for series {time_index} at size {}", result.size)
+            })?;
+            Ok((
+                x_scale.coordinate(result.size as f64),
+                y_scale.coordinate(value),
+            ))
         })
-        .try_collect()?;
+        .collect::<Result<_>>()?;
 
-    draw_line_with_points(chart, &points, color, label)
+    draw_line_with_points(chart, theme, &points, color, plot.series_names[time_index])?;
+
+    let error_bars: Vec<_> = plot
+        .results
+        .iter()
+        .filter_map(|result| {
+            let (low, high) = metric.interval(result, time_index)?;
+            let value = metric.value(result, time_index)?;
+            // A log axis cannot show an interval reaching down to zero
+            (y_scale == AxisScale::Linear || low > 0.0).then(|| {
+                ErrorBar::new_vertical(
+                    x_scale.coordinate(result.size as f64),
+                    y_scale.coordinate(low),
+                    y_scale.coordinate(value),
+                    y_scale.coordinate(high),
+                    color,
+                    ERROR_BAR_WIDTH,
+                )
+            })
+        })
+        .collect();
+    chart.draw_series(error_bars)?;
+    Ok(())
+}
+
+/// Draws one algorithm's fitted power law as a dashed curve.
+///
+/// The legend entry names the fitted exponent, e.g. `naive: ~n^1.98`.
+/// Series too short to fit are left without an overlay.
+///
+/// # Parameters
+/// * `chart` - Mutable reference to the chart context for drawing operations
+/// * `theme` - Supplies the line color
+/// * `plot` - Data and axes of the chart
+/// * `time_index` - Which algorithm's times to fit, also selecting its color
+///   and legend name
+///
+/// # Errors
+///
+/// Returns an error if drawing fails.
+fn plot_fit_overlay<'a, DB>(
+    chart: &mut PlotChart<'a, DB>,
+    theme: &PlotTheme,
+    plot: &AlgorithmChart,
+    time_index: usize,
+) -> Result<()>
+where
+    DB: DrawingBackend + 'a,
+    DB::ErrorType: 'static,
+{
+    let measurements: Vec<(usize, f64)> = plot
+        .results
+        .iter()
+        .filter_map(|result| Some((result.size, *result.times.get(time_index)?)))
+        .collect();
+    let Some(fit) = fit_power_law(&measurements) else {
+        return Ok(());
+    };
+    let (Some(first), Some(last)) = (
+        measurements.iter().map(|&(size, _)| size).min(),
+        measurements.iter().map(|&(size, _)| size).max(),
+    ) else {
+        return Ok(());
+    };
+
+    let color = theme.series_color(time_index);
+    let curve = fit_curve(fit, plot.x_scale, plot.y_scale, first as f64, last as f64);
+    chart
+        .draw_series(DashedLineSeries::new(
+            curve,
+            FIT_DASH,
+            FIT_DASH,
+            color.into(),
+        ))?
+        .label(format!(
+            "{}: ~n^{:.2}",
+            plot.series_names[time_index], fit.exponent
+        ))
+        .legend(move |(x, y)| {
+            DashedPathElement::new(
+                vec![(x, y), (x + LEGEND_LINE_LENGTH, y)],
+                FIT_DASH / 2,
+                FIT_DASH / 2,
+                color,
+            )
+        });
+    Ok(())
+}
+
+/// Samples a fitted power law across a size range in chart coordinates.
+///
+/// Samples are evenly spaced along the x-axis so the curve looks smooth on
+/// either scale.
+///
+/// # Parameters
+/// * `fit` - Fitted power law of times in nanoseconds
+/// * `x_scale` - Scale of the x-axis
+/// * `y_scale` - Scale of the y-axis
+/// * `first` - Smallest measured size
+/// * `last` - Largest measured size
+///
+/// # Returns
+/// `(x, y)` chart coordinates of microseconds from `first` to `last`
+fn fit_curve(
+    fit: PowerLawFit,
+    x_scale: AxisScale,
+    y_scale: AxisScale,
+    first: f64,
+    last: f64,
+) -> Vec<(f64, f64)> {
+    let (start, end) = (x_scale.coordinate(first), x_scale.coordinate(last));
+    (0..=FIT_SEGMENTS)
+        .map(|segment| {
+            let x = start + (end - start) * f64::from(segment) / f64::from(FIT_SEGMENTS);
+            (x, y_scale.coordinate(fit.apply(x_scale.value(x)) / 1000.0))
+        })
+        .collect()
 }
 
 /// Draws a performance line with circular markers and legend entry.
@@ -208,58 +1928,205 @@ fn plot_performance_line<'a>(
 ///
 /// # Parameters
 /// * `chart` - Mutable reference to the chart context for drawing operations
+/// * `theme` - Supplies the marker radius
 /// * `points` - Array of (x, y) coordinates representing algorithm
 ///   performance data
-/// * `color` - Reference to the RGB color for drawing the line and markers
+/// * `color` - RGB color for drawing the line and markers
 /// * `label` - Text label for the legend entry describing this algorithm
 ///
 /// # Errors
 ///
 /// Returns an error if drawing fails.
-fn draw_line_with_points<'a>(
-    chart: &mut PlotChart<'a>,
+fn draw_line_with_points<'a, DB>(
+    chart: &mut PlotChart<'a, DB>,
+    theme: &PlotTheme,
     points: &[(f64, f64)],
-    color: &'a RGBColor,
+    color: RGBColor,
     label: &str,
-) -> Result<()> {
+) -> Result<()>
+where
+    DB: DrawingBackend + 'a,
+    DB::ErrorType: 'static,
+{
     chart
         .draw_series(LineSeries::new(points.iter().copied(), color))?
         .label(label)
-        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + LEGEND_LINE_LENGTH, y)], *color));
+        .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + LEGEND_LINE_LENGTH, y)], color));
     chart.draw_series(
         points
             .iter()
-            .map(|&(x, y)| Circle::new((x, y), CIRCLE_RADIUS, color.filled())),
+            .map(|&(x, y)| Circle::new((x, y), theme.marker_radius, color.filled())),
     )?;
     Ok(())
 }
 
-/// Adds speedup factor labels above the second algorithm performance line.
+/// Adds the chart's [`PointAnnotation`] labels above one algorithm's
+/// performance line.
 ///
-/// Places text annotations showing the performance improvement factor
-/// at each data point for easy interpretation of results.
+/// Speedup labels show the factor relative to the baseline algorithm, so the
+/// baseline itself is never labelled with them. Value labels show the
+/// plotted value in the metric's units.
 ///
 /// # Parameters
 /// * `chart` - Mutable reference to the chart context for drawing text labels
-/// * `results` - Benchmark data as tuples of (input_size, time1_ns, time2_ns,
-///   speedup_factor)
+/// * `theme` - Supplies the label font
+/// * `plot` - Data and axes of the chart
+/// * `time_index` - Which algorithm's line to label
 ///
 /// # Errors
 ///
 /// Returns an error if drawing the labels fails (e.g., invalid coordinates,
-/// SVG backend issues).
-fn add_speedup_labels(chart: &mut PlotChart<'_>, results: &[(usize, f64, f64, f64)]) -> Result<()> {
-    let labels: Vec<_> = results
+/// backend issues).
+fn add_point_labels<DB>(
+    chart: &mut PlotChart<'_, DB>,
+    theme: &PlotTheme,
+    plot: &AlgorithmChart,
+    time_index: usize,
+) -> Result<()>
+where
+    DB: DrawingBackend,
+    DB::ErrorType: 'static,
+{
+    let last = plot.results.len().saturating_sub(1);
+    let labels: Vec<_> = plot
+        .results
         .iter()
-        .map(|(size, _, time2, speedup)| {
-            Text::new(
-                format!("{speedup:.1}x"),
-                (*size as f64, time2.log10() * 1.05),
-                ("sans-serif", 12),
-            )
+        .enumerate()
+        .filter_map(|(position, result)| {
+            let value = plot.metric.value(result, time_index)?;
+            let text = match plot.annotation {
+                PointAnnotation::Speedup if time_index > 0 => {
+                    format!("{:.1}x", result.speedup(time_index)?)
+                }
+                // Results are in ascending size order
+                PointAnnotation::MinMax
+                    if time_index > 0 && (position == 0 || position == last) =>
+                {
+                    format!("{:.1}x", result.speedup(time_index)?)
+                }
+                PointAnnotation::Value => format_point_value(plot.metric, value),
+                _ => return None,
+            };
+            let position = (
+                plot.x_scale.coordinate(result.size as f64),
+                plot.y_scale.coordinate(value),
+            );
+            Some(EmptyElement::at(position) + Text::new(text, (0, -LABEL_LIFT), theme.label_font()))
         })
         .collect();
 
     chart.draw_series(labels)?;
     Ok(())
 }
+
+/// Formats a plotted value with the unit of its metric, e.g. `12.50μs`.
+fn format_point_value(metric: PlotMetric, value: f64) -> String {
+    let unit = match metric {
+        PlotMetric::Latency => "μs",
+        PlotMetric::Throughput(ThroughputUnit::Elements) => " Melem/s",
+        PlotMetric::Throughput(ThroughputUnit::Bytes) => " MB/s",
+    };
+    format!("{value:.2}{unit}")
+}
+
+/// Returns the path of the CSV file holding a chart's plotted values.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::plotting::plot_data_path;
+/// # use std::path::PathBuf;
+/// assert_eq!(plot_data_path("data/scaling.svg"), PathBuf::from("data/scaling.csv"));
+/// ```
+pub fn plot_data_path(filename: &str) -> PathBuf {
+    Path::new(filename).with_extension("csv")
+}
+
+/// Writes the values behind a chart to its [`plot_data_path`].
+///
+/// Values are raw, before any logarithmic axis transform, so the chart can
+/// be redrawn in other tools.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+fn write_plot_data(filename: &str, header: &[&str], rows: &[Vec<String>]) -> Result<()> {
+    let mut csv = String::new();
+    for row in std::iter::once(header.iter().map(|column| column.to_string()).collect())
+        .chain(rows.iter().cloned())
+    {
+        let fields: Vec<String> = row.iter().map(|field| csv_field(field)).collect();
+        csv.push_str(&fields.join(","));
+        csv.push('\n');
+    }
+
+    let path = plot_data_path(filename);
+    std::fs::write(&path, csv).with_context(|| format!("Failed to write {}", path.display()))?;
+    println!("🧾 Plot data saved as '{}'", path.display());
+    Ok(())
+}
+
+/// Quotes a CSV field if it contains a separator, quote or line break.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Returns the CSV columns of benchmark series data.
+fn series_header(metric: PlotMetric) -> [&'static str; 5] {
+    [
+        "series",
+        "size",
+        metric.axis_label(),
+        "interval_low",
+        "interval_high",
+    ]
+}
+
+/// Lists every plotted point of every series, with its confidence interval
+/// if one was extracted.
+fn series_rows(
+    series_names: &[&str],
+    metric: PlotMetric,
+    results: &[BenchmarkResult],
+) -> Vec<Vec<String>> {
+    results
+        .iter()
+        .flat_map(|result| {
+            series_names
+                .iter()
+                .enumerate()
+                .filter_map(move |(index, name)| {
+                    let value = metric.value(result, index)?;
+                    let (low, high) = match metric.interval(result, index) {
+                        Some((low, high)) => (low.to_string(), high.to_string()),
+                        None => (String::new(), String::new()),
+                    };
+                    Some(vec![
+                        name.to_string(),
+                        result.size.to_string(),
+                        value.to_string(),
+                        low,
+                        high,
+                    ])
+                })
+        })
+        .collect()
+}
+
+/// Formats an x-axis tick as the size it stands for.
+fn format_size(x_scale: AxisScale, x: f64) -> String {
+    format!("{:.0}", x_scale.value(x))
+}
+
+/// Formats a y-axis tick, keeping decimals for values below 10.
+fn format_axis_value(value: f64) -> String {
+    if value < 10.0 {
+        format!("{value:.2}")
+    } else {
+        format!("{value:.0}")
+    }
+}
@@ -0,0 +1,196 @@
+//! Grid coordinates and compass directions.
+//!
+//! [`Point`] is a signed `(row, col)` coordinate so that walks can step off
+//! the top or left edge without underflow; [`Grid::get_point`] turns such
+//! positions back into bounds-checked lookups.
+//!
+//! [`Grid::get_point`]: crate::grid::Grid::get_point
+
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+/// Signed grid coordinate `(row, col)`; rows grow downwards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point(pub i64, pub i64);
+
+impl Point {
+    /// Returns the row component.
+    pub fn row(self) -> i64 {
+        self.0
+    }
+
+    /// Returns the column component.
+    pub fn col(self) -> i64 {
+        self.1
+    }
+
+    /// Returns the Manhattan distance to another point.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::point::Point;
+    /// assert_eq!(Point(1, 2).manhattan(Point(4, -2)), 7);
+    /// ```
+    pub fn manhattan(self, other: Point) -> u64 {
+        self.0.abs_diff(other.0) + self.1.abs_diff(other.1)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, direction: Direction) -> Point {
+        self + direction.delta()
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl AddAssign<Direction> for Point {
+    fn add_assign(&mut self, direction: Direction) {
+        *self = *self + direction;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point(-self.0, -self.1)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: i64) -> Point {
+        Point(self.0 * scale, self.1 * scale)
+    }
+}
+
+/// One of the eight compass directions on a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+/// All eight directions, clockwise from north.
+pub const ALL_DIRECTIONS: [Direction; 8] = [
+    Direction::North,
+    Direction::NorthEast,
+    Direction::East,
+    Direction::SouthEast,
+    Direction::South,
+    Direction::SouthWest,
+    Direction::West,
+    Direction::NorthWest,
+];
+
+/// The four orthogonal directions, clockwise from north.
+pub const CARDINAL_DIRECTIONS: [Direction; 4] = [
+    Direction::North,
+    Direction::East,
+    Direction::South,
+    Direction::West,
+];
+
+/// The four diagonal directions, clockwise from north-east.
+pub const DIAGONAL_DIRECTIONS: [Direction; 4] = [
+    Direction::NorthEast,
+    Direction::SouthEast,
+    Direction::SouthWest,
+    Direction::NorthWest,
+];
+
+impl Direction {
+    /// Returns the offset of one step in this direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::point::{Direction, Point};
+    /// assert_eq!(Direction::NorthEast.delta(), Point(-1, 1));
+    /// assert_eq!(Point(2, 2) + Direction::South * 3, Point(5, 2));
+    /// ```
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::North => Point(-1, 0),
+            Direction::NorthEast => Point(-1, 1),
+            Direction::East => Point(0, 1),
+            Direction::SouthEast => Point(1, 1),
+            Direction::South => Point(1, 0),
+            Direction::SouthWest => Point(1, -1),
+            Direction::West => Point(0, -1),
+            Direction::NorthWest => Point(-1, -1),
+        }
+    }
+
+    /// Rotates clockwise by `steps` eighths of a turn (45° each).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::point::Direction;
+    /// assert_eq!(Direction::North.rotate(2), Direction::East);
+    /// assert_eq!(Direction::North.rotate(-1), Direction::NorthWest);
+    /// ```
+    pub fn rotate(self, steps: i32) -> Direction {
+        let index = ALL_DIRECTIONS
+            .iter()
+            .position(|&direction| direction == self)
+            .unwrap_or(0);
+        // rem_euclid keeps the index in 0..8 for negative steps
+        let rotated = (index as i32 + steps).rem_euclid(8) as usize;
+        ALL_DIRECTIONS[rotated]
+    }
+
+    /// Turns 90° clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// Turns 90° counter-clockwise.
+    pub fn turn_left(self) -> Direction {
+        self.rotate(-2)
+    }
+
+    /// Returns the direction pointing the other way.
+    pub fn opposite(self) -> Direction {
+        self.rotate(4)
+    }
+}
+
+impl Mul<i64> for Direction {
+    type Output = Point;
+
+    fn mul(self, scale: i64) -> Point {
+        self.delta() * scale
+    }
+}
@@ -0,0 +1,195 @@
+//! Min-priority queue with decrease-key.
+//!
+//! [`std::collections::BinaryHeap`] cannot change the priority of a queued
+//! item, so Dijkstra implementations push duplicates and skip stale entries
+//! when popping. [`IndexedPriorityQueue`] tracks each key's heap position
+//! instead, keeping one entry per key and the queue no larger than the
+//! frontier.
+
+use rustc_hash::FxHashMap;
+use std::hash::Hash;
+
+/// Binary min-heap of keys with updatable priorities.
+///
+/// # Attributes
+/// * `heap` - `(key, priority)` pairs in heap order
+/// * `positions` - Index of every queued key in `heap`
+///
+/// # Examples
+///
+/// ```
+/// # use shared::priority_queue::IndexedPriorityQueue;
+/// let mut queue = IndexedPriorityQueue::new();
+/// queue.push('a', 5);
+/// queue.push('b', 3);
+/// assert!(queue.push_or_decrease('a', 1));
+/// assert!(!queue.push_or_decrease('b', 4)); // 4 is not lower than 3
+/// assert_eq!(queue.pop(), Some(('a', 1)));
+/// assert_eq!(queue.pop(), Some(('b', 3)));
+/// assert!(queue.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct IndexedPriorityQueue<K, P> {
+    heap: Vec<(K, P)>,
+    positions: FxHashMap<K, usize>,
+}
+
+impl<K, P> Default for IndexedPriorityQueue<K, P> {
+    fn default() -> Self {
+        IndexedPriorityQueue {
+            heap: Vec::new(),
+            positions: FxHashMap::default(),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash, P: Ord> IndexedPriorityQueue<K, P> {
+    /// Creates an empty queue.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of queued keys.
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns `true` if no keys are queued.
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Returns `true` if a key is queued.
+    pub fn contains(&self, key: &K) -> bool {
+        self.positions.contains_key(key)
+    }
+
+    /// Returns the priority of a queued key.
+    pub fn priority(&self, key: &K) -> Option<&P> {
+        let &index = self.positions.get(key)?;
+        self.heap.get(index).map(|(_, priority)| priority)
+    }
+
+    /// Returns the key with the lowest priority without removing it.
+    pub fn peek(&self) -> Option<(&K, &P)> {
+        self.heap.first().map(|(key, priority)| (key, priority))
+    }
+
+    /// Queues a key, or sets its priority if it is already queued.
+    ///
+    /// # Returns
+    /// The key's previous priority, if it was queued
+    pub fn push(&mut self, key: K, priority: P) -> Option<P> {
+        match self.positions.get(&key) {
+            Some(&index) => {
+                let previous = std::mem::replace(&mut self.heap[index].1, priority);
+                // Only one direction moves the entry; the other is a no-op
+                let index = self.sift_up(index);
+                self.sift_down(index);
+                Some(previous)
+            }
+            None => {
+                self.positions.insert(key.clone(), self.heap.len());
+                self.heap.push((key, priority));
+                self.sift_up(self.heap.len() - 1);
+                None
+            }
+        }
+    }
+
+    /// Queues a key, or lowers its priority if the new one is lower.
+    ///
+    /// This is the relaxation step of Dijkstra's algorithm.
+    ///
+    /// # Returns
+    /// `true` if the key was added or its priority lowered
+    pub fn push_or_decrease(&mut self, key: K, priority: P) -> bool {
+        if self
+            .priority(&key)
+            .is_some_and(|current| *current <= priority)
+        {
+            return false;
+        }
+        self.push(key, priority);
+        true
+    }
+
+    /// Removes and returns the key with the lowest priority.
+    pub fn pop(&mut self) -> Option<(K, P)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        let (key, priority) = self.heap.pop()?;
+        self.positions.remove(&key);
+        self.sift_down(0);
+        Some((key, priority))
+    }
+
+    /// Removes a key from the queue.
+    ///
+    /// # Returns
+    /// The key's priority, or `None` if it was not queued
+    pub fn remove(&mut self, key: &K) -> Option<P> {
+        let index = *self.positions.get(key)?;
+        let last = self.heap.len() - 1;
+        self.swap(index, last);
+        let (removed, priority) = self.heap.pop()?;
+        self.positions.remove(&removed);
+        if index < self.heap.len() {
+            let index = self.sift_up(index);
+            self.sift_down(index);
+        }
+        Some(priority)
+    }
+
+    /// Moves an entry towards the root while it beats its parent.
+    ///
+    /// # Returns
+    /// The entry's final index
+    fn sift_up(&mut self, mut index: usize) -> usize {
+        while index > 0 {
+            let parent = (index - 1) / 2;
+            if self.heap[index].1 >= self.heap[parent].1 {
+                break;
+            }
+            self.swap(index, parent);
+            index = parent;
+        }
+        index
+    }
+
+    /// Moves an entry towards the leaves while a child beats it.
+    fn sift_down(&mut self, mut index: usize) {
+        loop {
+            let smallest = [2 * index + 1, 2 * index + 2]
+                .into_iter()
+                .filter(|&child| child < self.heap.len())
+                .fold(index, |best, child| {
+                    if self.heap[child].1 < self.heap[best].1 {
+                        child
+                    } else {
+                        best
+                    }
+                });
+            if smallest == index {
+                break;
+            }
+            self.swap(index, smallest);
+            index = smallest;
+        }
+    }
+
+    /// Swaps two heap entries and updates their recorded positions.
+    fn swap(&mut self, a: usize, b: usize) {
+        self.heap.swap(a, b);
+        for index in [a, b] {
+            if let Some((key, _)) = self.heap.get(index) {
+                if let Some(position) = self.positions.get_mut(key) {
+                    *position = index;
+                }
+            }
+        }
+    }
+}
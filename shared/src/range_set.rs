@@ -0,0 +1,180 @@
+//! Sets of integers stored as sorted, disjoint intervals.
+//!
+//! Suited to coverage puzzles over huge 1-D spans (sensor ranges, seed
+//! maps) where enumerating every value is infeasible. Ranges are half-open
+//! `start..end`, like Rust's own [`Range`].
+
+use std::ops::Range;
+
+/// Set of `i64` values kept as merged, sorted, half-open intervals.
+///
+/// Touching intervals such as `0..3` and `3..5` are merged into `0..5`, so
+/// the representation is canonical and two sets are equal exactly when they
+/// contain the same values.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::range_set::RangeSet;
+/// let mut set: RangeSet = [0..5, 10..15].into_iter().collect();
+/// set.insert(4..11);
+/// assert_eq!(set.ranges().collect::<Vec<_>>(), vec![0..15]);
+/// set.remove(3..7);
+/// assert_eq!(set.len(), 11);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeSet {
+    ranges: Vec<Range<i64>>,
+}
+
+impl RangeSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds every value in `range`, merging with overlapping or adjacent
+    /// intervals. Empty ranges are ignored.
+    pub fn insert(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        // Intervals that end before the new one starts stay untouched
+        let first = self
+            .ranges
+            .partition_point(|existing| existing.end < range.start);
+        let last = self
+            .ranges
+            .partition_point(|existing| existing.start <= range.end);
+
+        let mut merged = range;
+        let overlapping = self.ranges.get(first..last).unwrap_or_default();
+        if let (Some(head), Some(tail)) = (overlapping.first(), overlapping.last()) {
+            merged.start = merged.start.min(head.start);
+            merged.end = merged.end.max(tail.end);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    /// Removes every value in `range`, splitting intervals if needed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::range_set::RangeSet;
+    /// let mut set: RangeSet = [0..10].into_iter().collect();
+    /// set.remove(3..5);
+    /// assert_eq!(set.ranges().collect::<Vec<_>>(), vec![0..3, 5..10]);
+    /// ```
+    pub fn remove(&mut self, range: Range<i64>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self
+            .ranges
+            .partition_point(|existing| existing.end <= range.start);
+        let last = self
+            .ranges
+            .partition_point(|existing| existing.start < range.end);
+        let Some(overlapping) = self.ranges.get(first..last) else {
+            return;
+        };
+
+        // Keep the parts of the first and last overlapping intervals that
+        // stick out past the removed range
+        let mut remainder = Vec::with_capacity(2);
+        if let Some(head) = overlapping.first().filter(|head| head.start < range.start) {
+            remainder.push(head.start..range.start);
+        }
+        if let Some(tail) = overlapping.last().filter(|tail| tail.end > range.end) {
+            remainder.push(range.end..tail.end);
+        }
+        self.ranges.splice(first..last, remainder);
+    }
+
+    /// Returns `true` if the set contains a value.
+    pub fn contains(&self, value: i64) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= value)
+    }
+
+    /// Returns the total number of values in the set.
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|range| range.start.abs_diff(range.end))
+            .sum()
+    }
+
+    /// Returns `true` if the set contains no values.
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// Iterates over the disjoint intervals in ascending order.
+    pub fn ranges(&self) -> impl Iterator<Item = Range<i64>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    /// Returns the values present in either set.
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut result = self.clone();
+        for range in other.ranges() {
+            result.insert(range);
+        }
+        result
+    }
+
+    /// Returns the values present in both sets.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::range_set::RangeSet;
+    /// let a: RangeSet = [0..10, 20..30].into_iter().collect();
+    /// let b: RangeSet = [5..25].into_iter().collect();
+    /// let both = a.intersection(&b);
+    /// assert_eq!(both.ranges().collect::<Vec<_>>(), vec![5..10, 20..25]);
+    /// ```
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = Vec::new();
+        let (mut left, mut right) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        // Both lists are sorted, so walk them together like a merge
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            let overlap = a.start.max(b.start)..a.end.min(b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            if a.end < b.end {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+        RangeSet { ranges }
+    }
+
+    /// Returns the values in this set that are not in `other`.
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut result = self.clone();
+        for range in other.ranges() {
+            result.remove(range);
+        }
+        result
+    }
+}
+
+impl FromIterator<Range<i64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        let mut set = RangeSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+        set
+    }
+}
@@ -0,0 +1,528 @@
+//! Shared solution runner utilities.
+//!
+//! This module defines the registry entry each day exposes to the workspace
+//! runner and provides helpers for executing solvers with timing and an
+//! optional timeout.
+
+use crate::cache::verify_cached_input;
+use crate::input::{read_input_or_example, InputSource};
+use crate::output::{report_change, report_mismatch};
+use crate::submit::{accepted_answer, read_last_answers, read_submissions, record_last_answers};
+use crate::validate::Validator;
+use anyhow::{anyhow, bail, Context, Result};
+use std::fmt;
+use std::io::Write;
+use std::panic;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Uniform solver signature used by the runner.
+///
+/// Takes the raw puzzle input and returns the answer rendered as a string so
+/// that days with different answer types can share one registry.
+pub type Solver = fn(&str) -> Result<String>;
+
+/// Diagnostic explanation of an input, rendered by `aoc run --verbose`.
+///
+/// Takes the raw puzzle input and returns a human-readable description of
+/// how the day's rules apply to it.
+pub type Explainer = fn(&str) -> Result<String>;
+
+/// Registry entry describing a single day's solution.
+///
+/// Groups semantically related parameters: the day's identity, its example
+/// inputs, and the solver for each implemented part.
+///
+/// # Attributes
+/// * `day` - The day number (1-25)
+/// * `title` - Puzzle title shown in the runner banner
+/// * `example_input` - Example input from the problem statement
+/// * `example_input_part2` - Separate Part 2 example, if the problem has one
+/// * `part1` - Solver for Part 1
+/// * `part2` - Solver for Part 2, or `None` if not yet implemented
+/// * `variants` - Alternative implementations that must agree with the
+///   canonical `part1`/`part2` solvers
+/// * `validate` - Input shape checks run before solving the real input
+/// * `explain` - Diagnostic explanation of an input, if the day has one
+pub struct Solution {
+    pub day: u8,
+    pub title: &'static str,
+    pub example_input: &'static str,
+    pub example_input_part2: Option<&'static str>,
+    pub part1: Solver,
+    pub part2: Option<Solver>,
+    pub variants: &'static [Variant],
+    pub validate: Option<Validator>,
+    pub explain: Option<Explainer>,
+}
+
+/// Alternative implementation of one part, such as a naive reference solver.
+///
+/// # Attributes
+/// * `name` - Short variant name (e.g. "naive")
+/// * `part` - The puzzle part the variant solves (1 or 2)
+/// * `solver` - The variant's solver
+pub struct Variant {
+    pub name: &'static str,
+    pub part: u8,
+    pub solver: Solver,
+}
+
+impl Solution {
+    /// Returns the solver for the requested part.
+    ///
+    /// # Parameters
+    /// * `part` - The puzzle part (1 or 2)
+    ///
+    /// # Returns
+    /// The part's solver, or `None` if the part is not implemented
+    pub fn solver(&self, part: u8) -> Option<Solver> {
+        match part {
+            1 => Some(self.part1),
+            2 => self.part2,
+            _ => None,
+        }
+    }
+
+    /// Returns every solver of a part, canonical first.
+    ///
+    /// # Parameters
+    /// * `part` - The puzzle part (1 or 2)
+    ///
+    /// # Returns
+    /// `(name, solver)` pairs; the canonical solver is named "canonical"
+    pub fn solvers(&self, part: u8) -> Vec<(&'static str, Solver)> {
+        self.solver(part)
+            .map(|solver| ("canonical", solver))
+            .into_iter()
+            .chain(
+                self.variants
+                    .iter()
+                    .filter(|variant| variant.part == part)
+                    .map(|variant| (variant.name, variant.solver)),
+            )
+            .collect()
+    }
+
+    /// Checks real input for problems before it is solved.
+    ///
+    /// Runs the day's validator, if any, and flags input that is identical
+    /// to an example (a common copy-paste mistake).
+    ///
+    /// # Parameters
+    /// * `input` - The real puzzle input
+    ///
+    /// # Returns
+    /// Every problem found; an empty vector means the input looks fine
+    pub fn check_input(&self, input: &str) -> Vec<String> {
+        let mut problems = self
+            .validate
+            .map_or_else(Vec::new, |validate| validate(input));
+        if [Some(self.example_input), self.example_input_part2]
+            .into_iter()
+            .flatten()
+            .any(|example| example.trim() == input.trim())
+        {
+            problems.push("Input is identical to the example input".to_string());
+        }
+        problems
+    }
+
+    /// Returns the example input for the requested part.
+    ///
+    /// Falls back to the shared example when the part has no dedicated one.
+    ///
+    /// # Parameters
+    /// * `part` - The puzzle part (1 or 2)
+    ///
+    /// # Returns
+    /// Example input text from the problem statement
+    pub fn example_input(&self, part: u8) -> &'static str {
+        match (part, self.example_input_part2) {
+            (2, Some(example)) => example,
+            _ => self.example_input,
+        }
+    }
+}
+
+/// Result of executing a solver through the runner.
+///
+/// Distinguishes a solver that finished (successfully or with an error) from
+/// one that exceeded its time limit.
+#[derive(Debug)]
+pub enum RunOutcome {
+    /// The solver returned an answer.
+    Solved { answer: String, elapsed: Duration },
+    /// The solver returned an error or panicked.
+    Failed {
+        error: anyhow::Error,
+        elapsed: Duration,
+    },
+    /// The solver did not finish within the time limit.
+    TimedOut { limit: Duration },
+}
+
+impl RunOutcome {
+    /// Returns the answer if the solver completed successfully.
+    ///
+    /// # Returns
+    /// The answer string, or `None` for failed and timed out runs
+    pub fn answer(&self) -> Option<&str> {
+        match self {
+            RunOutcome::Solved { answer, .. } => Some(answer),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RunOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RunOutcome::Solved { answer, elapsed } => write!(f, "{answer} ({elapsed:.2?})"),
+            RunOutcome::Failed { error, elapsed } => {
+                write!(f, "error after {elapsed:.2?}: {error:#}")
+            }
+            RunOutcome::TimedOut { limit } => write!(f, "timed out after {limit:?}"),
+        }
+    }
+}
+
+/// Executes a solver, optionally enforcing a time limit.
+///
+/// Without a timeout the solver runs on the calling thread. With a timeout
+/// the solver runs on a worker thread and the runner stops waiting once the
+/// limit is reached. Rust threads cannot be killed, so a timed out worker
+/// keeps running in the background until the process exits.
+///
+/// On both paths a solver panic is caught and reported as
+/// [`RunOutcome::Failed`] with its message, but only in builds that unwind.
+/// The workspace release profile sets `panic = "abort"`, so there a
+/// panicking solver still takes the whole process down.
+///
+/// # Parameters
+/// * `solver` - Solver to execute
+/// * `input` - Puzzle input passed to the solver
+/// * `timeout` - Maximum time to wait for an answer, or `None` to wait forever
+///
+/// # Returns
+/// Structured outcome with the answer or error and the elapsed time, or a
+/// timed out marker
+///
+/// # Examples
+///
+/// ```
+/// # use shared::runner::{run_solver, RunOutcome};
+/// # use std::time::Duration;
+/// let outcome = run_solver(|input| Ok(input.len().to_string()), "abc", None);
+/// assert_eq!(outcome.answer(), Some("3"));
+///
+/// // In unwinding builds a panicking solver fails instead of taking the
+/// // runner down
+/// let outcome = run_solver(|_| panic!("unreachable state"), "abc", None);
+/// assert!(matches!(outcome, RunOutcome::Failed { .. }));
+///
+/// // The same holds on the worker thread, keeping the panic message
+/// let timeout = Some(Duration::from_secs(5));
+/// let outcome = run_solver(|_| panic!("unreachable state"), "abc", timeout);
+/// let RunOutcome::Failed { error, elapsed } = outcome else { panic!() };
+/// assert!(error.to_string().contains("unreachable state"));
+/// assert!(elapsed < Duration::from_secs(5));
+/// ```
+pub fn run_solver(solver: Solver, input: &str, timeout: Option<Duration>) -> RunOutcome {
+    let Some(limit) = timeout else {
+        let start = Instant::now();
+        let result = solve_catching_panics(solver, input);
+        return outcome_from_result(result, start.elapsed());
+    };
+
+    let (sender, receiver) = mpsc::channel();
+    let input = input.to_owned();
+    let spawned = Instant::now();
+    thread::spawn(move || {
+        let start = Instant::now();
+        let result = solve_catching_panics(solver, &input);
+        // The receiver is gone if the runner already gave up waiting
+        let _ = sender.send((result, start.elapsed()));
+    });
+
+    match receiver.recv_timeout(limit) {
+        Ok((result, elapsed)) => outcome_from_result(result, elapsed),
+        Err(RecvTimeoutError::Timeout) => RunOutcome::TimedOut { limit },
+        // Only reachable if the worker died without reporting back
+        Err(RecvTimeoutError::Disconnected) => RunOutcome::Failed {
+            error: anyhow!("Solver thread exited without an answer"),
+            elapsed: spawned.elapsed(),
+        },
+    }
+}
+
+/// Runs a solver, turning a panic into an error carrying its message.
+///
+/// # Parameters
+/// * `solver` - Solver to execute
+/// * `input` - Puzzle input passed to the solver
+///
+/// # Returns
+/// The solver's result, or an error describing the panic
+fn solve_catching_panics(solver: Solver, input: &str) -> Result<String> {
+    panic::catch_unwind(|| solver(input)).unwrap_or_else(|payload| {
+        // Panics carry a &str or a String, depending on whether the message
+        // was formatted
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("unknown cause");
+        Err(anyhow!("Solver panicked: {message}"))
+    })
+}
+
+/// Runs the selected parts of a day on its example and real input.
+///
+/// Prints the day banner, the example results, and the real input results
+/// when `dayXX/input.txt` exists. The real input is checked for cache
+/// corruption and shape problems first and not solved if any are found.
+/// Real input answers that differ from an accepted submission are shown as a
+/// diff; parts without one are diffed against the previous run's answer
+/// instead, kept in `dayXX/last_answers.txt`. Every outcome is printed,
+/// including failures, before the run is reported as failed.
+///
+/// # Parameters
+/// * `solution` - Registry entry of the day to run
+/// * `parts` - Parts to run (1 and/or 2)
+/// * `timeout` - Per-solver time limit, or `None` to wait forever
+///
+/// # Errors
+///
+/// Returns an error if `input.txt` exists but cannot be read, the real input
+/// failed validation, any solver failed or timed out, an answer differs from
+/// the accepted submission, or the submission history or previous answers
+/// cannot be read or written. A change since the previous run is only a
+/// warning.
+pub fn run_day(solution: &Solution, parts: &[u8], timeout: Option<Duration>) -> Result<()> {
+    let mut failures = 0;
+    let mut mismatches = 0;
+    let mut report = |label: String, outcome: RunOutcome| {
+        if outcome.answer().is_none() {
+            failures += 1;
+        }
+        println!("{label}: {outcome}");
+    };
+
+    println!("=== Day {}: {} ===", solution.day, solution.title);
+    println!();
+
+    println!("=== Example Input Results ===");
+    for &part in parts {
+        match solution.solver(part) {
+            Some(solver) => report(
+                format!("Part {part} example result"),
+                run_solver(solver, solution.example_input(part), timeout),
+            ),
+            None => println!("Part {part}: not implemented"),
+        }
+    }
+
+    match read_input_or_example(solution.day, solution.example_input)? {
+        (input, InputSource::Real) => {
+            let mut problems = verify_cached_input(solution.day, &input);
+            problems.extend(solution.check_input(&input));
+            if !problems.is_empty() {
+                println!("\n=== Input Problems ===");
+                for problem in &problems {
+                    println!("⚠️  {problem}");
+                }
+                bail!(
+                    "day{:02}/input.txt failed validation with {} problem(s)",
+                    solution.day,
+                    problems.len()
+                );
+            }
+
+            let submissions = read_submissions(solution.day)?;
+            let mut last_answers = read_last_answers(solution.day)?;
+            println!("\n=== Real Input Results ===");
+            for &part in parts {
+                if let Some(solver) = solution.solver(part) {
+                    let label = format!("Part {part} result");
+                    let outcome = run_solver(solver, &input, timeout);
+                    let answer = outcome.answer().map(str::to_owned);
+                    report(label.clone(), outcome);
+                    let Some(got) = answer else {
+                        continue;
+                    };
+                    match accepted_answer(&submissions, part) {
+                        Some(expected) => {
+                            if !report_mismatch(&label, expected, &got) {
+                                mismatches += 1;
+                            }
+                        }
+                        None => {
+                            if let Some(previous) = last_answers.get(&part) {
+                                report_change(&label, previous, &got);
+                            }
+                        }
+                    }
+                    last_answers.insert(part, got);
+                }
+            }
+            record_last_answers(solution.day, &last_answers)?;
+        }
+        (_, InputSource::Example) => println!(
+            "\nNo input.txt found - create day{:02}/input.txt with your puzzle input",
+            solution.day
+        ),
+    }
+
+    if failures > 0 {
+        bail!("{failures} solver run(s) did not produce an answer");
+    }
+    if mismatches > 0 {
+        bail!("{mismatches} answer(s) differ from the accepted submission");
+    }
+    Ok(())
+}
+
+/// Entry point used by the per-day binaries generated with [`aoc_main!`].
+///
+/// Runs both parts without a timeout.
+///
+/// # Parameters
+/// * `solution` - Registry entry of the day to run
+///
+/// # Errors
+///
+/// Returns an error if any solver failed.
+///
+/// [`aoc_main!`]: crate::aoc_main
+pub fn run_main(solution: &Solution) -> Result<()> {
+    run_day(solution, &[1, 2], None)
+}
+
+/// Generates a day binary's `main` function from its registry entry.
+///
+/// The day's library crate must export a `SOLUTION` registry entry.
+///
+/// # Examples
+///
+/// ```ignore
+/// // dayXX/src/main.rs
+/// shared::aoc_main!(day03);
+/// ```
+#[macro_export]
+macro_rules! aoc_main {
+    ($day:ident) => {
+        fn main() -> ::anyhow::Result<()> {
+            $crate::runner::run_main(&$day::SOLUTION)
+        }
+    };
+}
+
+/// Pipes puzzle input through an external transformation script.
+///
+/// The script receives the input on stdin and must print the transformed
+/// input on stdout. Extra environment variables (such as the answers of an
+/// earlier run) let the script base its transformation on solver output.
+///
+/// # Parameters
+/// * `script` - Executable to run
+/// * `args` - Arguments passed to the script
+/// * `input` - Puzzle input written to the script's stdin
+/// * `env` - Additional environment variables for the script
+///
+/// # Returns
+/// The script's stdout, used as the new puzzle input
+///
+/// # Errors
+///
+/// Returns an error if the script cannot be started, exits unsuccessfully,
+/// or prints output that is not valid UTF-8.
+pub fn transform_input(
+    script: &Path,
+    args: &[String],
+    input: &str,
+    env: &[(String, String)],
+) -> Result<String> {
+    let mut child = Command::new(script)
+        .args(args)
+        .envs(env.iter().map(|(key, value)| (key, value)))
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .with_context(|| format!("Failed to start {}", script.display()))?;
+
+    // Feed stdin from a separate thread so a script that streams its output
+    // cannot deadlock against a full pipe
+    let mut stdin = child
+        .stdin
+        .take()
+        .context("Script stdin was not captured")?;
+    let input = input.to_owned();
+    let writer = thread::spawn(move || stdin.write_all(input.as_bytes()));
+
+    let output = child
+        .wait_with_output()
+        .with_context(|| format!("Failed to wait for {}", script.display()))?;
+    // A script may legitimately stop reading early, so a broken pipe is fine
+    let _ = writer.join();
+
+    if !output.status.success() {
+        bail!("{} exited with {}", script.display(), output.status);
+    }
+    String::from_utf8(output.stdout)
+        .with_context(|| format!("{} printed invalid UTF-8", script.display()))
+}
+
+/// Converts a solver result and its elapsed time into a run outcome.
+fn outcome_from_result(result: Result<String>, elapsed: Duration) -> RunOutcome {
+    match result {
+        Ok(answer) => RunOutcome::Solved { answer, elapsed },
+        Err(error) => RunOutcome::Failed { error, elapsed },
+    }
+}
+
+/// Parses a human-readable duration such as `10s`, `500ms` or `2m`.
+///
+/// A bare number is interpreted as seconds. Fractional values are accepted
+/// (e.g. `1.5s`).
+///
+/// # Parameters
+/// * `text` - Duration text with an optional `ms`, `s` or `m` suffix
+///
+/// # Returns
+/// The parsed duration
+///
+/// # Errors
+///
+/// Returns an error if the number is invalid or negative, or if the unit is
+/// not one of `ms`, `s` or `m`.
+///
+/// # Examples
+///
+/// ```
+/// # use shared::runner::parse_duration;
+/// # use std::time::Duration;
+/// assert_eq!(parse_duration("10s").unwrap(), Duration::from_secs(10));
+/// ```
+pub fn parse_duration(text: &str) -> Result<Duration> {
+    let text = text.trim();
+    let split = text
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(text.len());
+    let (number, unit) = text.split_at(split);
+
+    let value: f64 = number
+        .parse()
+        .with_context(|| format!("Invalid duration '{text}': expected a number"))?;
+    let seconds = match unit.trim() {
+        "ms" => value / 1000.0,
+        "" | "s" => value,
+        "m" => value * 60.0,
+        other => bail!("Invalid duration unit '{other}': expected ms, s or m"),
+    };
+
+    Duration::try_from_secs_f64(seconds).with_context(|| format!("Invalid duration '{text}'"))
+}
@@ -0,0 +1,284 @@
+//! Generic graph searches over implicit graphs.
+//!
+//! Nodes are never stored up front: each search takes a `successors` closure
+//! that produces the neighbours of a node on demand, so the same functions
+//! work for grid positions, `(position, direction)` states or anything else
+//! that is `Clone + Eq + Hash`. Weighted searches take any cost type that
+//! can be added and ordered, with `Default` as zero.
+//!
+//! # Examples
+//!
+//! ```
+//! # use shared::grid::parse_char_grid;
+//! # use shared::point::{Point, CARDINAL_DIRECTIONS};
+//! # use shared::search::bfs;
+//! let grid = parse_char_grid("..#\n.##\n...").unwrap();
+//! let open = |point: &Point| grid.get_point(*point) == Some(&'.');
+//! let result = bfs(Point(0, 0), |&point| {
+//!     CARDINAL_DIRECTIONS.map(|direction| point + direction).into_iter().filter(open)
+//! });
+//! assert_eq!(result.distance(&Point(2, 2)), Some(4));
+//! assert_eq!(result.path_to(&Point(0, 2)), None); // wall
+//! ```
+
+use crate::priority_queue::IndexedPriorityQueue;
+use rustc_hash::FxHashMap;
+use std::collections::VecDeque;
+use std::hash::Hash;
+use std::ops::Add;
+
+/// Distances and shortest-path tree produced by a full search.
+///
+/// # Attributes
+/// * `start` - The node the search started from
+/// * `distances` - Cost to reach every discovered node
+/// * `parents` - Predecessor of every discovered node except `start`
+#[derive(Debug, Clone)]
+pub struct SearchResult<N, C> {
+    start: N,
+    distances: FxHashMap<N, C>,
+    parents: FxHashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash, C: Copy> SearchResult<N, C> {
+    /// Returns the cost of reaching a node, or `None` if it was not reached.
+    pub fn distance(&self, node: &N) -> Option<C> {
+        self.distances.get(node).copied()
+    }
+
+    /// Returns every reached node with its cost.
+    pub fn distances(&self) -> &FxHashMap<N, C> {
+        &self.distances
+    }
+
+    /// Returns `true` if the search reached a node.
+    pub fn reached(&self, node: &N) -> bool {
+        self.distances.contains_key(node)
+    }
+
+    /// Reconstructs the path from the start to a node.
+    ///
+    /// # Returns
+    /// The nodes from start to `goal` inclusive, or `None` if `goal` was not
+    /// reached
+    pub fn path_to(&self, goal: &N) -> Option<Vec<N>> {
+        if !self.reached(goal) {
+            return None;
+        }
+        let mut path = vec![goal.clone()];
+        let mut current = goal;
+        while *current != self.start {
+            current = self.parents.get(current)?;
+            path.push(current.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+impl<N: Eq + Hash, C> SearchResult<N, C> {
+    /// Creates a result in which only the start node has been reached.
+    fn new(start: N, zero: C) -> Self
+    where
+        N: Clone,
+    {
+        let mut distances = FxHashMap::default();
+        distances.insert(start.clone(), zero);
+        SearchResult {
+            start,
+            distances,
+            parents: FxHashMap::default(),
+        }
+    }
+
+    /// Records the best known cost and predecessor of a node.
+    fn record(&mut self, node: N, parent: N, cost: C)
+    where
+        N: Clone,
+    {
+        self.parents.insert(node.clone(), parent);
+        self.distances.insert(node, cost);
+    }
+}
+
+/// Breadth-first search counting edges as unit cost.
+///
+/// # Parameters
+/// * `start` - Node to search from
+/// * `successors` - Returns the neighbours of a node
+///
+/// # Returns
+/// Shortest step counts and paths to every reachable node
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> SearchResult<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new(start.clone(), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((node, steps)) = queue.pop_front() {
+        for next in successors(&node) {
+            if !result.reached(&next) {
+                result.record(next.clone(), node.clone(), steps + 1);
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    result
+}
+
+/// Depth-first search.
+///
+/// Paths follow the DFS tree and are generally not the shortest; use it for
+/// reachability or when visiting order matters.
+///
+/// # Parameters
+/// * `start` - Node to search from
+/// * `successors` - Returns the neighbours of a node
+///
+/// # Returns
+/// Every reachable node with its depth in the DFS tree
+///
+/// # Examples
+///
+/// ```
+/// # use shared::search::dfs;
+/// let result = dfs(1u32, |&n| if n < 5 { vec![n + 1] } else { vec![] });
+/// assert_eq!(result.path_to(&5), Some(vec![1, 2, 3, 4, 5]));
+/// ```
+pub fn dfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> SearchResult<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new(start.clone(), 0);
+    let mut stack = vec![(start, 0)];
+    while let Some((node, depth)) = stack.pop() {
+        for next in successors(&node) {
+            if !result.reached(&next) {
+                result.record(next.clone(), node.clone(), depth + 1);
+                stack.push((next, depth + 1));
+            }
+        }
+    }
+    result
+}
+
+/// Dijkstra's algorithm for non-negative edge costs.
+///
+/// # Parameters
+/// * `start` - Node to search from
+/// * `successors` - Returns the neighbours of a node with the cost of each
+///   edge
+///
+/// # Returns
+/// Cheapest costs and paths to every reachable node
+///
+/// # Examples
+///
+/// ```
+/// # use shared::search::dijkstra;
+/// let edges = |&node: &char| match node {
+///     'a' => vec![('b', 7), ('c', 2)],
+///     'c' => vec![('b', 3)],
+///     _ => vec![],
+/// };
+/// let result = dijkstra('a', edges);
+/// assert_eq!(result.distance(&'b'), Some(5));
+/// assert_eq!(result.path_to(&'b'), Some(vec!['a', 'c', 'b']));
+/// ```
+pub fn dijkstra<N, C, I>(start: N, successors: impl FnMut(&N) -> I) -> SearchResult<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    best_first(start, successors, |_| C::default(), |_| false).0
+}
+
+/// A* search towards the first node satisfying `is_goal`.
+///
+/// The heuristic must never overestimate the remaining cost, otherwise the
+/// returned path may not be the cheapest. A zero heuristic makes this
+/// Dijkstra with early exit.
+///
+/// # Parameters
+/// * `start` - Node to search from
+/// * `successors` - Returns the neighbours of a node with edge costs
+/// * `heuristic` - Estimated remaining cost from a node to the goal
+/// * `is_goal` - Returns `true` for goal nodes
+///
+/// # Returns
+/// The cheapest path from start to a goal and its cost, or `None` if no goal
+/// is reachable
+///
+/// # Examples
+///
+/// ```
+/// # use shared::point::{Point, CARDINAL_DIRECTIONS};
+/// # use shared::search::astar;
+/// let goal = Point(3, 4);
+/// let (path, cost) = astar(
+///     Point(0, 0),
+///     |&point| CARDINAL_DIRECTIONS.map(|direction| (point + direction, 1u64)),
+///     |&point| point.manhattan(goal),
+///     |&point| point == goal,
+/// )
+/// .unwrap();
+/// assert_eq!(cost, 7);
+/// assert_eq!(path.len(), 8);
+/// ```
+pub fn astar<N, C, I>(
+    start: N,
+    successors: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let (result, goal) = best_first(start, successors, heuristic, is_goal);
+    let goal = goal?;
+    Some((result.path_to(&goal)?, result.distance(&goal)?))
+}
+
+/// Shared best-first loop behind [`dijkstra`] and [`astar`].
+///
+/// # Returns
+/// The search result and the goal node it stopped at, if any
+fn best_first<N, C, I>(
+    start: N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> (SearchResult<N, C>, Option<N>)
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut result = SearchResult::new(start.clone(), C::default());
+    let mut queue = IndexedPriorityQueue::new();
+    queue.push(start.clone(), heuristic(&start));
+
+    // Each node is queued at most once, so popped nodes are never stale
+    while let Some((node, _)) = queue.pop() {
+        let Some(cost) = result.distance(&node) else {
+            continue;
+        };
+        if is_goal(&node) {
+            return (result, Some(node));
+        }
+        for (next, edge_cost) in successors(&node) {
+            let next_cost = cost + edge_cost;
+            if result.distance(&next).is_none_or(|best| next_cost < best) {
+                result.record(next.clone(), node.clone(), next_cost);
+                let priority = next_cost + heuristic(&next);
+                queue.push(next, priority);
+            }
+        }
+    }
+    (result, None)
+}
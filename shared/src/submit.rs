@@ -0,0 +1,340 @@
+//! Answer submission safeguards for Advent of Code puzzles.
+//!
+//! This module provides cheap plausibility checks that run before an answer
+//! is submitted, a per-day submission history, the answers of the previous
+//! run, and parsing of the verdict returned by the Advent of Code website.
+//! Wrong submissions trigger a cooldown on the site, so obviously bad answers
+//! should never leave the machine without an explicit override.
+
+use anyhow::{bail, Context, Result};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+
+/// Reason an answer looks wrong before it has been submitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlausibilityIssue {
+    /// The answer is an empty string.
+    Empty,
+    /// The answer is numerically zero.
+    Zero,
+    /// The answer is negative or sits exactly on an integer type limit,
+    /// which usually indicates overflow or saturation.
+    OutOfBounds,
+    /// The answer equals the answer computed for the example input.
+    MatchesExample,
+    /// The same answer was already submitted for this part.
+    AlreadySubmitted,
+}
+
+impl fmt::Display for PlausibilityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            PlausibilityIssue::Empty => "answer is empty",
+            PlausibilityIssue::Zero => "answer is zero",
+            PlausibilityIssue::OutOfBounds => {
+                "answer is negative or at an integer type limit (overflow?)"
+            }
+            PlausibilityIssue::MatchesExample => "answer equals the example answer",
+            PlausibilityIssue::AlreadySubmitted => "answer was already submitted",
+        };
+        f.write_str(message)
+    }
+}
+
+/// Outcome reported by the Advent of Code website for a submission.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    RateLimited,
+    AlreadySolved,
+    Unknown,
+}
+
+impl Verdict {
+    /// Classifies the HTML response of an answer submission.
+    ///
+    /// # Parameters
+    /// * `body` - Response body returned by the answer endpoint
+    ///
+    /// # Returns
+    /// The verdict matching the site's message, or `Unknown` if no known
+    /// message is present
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::submit::Verdict;
+    /// let body = "<article><p>That's the right answer!</p></article>";
+    /// assert_eq!(Verdict::from_response(body), Verdict::Correct);
+    /// ```
+    pub fn from_response(body: &str) -> Verdict {
+        if body.contains("That's the right answer") {
+            Verdict::Correct
+        } else if body.contains("That's not the right answer") {
+            Verdict::Incorrect
+        } else if body.contains("You gave an answer too recently") {
+            Verdict::RateLimited
+        } else if body.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    /// Returns the verdict's name as stored in the submission history.
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Incorrect => "incorrect",
+            Verdict::RateLimited => "rate-limited",
+            Verdict::AlreadySolved => "already-solved",
+            Verdict::Unknown => "unknown",
+        }
+    }
+
+    /// Parses a verdict name from the submission history.
+    fn from_name(name: &str) -> Verdict {
+        match name {
+            "correct" => Verdict::Correct,
+            "incorrect" => Verdict::Incorrect,
+            "rate-limited" => Verdict::RateLimited,
+            "already-solved" => Verdict::AlreadySolved,
+            _ => Verdict::Unknown,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A previously submitted answer.
+///
+/// # Attributes
+/// * `part` - The puzzle part (1 or 2)
+/// * `answer` - The submitted answer
+/// * `verdict` - The site's response to the submission
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Returns the answer the website accepted for a part, if any.
+///
+/// # Parameters
+/// * `submissions` - Submission history of a day
+/// * `part` - The puzzle part (1 or 2)
+///
+/// # Returns
+/// The most recent answer recorded as correct for the part
+pub fn accepted_answer(submissions: &[Submission], part: u8) -> Option<&str> {
+    submissions
+        .iter()
+        .rev()
+        .find(|submission| submission.part == part && submission.verdict == Verdict::Correct)
+        .map(|submission| submission.answer.as_str())
+}
+
+/// Runs cheap plausibility checks on an answer before submission.
+///
+/// Checks that the answer is non-empty and nonzero, that numeric answers are
+/// neither negative nor exactly at an `i32`/`u32`/`i64`/`u64` limit, that it
+/// differs from the example answer, and that it was not submitted before.
+///
+/// # Parameters
+/// * `answer` - The answer about to be submitted
+/// * `example_answer` - The solver's answer for the example input, if known
+/// * `previous` - Earlier submissions for the same part
+///
+/// # Returns
+/// Every failed check; an empty vector means the answer looks plausible
+///
+/// # Examples
+///
+/// ```
+/// # use shared::submit::{check_plausibility, PlausibilityIssue};
+/// let issues = check_plausibility("11", Some("11"), &[]);
+/// assert_eq!(issues, vec![PlausibilityIssue::MatchesExample]);
+/// ```
+pub fn check_plausibility(
+    answer: &str,
+    example_answer: Option<&str>,
+    previous: &[Submission],
+) -> Vec<PlausibilityIssue> {
+    let answer = answer.trim();
+    let mut issues = Vec::new();
+
+    if answer.is_empty() {
+        issues.push(PlausibilityIssue::Empty);
+    }
+
+    if let Ok(value) = answer.parse::<i128>() {
+        const TYPE_LIMITS: [i128; 4] = [
+            i32::MAX as i128,
+            u32::MAX as i128,
+            i64::MAX as i128,
+            u64::MAX as i128,
+        ];
+        if value == 0 {
+            issues.push(PlausibilityIssue::Zero);
+        } else if value < 0 || TYPE_LIMITS.contains(&value) {
+            issues.push(PlausibilityIssue::OutOfBounds);
+        }
+    }
+
+    if example_answer.is_some_and(|example| example.trim() == answer) {
+        issues.push(PlausibilityIssue::MatchesExample);
+    }
+
+    if previous
+        .iter()
+        .any(|submission| submission.answer == answer)
+    {
+        issues.push(PlausibilityIssue::AlreadySubmitted);
+    }
+
+    issues
+}
+
+/// Reads the submission history for a day.
+///
+/// The history lives in `dayXX/submissions.txt` with one tab-separated
+/// `part`, `answer`, `verdict` record per line. A missing file means nothing
+/// was submitted yet.
+///
+/// # Parameters
+/// * `day` - The day number (1-25)
+///
+/// # Returns
+/// All recorded submissions for the day, oldest first
+///
+/// # Errors
+///
+/// Returns an error if the history file exists but cannot be read, or if a
+/// record is malformed.
+pub fn read_submissions(day: u8) -> Result<Vec<Submission>> {
+    let path = submissions_path(day);
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(error) => return Err(error).with_context(|| format!("Failed to read {path}")),
+    };
+
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let mut fields = line.split('\t');
+            let (Some(part), Some(answer), Some(verdict)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                bail!("Malformed submission record in {path}: '{line}'");
+            };
+            Ok(Submission {
+                part: part
+                    .parse()
+                    .with_context(|| format!("Invalid part in {path}: '{line}'"))?,
+                answer: answer.to_string(),
+                verdict: Verdict::from_name(verdict),
+            })
+        })
+        .collect()
+}
+
+/// Appends a submission to the day's history file.
+///
+/// # Parameters
+/// * `day` - The day number (1-25)
+/// * `submission` - The submission to record
+///
+/// # Errors
+///
+/// Returns an error if the history file cannot be opened or written.
+pub fn record_submission(day: u8, submission: &Submission) -> Result<()> {
+    let path = submissions_path(day);
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .with_context(|| format!("Failed to open {path}"))?;
+    writeln!(
+        file,
+        "{}\t{}\t{}",
+        submission.part, submission.answer, submission.verdict
+    )?;
+    Ok(())
+}
+
+/// Reads the real-input answers of the previous run of a day.
+///
+/// The answers live in `dayXX/last_answers.txt` with one tab-separated
+/// `part`, `answer` record per line. A missing file means the day was not
+/// run yet.
+///
+/// # Parameters
+/// * `day` - The day number (1-25)
+///
+/// # Returns
+/// The previous answer of each part that produced one, keyed by part
+///
+/// # Errors
+///
+/// Returns an error if the file exists but cannot be read, or if a record is
+/// malformed.
+pub fn read_last_answers(day: u8) -> Result<BTreeMap<u8, String>> {
+    let path = last_answers_path(day);
+    let contents = match fs::read_to_string(&path) {
+        Ok(contents) => contents,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(BTreeMap::new()),
+        Err(error) => return Err(error).with_context(|| format!("Failed to read {path}")),
+    };
+
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let Some((part, answer)) = line.split_once('\t') else {
+                bail!("Malformed answer record in {path}: '{line}'");
+            };
+            let part = part
+                .parse()
+                .with_context(|| format!("Invalid part in {path}: '{line}'"))?;
+            Ok((part, answer.to_string()))
+        })
+        .collect()
+}
+
+/// Replaces the stored answers of the previous run of a day.
+///
+/// # Parameters
+/// * `day` - The day number (1-25)
+/// * `answers` - The answer of each part, keyed by part
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be written.
+pub fn record_last_answers(day: u8, answers: &BTreeMap<u8, String>) -> Result<()> {
+    let path = last_answers_path(day);
+    let contents: String = answers
+        .iter()
+        .map(|(part, answer)| format!("{part}\t{answer}\n"))
+        .collect();
+    fs::write(&path, contents).with_context(|| format!("Failed to write {path}"))
+}
+
+/// Returns the path of the previous run's answers for a day.
+fn last_answers_path(day: u8) -> String {
+    format!("day{day:02}/last_answers.txt")
+}
+
+/// Returns the path of the submission history file for a day.
+fn submissions_path(day: u8) -> String {
+    format!("day{day:02}/submissions.txt")
+}
@@ -0,0 +1,156 @@
+//! Disjoint-set forest for grouping elements into connected components.
+//!
+//! Elements are the indices `0..len`; map grid cells or other keys to
+//! indices first (e.g. `row * width + col`).
+
+/// Union-find with path compression and union by rank.
+///
+/// # Attributes
+/// * `parent` - Parent of each element; roots are their own parent
+/// * `rank` - Upper bound on the height of each root's tree
+/// * `size` - Number of elements in each root's set
+/// * `components` - Number of disjoint sets
+#[derive(Debug, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// Creates `len` singleton sets.
+    pub fn new(len: usize) -> Self {
+        UnionFind {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    /// Returns the number of elements.
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    /// Returns `true` if there are no elements.
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Returns the number of disjoint sets.
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Finds the representative of an element's set, compressing the path.
+    ///
+    /// # Returns
+    /// The root, or `None` if the element is out of range
+    pub fn find(&mut self, element: usize) -> Option<usize> {
+        let mut root = element;
+        while *self.parent.get(root)? != root {
+            root = self.parent[root];
+        }
+
+        // Point every node on the path directly at the root
+        let mut current = element;
+        while current != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+        Some(root)
+    }
+
+    /// Merges the sets containing two elements.
+    ///
+    /// # Returns
+    /// `true` if the elements were in different sets, `false` if they were
+    /// already connected or either is out of range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::union_find::UnionFind;
+    /// let mut sets = UnionFind::new(4);
+    /// assert!(sets.union(0, 1));
+    /// assert!(!sets.union(1, 0));
+    /// assert_eq!(sets.component_count(), 3);
+    /// ```
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (Some(a), Some(b)) = (self.find(a), self.find(b)) else {
+            return false;
+        };
+        if a == b {
+            return false;
+        }
+
+        let (root, child) = if self.rank[a] >= self.rank[b] {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        self.parent[child] = root;
+        self.size[root] += self.size[child];
+        if self.rank[root] == self.rank[child] {
+            self.rank[root] += 1;
+        }
+        self.components -= 1;
+        true
+    }
+
+    /// Returns `true` if two elements are in the same set.
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        matches!((self.find(a), self.find(b)), (Some(a), Some(b)) if a == b)
+    }
+
+    /// Returns the size of the set containing an element.
+    ///
+    /// # Returns
+    /// The set size, or `None` if the element is out of range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::union_find::UnionFind;
+    /// let mut sets = UnionFind::new(5);
+    /// sets.union(0, 1);
+    /// sets.union(1, 2);
+    /// assert_eq!(sets.size_of(2), Some(3));
+    /// assert_eq!(sets.size_of(4), Some(1));
+    /// ```
+    pub fn size_of(&mut self, element: usize) -> Option<usize> {
+        let root = self.find(element)?;
+        Some(self.size[root])
+    }
+
+    /// Groups every element by set.
+    ///
+    /// # Returns
+    /// One vector per set, ordered by smallest element, each sorted ascending
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use shared::union_find::UnionFind;
+    /// let mut sets = UnionFind::new(5);
+    /// sets.union(3, 0);
+    /// sets.union(4, 2);
+    /// assert_eq!(sets.components(), vec![vec![0, 3], vec![1], vec![2, 4]]);
+    /// ```
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut slot_of_root = vec![usize::MAX; self.len()];
+        let mut groups: Vec<Vec<usize>> = Vec::with_capacity(self.components);
+        for element in 0..self.len() {
+            let Some(root) = self.find(element) else {
+                continue;
+            };
+            if slot_of_root[root] == usize::MAX {
+                slot_of_root[root] = groups.len();
+                groups.push(Vec::new());
+            }
+            groups[slot_of_root[root]].push(element);
+        }
+        groups
+    }
+}
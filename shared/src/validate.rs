@@ -0,0 +1,146 @@
+//! Input shape checks run before solving.
+//!
+//! Parsers stop at the first problem. Validators instead report every
+//! problem they find, so a badly pasted `input.txt` is diagnosed in one run.
+//! Each day can register a [`Validator`] built from these checks.
+
+use crate::grid::grid_lines;
+use crate::input::{indentation, locate_error, split_sections};
+
+/// Per-day input validator.
+///
+/// Returns one human-readable message per problem; an empty vector means
+/// the input looks well-formed.
+pub type Validator = fn(&str) -> Vec<String>;
+
+/// Runs a per-line parser over every non-blank line and collects all errors.
+///
+/// Errors are located like those of
+/// [`parse_lines_with`](crate::input::parse_lines_with).
+///
+/// # Parameters
+/// * `input` - Multi-line string with one record per line
+/// * `parse` - Parser applied to each trimmed, non-blank line
+///
+/// # Returns
+/// One message per line that failed to parse
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::{parse_pair, Delimiter};
+/// # use shared::validate::check_lines;
+/// let problems = check_lines("1 2\nx 3\n4", |line| {
+///     parse_pair::<i32>(line, Delimiter::Whitespace, "a number")
+/// });
+/// assert_eq!(problems.len(), 2);
+/// ```
+pub fn check_lines<T>(input: &str, parse: impl Fn(&str) -> anyhow::Result<T>) -> Vec<String> {
+    check_lines_from(input, 1, parse)
+}
+
+/// Like [`check_lines`], but numbers the lines of `input` from
+/// `first_line`, for checking one section of a larger input.
+///
+/// # Parameters
+/// * `input` - Multi-line string with one record per line
+/// * `first_line` - Line number reported for the first line of `input`
+/// * `parse` - Parser applied to each trimmed, non-blank line
+///
+/// # Returns
+/// One message per line that failed to parse
+///
+/// # Examples
+///
+/// ```
+/// # use shared::input::parse_token;
+/// # use shared::validate::check_lines_from;
+/// let problems = check_lines_from("1\nx", 5, |line| {
+///     parse_token::<i32>(line, line, "a number")
+/// });
+/// assert_eq!(problems.len(), 1);
+/// assert!(problems[0].starts_with("Line 6"));
+/// ```
+pub fn check_lines_from<T>(
+    input: &str,
+    first_line: usize,
+    parse: impl Fn(&str) -> anyhow::Result<T>,
+) -> Vec<String> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .filter_map(|(index, line)| {
+            parse(line.trim()).err().map(|e| {
+                format!(
+                    "{:#}",
+                    locate_error(e, first_line + index, indentation(line))
+                )
+            })
+        })
+        .collect()
+}
+
+/// Checks that the input has the expected number of blank-line separated
+/// sections.
+///
+/// # Parameters
+/// * `input` - Raw puzzle input
+/// * `expected` - Required number of sections
+///
+/// # Returns
+/// A message if the section count differs, otherwise nothing
+pub fn check_section_count(input: &str, expected: usize) -> Vec<String> {
+    let found = split_sections(input).len();
+    if found == expected {
+        Vec::new()
+    } else {
+        vec![format!(
+            "Expected {expected} blank-line separated sections, found {found}"
+        )]
+    }
+}
+
+/// Checks that every non-blank line has the same length as the first.
+///
+/// Lines are trimmed like [`parse_char_grid`](crate::grid::parse_char_grid)
+/// trims them, so an input passes exactly when the grid parser accepts it.
+///
+/// # Parameters
+/// * `input` - Multi-line string containing a character grid
+///
+/// # Returns
+/// One message per line whose length differs from the first line's
+///
+/// # Examples
+///
+/// ```
+/// # use shared::validate::check_rectangular;
+/// assert!(check_rectangular("ABC\nDEF").is_empty());
+/// assert!(check_rectangular("  ABC\n  DEF\n").is_empty());
+/// assert_eq!(
+///     check_rectangular("ABC\nDE\nFGHI"),
+///     vec![
+///         "Line 2: expected 3 columns, found 2",
+///         "Line 3: expected 3 columns, found 4",
+///     ]
+/// );
+/// ```
+pub fn check_rectangular(input: &str) -> Vec<String> {
+    let mut rows = grid_lines(input).map(|(index, _, line)| (index, line));
+    let Some((_, first)) = rows.next() else {
+        return vec!["Grid is empty".to_string()];
+    };
+    let width = first.chars().count();
+
+    rows.filter_map(|(index, line)| {
+        let columns = line.chars().count();
+        (columns != width).then(|| {
+            format!(
+                "Line {}: expected {width} columns, found {columns}",
+                index + 1
+            )
+        })
+    })
+    .collect()
+}
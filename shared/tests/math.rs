@@ -0,0 +1,107 @@
+use rstest::rstest;
+use shared::math::{crt, extended_gcd, gcd, lcm, mod_inverse, mod_pow};
+
+// ===== GCD / LCM TESTS =====
+
+#[rstest]
+#[case(-12, -18, 6)] // Both negative
+#[case(0, -7, 7)] // Zero and a negative
+#[case(0, 0, 0)] // Both zero
+#[case(i64::MIN, i64::MIN, 1 << 63)] // Result does not fit in i64
+#[case(i64::MIN, i64::MAX, 1)] // Extremes are coprime
+fn test_gcd(#[case] a: i64, #[case] b: i64, #[case] expected: u64) {
+    assert_eq!(gcd(a, b), expected);
+}
+
+#[rstest]
+#[case(-4, 6, Some(12))] // Sign is dropped
+#[case(-4, -6, Some(12))] // Both negative
+#[case(0, -5, Some(0))] // Zero input
+#[case(i64::MAX, 1, Some(i64::MAX))] // Largest representable result
+#[case(i64::MAX, i64::MAX, Some(i64::MAX))] // Equal extremes
+#[case(1 << 62, 2, Some(1 << 62))] // Power of two divides the other
+#[case(3, i64::MAX, None)] // Just over the boundary
+#[case(i64::MIN, 1, None)] // |i64::MIN| does not fit in i64
+fn test_lcm(#[case] a: i64, #[case] b: i64, #[case] expected: Option<i64>) {
+    assert_eq!(lcm(a, b), expected);
+}
+
+// ===== EXTENDED GCD TESTS =====
+
+#[rstest]
+#[case(-240, 46, 2)] // Negative left
+#[case(240, -46, 2)] // Negative right
+#[case(-240, -46, 2)] // Both negative
+#[case(0, 0, 0)] // Both zero
+#[case(i64::MAX, i64::MIN, 1)] // Extremes
+#[case(i64::MIN, -1, 1)] // i64::MIN with a unit
+fn test_extended_gcd_bezout(#[case] a: i64, #[case] b: i64, #[case] expected_gcd: i64) {
+    let (g, x, y) = extended_gcd(a, b).unwrap();
+    assert_eq!(g, expected_gcd);
+    assert_eq!(
+        i128::from(a) * i128::from(x) + i128::from(b) * i128::from(y),
+        i128::from(g)
+    );
+}
+
+#[rstest]
+#[case(i64::MIN, 0)] // gcd is 2^63
+#[case(i64::MIN, i64::MIN)] // gcd is 2^63
+fn test_extended_gcd_overflow(#[case] a: i64, #[case] b: i64) {
+    assert_eq!(extended_gcd(a, b), None);
+}
+
+// ===== MODULAR ARITHMETIC TESTS =====
+
+#[rstest]
+#[case(-3, 11, Some(7))] // Negative value is reduced first
+#[case(0, 1, Some(0))] // Everything is congruent modulo 1
+#[case(3, 0, None)] // Zero modulus
+#[case(3, -11, None)] // Negative modulus
+#[case(6, 9, None)] // Not coprime
+#[case(i64::MAX - 1, i64::MAX, Some(i64::MAX - 1))] // -1 is its own inverse
+#[case(2, i64::MAX, Some(1 << 62))] // 2 * 2^62 = i64::MAX + 1
+fn test_mod_inverse(#[case] a: i64, #[case] modulus: i64, #[case] expected: Option<i64>) {
+    assert_eq!(mod_inverse(a, modulus), expected);
+}
+
+#[rstest]
+#[case(2, 10, 0, None)] // Zero modulus
+#[case(5, 3, 1, Some(0))] // Modulus 1
+#[case(u64::MAX, 2, u64::MAX, Some(0))] // Base equal to the modulus
+#[case(u64::MAX - 1, 2, u64::MAX, Some(1))] // (-1)^2 at the largest modulus
+#[case(2, 64, u64::MAX, Some(1))] // 2^64 ≡ 1 (mod 2^64 - 1)
+fn test_mod_pow(
+    #[case] base: u64,
+    #[case] exponent: u64,
+    #[case] modulus: u64,
+    #[case] expected: Option<u64>,
+) {
+    assert_eq!(mod_pow(base, exponent, modulus), expected);
+}
+
+// ===== CRT TESTS =====
+
+#[rstest]
+#[case(&[], Some((0, 1)))] // No congruences
+#[case(&[(-1, 5)], Some((4, 5)))] // Negative residue
+#[case(&[(1, 0)], None)] // Zero modulus
+#[case(&[(1, -3)], None)] // Negative modulus
+#[case(&[(2, 4), (4, 6)], Some((10, 12)))] // Non-coprime, consistent
+#[case(&[(3, 6), (0, 9)], Some((9, 18)))] // Non-coprime, consistent
+#[case(&[(0, 6), (0, 4)], Some((0, 12)))] // Non-coprime, zero residues
+#[case(&[(0, 4), (1, 6)], None)] // Non-coprime, parities disagree
+#[case(&[(i64::MAX - 1, i64::MAX)], Some((i64::MAX - 1, i64::MAX)))] // Largest modulus
+#[case(&[(0, i64::MAX), (0, 2)], None)] // Combined modulus overflows
+fn test_crt(#[case] congruences: &[(i64, i64)], #[case] expected: Option<(i64, i64)>) {
+    assert_eq!(crt(congruences), expected);
+}
+
+#[test]
+fn test_crt_combined_modulus_at_i64_max() {
+    // 153092023 * 60247241209 == i64::MAX, and the factors are coprime
+    let (x, modulus) = crt(&[(1, 153_092_023), (2, 60_247_241_209)]).unwrap();
+    assert_eq!(modulus, i64::MAX);
+    assert_eq!(x % 153_092_023, 1);
+    assert_eq!(x % 60_247_241_209, 2);
+}